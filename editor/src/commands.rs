@@ -0,0 +1,106 @@
+//! # Command-line commands
+
+use crate::file::save_atomically;
+use dtee::{Controller, CsvQuoting, Format};
+use std::io::{Error, ErrorKind, Result};
+use std::path::Path;
+
+/// Returns the format with the specified name, CSV and JSON options are applied when the format is CSV or JSON.
+///
+/// The delimiter is a single character, `tab` or `\t` stand for the tab character.
+pub fn format(name: &str, delimiter: Option<&str>, quote_all: bool, regions: bool) -> Result<Format> {
+  let format = name.parse::<Format>().map_err(|reason| Error::new(ErrorKind::InvalidInput, reason))?;
+  let mut options = match format {
+    Format::Csv(options) => options,
+    Format::Json(options) => return Ok(Format::Json(options.with_regions(regions))),
+    _ => return Ok(format),
+  };
+  if let Some(delimiter) = delimiter {
+    let mut chars = delimiter.chars();
+    let delimiter = match (delimiter, chars.next(), chars.next()) {
+      ("tab" | "\\t", _, _) => '\t',
+      (_, Some(ch), None) => ch,
+      _ => return Err(Error::new(ErrorKind::InvalidInput, format!("invalid delimiter: '{delimiter}'"))),
+    };
+    options = options.with_delimiter(delimiter);
+  }
+  if quote_all {
+    options = options.with_quoting(CsvQuoting::Always);
+  }
+  Ok(Format::Csv(options))
+}
+
+/// Exports the decision table read from the input file to the specified format.
+///
+/// When the input file contains several decision tables, the first one is exported.
+pub fn export(input_file: &str, format: Format, output_file: Option<&str>) -> Result<()> {
+  let content = std::fs::read_to_string(input_file)?;
+  let decision_table = Controller::new(content).decision_table().map_err(|reason| Error::new(ErrorKind::InvalidData, reason))?;
+  write_output(&format.export(&decision_table), output_file)
+}
+
+/// Imports the decision table from the input file in the specified format and converts it into box-drawing text.
+///
+/// When the input file contains several decision tables, the decision
+/// must be selected by its identifier or name.
+pub fn import(input_file: &str, format: Format, decision: Option<&str>, output_file: Option<&str>) -> Result<()> {
+  let content = std::fs::read_to_string(input_file)?;
+  let decision_table = format.import(&content, decision).map_err(|reason| Error::new(ErrorKind::InvalidData, reason))?;
+  write_output(&format!("{decision_table}\n"), output_file)
+}
+
+/// Reformats all decision tables read from the input file with the specified padding.
+///
+/// In check mode the file is left untouched and an error is reported when it is not formatted,
+/// otherwise the reformatted content is written back to the file, when it differs.
+/// Decision tables framed with ASCII characters are written back with ASCII frames.
+pub fn fmt(input_file: &str, padding: usize, check: bool) -> Result<()> {
+  let content = std::fs::read_to_string(input_file)?;
+  let mut controller = Controller::new(&content);
+  for _ in 0..controller.table_count() {
+    controller.decision_table().map_err(|reason| Error::new(ErrorKind::InvalidData, reason))?;
+    controller.reformat(padding);
+    controller.cursor_move_table_next();
+  }
+  let formatted = controller.text();
+  if formatted == content {
+    return Ok(());
+  }
+  if check {
+    return Err(Error::other(format!("{input_file}: decision table is not formatted")));
+  }
+  save_atomically(Path::new(input_file), &formatted)
+}
+
+/// Writes the content to the output file when specified, otherwise to the standard output.
+fn write_output(content: &str, output_file: Option<&str>) -> Result<()> {
+  match output_file {
+    Some(output_file) => save_atomically(Path::new(output_file), content),
+    None => {
+      print!("{content}");
+      Ok(())
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// Formatted decision table framed with ASCII characters.
+  const ASCII: &str = "+-----+\n|  T  |\n+---+-+-+---+\n| U | a # b |\n+===+===+===+\n| 1 | 2 # 3 |\n+---+---+---+\n";
+
+  #[test]
+  fn fmt_check_ascii() {
+    let file_name = std::env::temp_dir().join(format!("dte-fmt-check-{}.txt", std::process::id()));
+    std::fs::write(&file_name, ASCII).unwrap();
+    let input_file = file_name.to_string_lossy();
+    assert!(fmt(&input_file, 1, true).is_ok());
+    let unformatted = "+-----+\n|  T  |\n+---+-+-+----+\n| U | a # b  |\n+===+===+====+\n| 1 | 2 # 3  |\n+---+---+----+\n";
+    std::fs::write(&file_name, unformatted).unwrap();
+    assert!(fmt(&input_file, 1, true).is_err());
+    assert!(fmt(&input_file, 1, false).is_ok());
+    assert_eq!(ASCII, std::fs::read_to_string(&file_name).unwrap());
+    std::fs::remove_file(&file_name).unwrap();
+  }
+}
@@ -1,5 +1,6 @@
 //! # Decision table editor
 
+use crate::file::save_atomically;
 use crate::trigger::{read_trigger, Trigger};
 use crate::utils::*;
 use crossterm::style::{Print, Stylize};
@@ -7,6 +8,7 @@ use crossterm::{execute, queue};
 use dtee::{Char, Controller, CursorShape, Updates, SPACE};
 use std::cmp::max;
 use std::io::{Result, Stdout, Write};
+use std::path::PathBuf;
 
 /// Minimal terminal width before `locking` the screen.
 const MINIMAL_TERMINAL_WIDTH: usize = 40;
@@ -19,25 +21,35 @@ pub struct Editor {
   stdout: Stdout,
   controller: Controller,
   locked: bool,
+  /// Name of the file where the edited content is saved.
+  file_name: PathBuf,
 }
 
 impl Editor {
   /// Creates a new editor populated with the provided text.
-  pub fn new(text: String) -> Result<Self> {
+  pub fn new(text: String, file_name: PathBuf) -> Result<Self> {
     Ok(Self {
       stdout: std::io::stdout(),
       controller: Controller::new(text),
       locked: true,
+      file_name,
     })
   }
 
+  /// Saves all decision tables with ASCII frames when `ascii` is `true`,
+  /// otherwise every decision table is saved with the frames it was loaded with.
+  pub fn with_ascii(mut self, ascii: bool) -> Self {
+    self.controller = self.controller.with_ascii(ascii);
+    self
+  }
+
   /// Starts text editing loop.
   pub fn start(&mut self) -> Result<()> {
     execute!(self.stdout, t_enter_alternate_screen())?;
     execute!(self.stdout, t_clear_all())?;
     execute!(self.stdout, c_blinking_bar(), c_show())?;
     let (width, height) = t_size()?;
-    self.action_resize(width, height)?;
+    self.action_resize(width, height.saturating_sub(1))?;
     loop {
       let key = read_trigger();
       if matches!(key, Trigger::Exit) {
@@ -56,7 +68,7 @@ impl Editor {
   /// Processes a trigger when the screen is locked (too small).
   fn process_trigger_when_locked_screen(&mut self, trigger: Trigger) -> Result<()> {
     if let Trigger::Resize(width, height) = trigger {
-      self.action_resize(width, height)?
+      self.action_resize(width, height.saturating_sub(1))?
     }
     Ok(())
   }
@@ -64,27 +76,60 @@ impl Editor {
   /// Processes a trigger when the screen is unlocked (normal state).
   fn process_trigger_when_unlocked_screen(&mut self, trigger: Trigger) -> Result<()> {
     match trigger {
+      Trigger::AltDown => self.action_move_rule_down()?,
       Trigger::AltInsert => self.action_cursor_toggle_caret_under_score()?,
+      Trigger::AltLeft => self.action_move_column_left()?,
+      Trigger::AltPageDown => self.action_cursor_move_rule_next()?,
+      Trigger::AltPageUp => self.action_cursor_move_rule_prev()?,
+      Trigger::AltRight => self.action_move_column_right()?,
+      Trigger::AltUp => self.action_move_rule_up()?,
       Trigger::Backspace => self.action_delete(true)?,
       Trigger::Char(ch) => self.action_insert_char(ch)?,
       Trigger::CtrlEnd => self.action_cursor_move_row_end()?,
       Trigger::CtrlHome => self.action_cursor_move_row_start()?,
+      Trigger::CtrlInsert => self.action_copy()?,
       Trigger::CtrlPageDown => self.action_cursor_move_col_end()?,
       Trigger::CtrlPageUp => self.action_cursor_move_col_start()?,
       Trigger::Delete => self.action_delete(false)?,
+      Trigger::DeleteColumn => self.action_delete_column()?,
+      Trigger::DeleteRule => self.action_delete_rule()?,
       Trigger::Down => self.action_cursor_move_down()?,
       Trigger::End => self.action_cursor_move_cell_end()?,
       Trigger::Enter => self.action_split_line()?,
+      Trigger::Escape => self.action_selection_clear()?,
       Trigger::F1 => self.action_show_help()?,
+      Trigger::F6 => self.action_cursor_move_table_next()?,
       Trigger::Home => self.action_cursor_move_cell_start()?,
       Trigger::Insert => self.action_cursor_toggle_caret_block()?,
+      Trigger::InsertAnnotationColumn => self.action_insert_annotation_column()?,
+      Trigger::InsertInputColumn => self.action_insert_input_column()?,
+      Trigger::InsertOutputColumn => self.action_insert_output_column()?,
+      Trigger::InsertRuleAbove => self.action_insert_rule_above()?,
+      Trigger::InsertRuleBelow => self.action_insert_rule_below()?,
       Trigger::Left => self.action_cursor_move_left()?,
+      Trigger::Normalize => self.action_normalize()?,
       Trigger::PageDown => self.action_cursor_move_cell_bottom()?,
       Trigger::PageUp => self.action_cursor_move_cell_top()?,
-      Trigger::Resize(width, height) => self.action_resize(width, height - 1)?,
+      Trigger::Redo => self.action_redo()?,
+      Trigger::Resize(width, height) => self.action_resize(width, height.saturating_sub(1))?,
       Trigger::Right => self.action_cursor_move_right()?,
+      Trigger::Save => self.action_save()?,
+      Trigger::SaveAs => self.action_save_as()?,
+      Trigger::ShiftDelete => self.action_cut()?,
+      Trigger::ShiftDown => self.action_select_down()?,
+      Trigger::ShiftEnd => self.action_select_cell_end()?,
+      Trigger::ShiftF6 => self.action_cursor_move_table_prev()?,
+      Trigger::ShiftHome => self.action_select_cell_start()?,
+      Trigger::ShiftInsert => self.action_paste()?,
+      Trigger::ShiftLeft => self.action_select_left()?,
+      Trigger::ShiftPageDown => self.action_select_cell_bottom()?,
+      Trigger::ShiftPageUp => self.action_select_cell_top()?,
+      Trigger::ShiftRight => self.action_select_right()?,
       Trigger::ShiftTab => self.action_cursor_move_cell_prev()?,
+      Trigger::ShiftUp => self.action_select_up()?,
       Trigger::Tab => self.action_cursor_move_cell_next()?,
+      Trigger::Transpose => self.action_transpose()?,
+      Trigger::Undo => self.action_undo()?,
       Trigger::Up => self.action_cursor_move_up()?,
       _ => {}
     };
@@ -161,11 +206,78 @@ impl Editor {
     self.process_updates(updates)
   }
 
+  fn action_cursor_move_rule_next(&mut self) -> Result<()> {
+    let updates = self.controller.cursor_move_rule_next();
+    self.process_updates(updates)
+  }
+
+  fn action_cursor_move_rule_prev(&mut self) -> Result<()> {
+    let updates = self.controller.cursor_move_rule_prev();
+    self.process_updates(updates)
+  }
+
   fn action_cursor_move_cell_prev(&mut self) -> Result<()> {
     let updates = self.controller.cursor_move_cell_prev();
     self.process_updates(updates)
   }
 
+  /// Moves the cursor to the next decision table.
+  fn action_cursor_move_table_next(&mut self) -> Result<()> {
+    let updates = self.controller.cursor_move_table_next();
+    self.process_updates(updates)
+  }
+
+  /// Moves the cursor to the previous decision table.
+  fn action_cursor_move_table_prev(&mut self) -> Result<()> {
+    let updates = self.controller.cursor_move_table_prev();
+    self.process_updates(updates)
+  }
+
+  fn action_select_left(&mut self) -> Result<()> {
+    let updates = self.controller.select_left();
+    self.process_updates(updates)
+  }
+
+  fn action_select_right(&mut self) -> Result<()> {
+    let updates = self.controller.select_right();
+    self.process_updates(updates)
+  }
+
+  fn action_select_up(&mut self) -> Result<()> {
+    let updates = self.controller.select_up();
+    self.process_updates(updates)
+  }
+
+  fn action_select_down(&mut self) -> Result<()> {
+    let updates = self.controller.select_down();
+    self.process_updates(updates)
+  }
+
+  fn action_select_cell_start(&mut self) -> Result<()> {
+    let updates = self.controller.select_cell_start();
+    self.process_updates(updates)
+  }
+
+  fn action_select_cell_end(&mut self) -> Result<()> {
+    let updates = self.controller.select_cell_end();
+    self.process_updates(updates)
+  }
+
+  fn action_select_cell_top(&mut self) -> Result<()> {
+    let updates = self.controller.select_cell_top();
+    self.process_updates(updates)
+  }
+
+  fn action_select_cell_bottom(&mut self) -> Result<()> {
+    let updates = self.controller.select_cell_bottom();
+    self.process_updates(updates)
+  }
+
+  fn action_selection_clear(&mut self) -> Result<()> {
+    let updates = self.controller.selection_clear();
+    self.process_updates(updates)
+  }
+
   fn action_cursor_toggle_caret_block(&mut self) -> Result<()> {
     self.controller.cursor_toggle_caret_block();
     self.update_cursor_shape()?;
@@ -198,6 +310,170 @@ impl Editor {
     self.process_updates(updates)
   }
 
+  /// Inserts a new rule above the current rule.
+  fn action_insert_rule_above(&mut self) -> Result<()> {
+    let updates = self.controller.insert_rule_above();
+    self.process_updates(updates)
+  }
+
+  /// Inserts a new rule below the current rule.
+  fn action_insert_rule_below(&mut self) -> Result<()> {
+    let updates = self.controller.insert_rule_below();
+    self.process_updates(updates)
+  }
+
+  /// Deletes the current rule.
+  fn action_delete_rule(&mut self) -> Result<()> {
+    let updates = self.controller.delete_rule();
+    self.process_updates(updates)
+  }
+
+  /// Moves the current rule up.
+  fn action_move_rule_up(&mut self) -> Result<()> {
+    let updates = self.controller.move_rule_up();
+    self.process_updates(updates)
+  }
+
+  /// Moves the current rule down.
+  fn action_move_rule_down(&mut self) -> Result<()> {
+    let updates = self.controller.move_rule_down();
+    self.process_updates(updates)
+  }
+
+  /// Inserts a new input column.
+  fn action_insert_input_column(&mut self) -> Result<()> {
+    let updates = self.controller.insert_input_column();
+    self.process_updates(updates)
+  }
+
+  /// Inserts a new output column.
+  fn action_insert_output_column(&mut self) -> Result<()> {
+    let updates = self.controller.insert_output_column();
+    self.process_updates(updates)
+  }
+
+  /// Inserts a new annotation column.
+  fn action_insert_annotation_column(&mut self) -> Result<()> {
+    let updates = self.controller.insert_annotation_column();
+    self.process_updates(updates)
+  }
+
+  /// Deletes the current column.
+  fn action_delete_column(&mut self) -> Result<()> {
+    let updates = self.controller.delete_column();
+    self.process_updates(updates)
+  }
+
+  /// Moves the current column left.
+  fn action_move_column_left(&mut self) -> Result<()> {
+    let updates = self.controller.move_column_left();
+    self.process_updates(updates)
+  }
+
+  /// Moves the current column right.
+  fn action_move_column_right(&mut self) -> Result<()> {
+    let updates = self.controller.move_column_right();
+    self.process_updates(updates)
+  }
+
+  /// Transposes the decision table between horizontal and vertical orientation.
+  fn action_transpose(&mut self) -> Result<()> {
+    let updates = self.controller.transpose();
+    self.process_updates(updates)
+  }
+
+  /// Normalizes the frame of the decision table to the canonical light and double lines.
+  fn action_normalize(&mut self) -> Result<()> {
+    let updates = self.controller.normalize();
+    self.process_updates(updates)
+  }
+
+  /// Copies the selected content to the clipboard.
+  fn action_copy(&mut self) -> Result<()> {
+    let updates = self.controller.copy();
+    self.process_updates(updates)
+  }
+
+  /// Moves the selected content to the clipboard.
+  fn action_cut(&mut self) -> Result<()> {
+    let updates = self.controller.cut();
+    self.process_updates(updates)
+  }
+
+  /// Pastes the content of the clipboard at the cursor position.
+  fn action_paste(&mut self) -> Result<()> {
+    let updates = self.controller.paste();
+    self.process_updates(updates)
+  }
+
+  /// Reverts the last change.
+  fn action_undo(&mut self) -> Result<()> {
+    let updates = self.controller.undo();
+    self.process_updates(updates)
+  }
+
+  /// Reapplies the last reverted change.
+  fn action_redo(&mut self) -> Result<()> {
+    let updates = self.controller.redo();
+    self.process_updates(updates)
+  }
+
+  /// Saves the edited content to the current file.
+  fn action_save(&mut self) -> Result<()> {
+    let message = match save_atomically(&self.file_name, &self.controller.text()) {
+      Ok(()) => format!("Saved: {}", self.file_name.display()),
+      Err(reason) => format!("Saving {} failed: {}", self.file_name.display(), reason),
+    };
+    self.show_status(&message)?;
+    self.update_cursor_position()
+  }
+
+  /// Prompts for a new file name and saves the edited content to this file.
+  fn action_save_as(&mut self) -> Result<()> {
+    let current_file_name = self.file_name.to_string_lossy().to_string();
+    if let Some(file_name) = self.prompt("Save as: ", &current_file_name)? {
+      self.file_name = PathBuf::from(file_name);
+      self.action_save()?;
+    }
+    Ok(())
+  }
+
+  /// Reads a single line of text typed by the user in the status line.
+  ///
+  /// Returns [None] when the user cancels the prompt with `Esc`.
+  fn prompt(&mut self, label: &str, initial: &str) -> Result<Option<String>> {
+    let mut input = initial.to_string();
+    let result = loop {
+      self.show_status(&format!("{label}{input}"))?;
+      match read_trigger() {
+        Trigger::Enter if !input.trim().is_empty() => break Some(input.trim().to_string()),
+        Trigger::Escape | Trigger::Exit => break None,
+        Trigger::Backspace => {
+          input.pop();
+        }
+        Trigger::Char(ch) => input.push(ch),
+        Trigger::Resize(width, height) => self.action_resize(width, height.saturating_sub(1))?,
+        _ => {}
+      }
+    };
+    self.show_status("")?;
+    self.update_cursor_position()?;
+    Ok(result)
+  }
+
+  /// Displays a message in the status line below the viewport.
+  fn show_status(&mut self, message: &str) -> Result<()> {
+    let (width, row_index) = self.controller.viewport().size();
+    let message = message.chars().take(width).collect::<String>();
+    execute!(
+      self.stdout,
+      c_move(0, row_index),
+      t_clear_line(),
+      Print(&message),
+      c_move(message.chars().count(), row_index)
+    )
+  }
+
   fn action_resize(&mut self, width: usize, height: usize) -> Result<()> {
     if width < MINIMAL_TERMINAL_WIDTH || height < MINIMAL_TERMINAL_HEIGHT {
       self.controller.resize(max(width, MINIMAL_TERMINAL_WIDTH), max(height, MINIMAL_TERMINAL_HEIGHT));
@@ -223,8 +499,14 @@ impl Editor {
   /// Repaints the viewport.
   fn repaint_all(&mut self) -> Result<()> {
     queue!(self.stdout, c_hide())?;
+    let selection = self.controller.selection().unwrap_or_default();
+    let (offset_left, offset_top) = self.controller.viewport().offset();
     let f = |col_index, row_index, chr: &Char| {
-      let _ = queue!(self.stdout, c_move(col_index, row_index), Print(chr));
+      if selection.contains(col_index + offset_left, row_index + offset_top) {
+        let _ = queue!(self.stdout, c_move(col_index, row_index), Print(chr.to_string().reverse()));
+      } else {
+        let _ = queue!(self.stdout, c_move(col_index, row_index), Print(chr));
+      }
     };
     self.controller.visit_visible_content(f, Some(SPACE.into()), Some(1), Some(1));
     queue!(self.stdout, c_show())?;
@@ -234,7 +516,7 @@ impl Editor {
 
   /// Processes all pending updates.
   fn process_updates(&mut self, updates: Updates) -> Result<()> {
-    if updates.viewport_pos_changed() || updates.content_changed() {
+    if updates.viewport_pos_changed() || updates.content_changed() || updates.selection_changed() {
       self.repaint_all()?;
       self.update_cursor_position()?;
     } else if updates.cursor_pos_changed() {
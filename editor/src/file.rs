@@ -0,0 +1,98 @@
+//! # File operations
+
+use std::fs;
+use std::io::{Result, Write};
+use std::path::{Path, PathBuf};
+
+/// Writes the content to the specified file atomically.
+///
+/// The content is first written to a temporary file placed in the same directory
+/// as the target file, flushed to disk, and then renamed to the target file name.
+/// This way the target file is never left truncated or partially written,
+/// even when the editor crashes in the middle of saving.
+/// Finally, the parent directory is flushed to disk, so the renaming survives a power loss.
+pub fn save_atomically(path: &Path, content: &str) -> Result<()> {
+  let temporary_path = temporary_path(path);
+  let result = write_and_sync(&temporary_path, path, content).and_then(|_| fs::rename(&temporary_path, path));
+  if result.is_err() {
+    // the temporary file is useless when saving failed, errors are ignored on purpose
+    let _ = fs::remove_file(&temporary_path);
+  }
+  result?;
+  sync_parent(path)
+}
+
+/// Writes the content to the temporary file and flushes it to disk.
+///
+/// When the target file already exists, its permissions are copied
+/// to the temporary file, so renaming does not change them.
+fn write_and_sync(temporary_path: &Path, path: &Path, content: &str) -> Result<()> {
+  let mut file = fs::File::create(temporary_path)?;
+  file.write_all(content.as_bytes())?;
+  file.sync_all()?;
+  if let Ok(metadata) = fs::metadata(path) {
+    fs::set_permissions(temporary_path, metadata.permissions())?;
+  }
+  Ok(())
+}
+
+/// Flushes the directory containing the specified file to disk.
+#[cfg(unix)]
+fn sync_parent(path: &Path) -> Result<()> {
+  let parent = match path.parent() {
+    Some(parent) if !parent.as_os_str().is_empty() => parent,
+    _ => Path::new("."),
+  };
+  fs::File::open(parent)?.sync_all()
+}
+
+/// Directories can not be opened as files on this platform, the renaming is flushed by the file system.
+#[cfg(not(unix))]
+fn sync_parent(_path: &Path) -> Result<()> {
+  Ok(())
+}
+
+/// Returns the path of the temporary file used while saving the specified file.
+fn temporary_path(path: &Path) -> PathBuf {
+  let file_name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+  path.with_file_name(format!(".{}.{}.tmp", file_name, std::process::id()))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// Returns an empty directory for the test with the specified name.
+  fn test_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("dte-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+  }
+
+  #[test]
+  fn save_atomically_writes_content() {
+    let dir = test_dir("content");
+    let path = dir.join("table.dtb");
+    save_atomically(&path, "┌───┐\n│ A │\n└───┘\n").unwrap();
+    save_atomically(&path, "┌───┐\n│ B │\n└───┘\n").unwrap();
+    assert_eq!("┌───┐\n│ B │\n└───┘\n", fs::read_to_string(&path).unwrap());
+    // no temporary file is left in the directory
+    assert_eq!(1, fs::read_dir(&dir).unwrap().count());
+    fs::remove_dir_all(&dir).unwrap();
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn save_atomically_keeps_permissions() {
+    use std::os::unix::fs::PermissionsExt;
+    let dir = test_dir("permissions");
+    let path = dir.join("table.dtb");
+    fs::write(&path, "A").unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o640)).unwrap();
+    save_atomically(&path, "B").unwrap();
+    assert_eq!("B", fs::read_to_string(&path).unwrap());
+    assert_eq!(0o640, fs::metadata(&path).unwrap().permissions().mode() & 0o777);
+    fs::remove_dir_all(&dir).unwrap();
+  }
+}
@@ -1,36 +1,116 @@
 //! # Decision table editor
 
+mod commands;
 mod editor;
+mod file;
 mod trigger;
 mod utils;
 
-use clap::{arg, command, ArgMatches};
+use clap::{arg, command, ArgMatches, Command};
+use dtee::Format;
 use editor::Editor;
+use std::path::PathBuf;
 
 /// Returns argument matches for command-line arguments.
 fn get_matches() -> ArgMatches {
   command!()
+    .args_conflicts_with_subcommands(true)
+    .subcommand_negates_reqs(true)
     .arg(arg!(<INPUT_FILE>).help("File containing decision table to edit").required(true).index(1))
+    .arg(arg!(--ascii).help("Save all decision tables with ASCII frames (+, -, =, |, #) instead of box-drawing characters"))
+    .subcommand(
+      Command::new("export")
+        .about("Export decision table to another format")
+        .arg(
+          arg!(-f --format <FORMAT>)
+            .help("Format of the exported decision table")
+            .value_parser(Format::NAMES)
+            .required(true),
+        )
+        .arg(arg!(--delimiter <DELIMITER>).help("Character separating values in CSV, 'tab' for the tab character"))
+        .arg(arg!(--"quote-all").help("Quote all values in CSV"))
+        .arg(arg!(--regions).help("Include regions of cells in JSON"))
+        .arg(arg!(-o --output <OUTPUT_FILE>).help("File to write the exported decision table to, standard output when omitted"))
+        .arg(arg!(<INPUT_FILE>).help("File containing decision table to export").required(true).index(1)),
+    )
+    .subcommand(
+      Command::new("import")
+        .about("Import decision table from another format")
+        .arg(
+          arg!(-f --format <FORMAT>)
+            .help("Format of the imported decision table")
+            .value_parser(Format::NAMES)
+            .default_value("dmn"),
+        )
+        .arg(arg!(--delimiter <DELIMITER>).help("Character separating values in CSV, 'tab' for the tab character"))
+        .arg(arg!(-d --decision <DECISION>).help("Identifier or name of the decision to import, required when there are several"))
+        .arg(arg!(-o --output <OUTPUT_FILE>).help("File to write the imported decision table to, standard output when omitted"))
+        .arg(arg!(<INPUT_FILE>).help("File containing decision table to import").required(true).index(1)),
+    )
+    .subcommand(
+      Command::new("fmt")
+        .about("Reformat decision table, shrinking columns to the width of their content")
+        .arg(
+          arg!(-p --padding <PADDING>)
+            .help("Number of spaces on both sides of the text in cells")
+            .value_parser(clap::value_parser!(usize))
+            .default_value("1"),
+        )
+        .arg(arg!(--check).help("Do not write the file, exit with an error when it is not formatted"))
+        .arg(arg!(<INPUT_FILE>).help("File containing decision table to reformat").required(true).index(1)),
+    )
     .get_matches()
 }
 
 /// Starts editing provided decision table.
-fn start(content: String) -> std::io::Result<()> {
-  Editor::new(content)?.start()
+fn start(content: String, file_name: PathBuf, ascii: bool) -> std::io::Result<()> {
+  Editor::new(content, file_name)?.with_ascii(ascii).start()
 }
 
 /// Main entrypoint of the application.
 fn main() -> std::io::Result<()> {
   // get command-line argument matches
   let matches = get_matches();
+  // execute the subcommand when requested
+  if let Some(("fmt", matches)) = matches.subcommand() {
+    let input_file = matches.get_one::<String>("INPUT_FILE").unwrap();
+    let padding = *matches.get_one::<usize>("padding").unwrap();
+    if let Err(reason) = commands::fmt(input_file, padding, matches.get_flag("check")) {
+      eprintln!("{reason}");
+      std::process::exit(1);
+    }
+    return Ok(());
+  }
+  if let Some((name, matches)) = matches.subcommand() {
+    let input_file = matches.get_one::<String>("INPUT_FILE").unwrap();
+    let output_file = matches.get_one::<String>("output").map(|output_file| output_file.as_str());
+    let delimiter = matches.get_one::<String>("delimiter").map(|delimiter| delimiter.as_str());
+    let quote_all = matches.try_get_one::<bool>("quote-all").ok().flatten().copied().unwrap_or(false);
+    let regions = matches.try_get_one::<bool>("regions").ok().flatten().copied().unwrap_or(false);
+    let result = commands::format(matches.get_one::<String>("format").unwrap(), delimiter, quote_all, regions).and_then(|format| match name {
+      "export" => commands::export(input_file, format, output_file),
+      "import" => {
+        let decision = matches.get_one::<String>("decision").map(|decision| decision.as_str());
+        commands::import(input_file, format, decision, output_file)
+      }
+      _ => Ok(()),
+    });
+    if let Err(reason) = result {
+      eprintln!("{reason}");
+      std::process::exit(1);
+    }
+    return Ok(());
+  }
   // get the name of the file to be edited
   let file_name = matches.get_one::<String>("INPUT_FILE").unwrap().to_string();
+  // check if all decision tables should be saved with ASCII frames
+  let ascii = matches.get_flag("ascii");
   // read the file content as Unicode string
-  if let Ok(content) = std::fs::read_to_string(file_name) {
+  if let Ok(content) = std::fs::read_to_string(&file_name) {
     // switch the terminal to raw mode, we take the over the full control
     crossterm::terminal::enable_raw_mode()?;
     // start the editor...
-    let _ = start(content);
+    let _ = start(content, file_name.into(), ascii);
     // ...and when user is done with editing, switch back to normal mode
     crossterm::terminal::disable_raw_mode()?;
   }
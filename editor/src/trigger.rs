@@ -7,11 +7,17 @@ const MODIFIER_NONE: KeyModifiers = KeyModifiers::NONE;
 const MODIFIER_CTRL: KeyModifiers = KeyModifiers::CONTROL;
 const MODIFIER_ALT: KeyModifiers = KeyModifiers::ALT;
 const MODIFIER_SHIFT: KeyModifiers = KeyModifiers::SHIFT;
+const MODIFIER_ALT_SHIFT: KeyModifiers = KeyModifiers::ALT.union(KeyModifiers::SHIFT);
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Trigger {
+  AltDown,
   AltLeft,
+  AltRight,
+  AltUp,
   AltInsert,
+  AltPageDown,
+  AltPageUp,
   Backspace,
   CtrlEnd,
   CtrlHome,
@@ -19,26 +25,46 @@ pub enum Trigger {
   CtrlPageDown,
   CtrlPageUp,
   Delete,
+  DeleteColumn,
+  DeleteRule,
   Down,
   End,
   Exit,
   Enter,
+  Escape,
   F1,
+  F6,
   Home,
   Insert,
+  InsertAnnotationColumn,
+  InsertInputColumn,
+  InsertOutputColumn,
+  InsertRuleAbove,
+  InsertRuleBelow,
   Left,
+  Normalize,
   PageDown,
   PageUp,
+  Redo,
   Right,
+  Save,
+  SaveAs,
   Up,
+  ShiftDelete,
+  ShiftDown,
   ShiftEnd,
+  ShiftF6,
   ShiftHome,
   ShiftInsert,
   ShiftLeft,
   ShiftPageDown,
   ShiftPageUp,
+  ShiftRight,
   ShiftTab,
+  ShiftUp,
   Tab,
+  Transpose,
+  Undo,
   Char(char),
   Resize(usize, usize),
 }
@@ -52,11 +78,18 @@ pub fn read_trigger() -> Trigger {
           (KeyCode::Left, MODIFIER_NONE, KIND_PRESS, STATUS_NONE) => return Trigger::Left,
           (KeyCode::Left, MODIFIER_SHIFT, KIND_PRESS, STATUS_NONE) => return Trigger::ShiftLeft,
           (KeyCode::Left, MODIFIER_ALT, KIND_PRESS, STATUS_NONE) => return Trigger::AltLeft,
+          (KeyCode::Right, MODIFIER_ALT, KIND_PRESS, STATUS_NONE) => return Trigger::AltRight,
+          (KeyCode::Up, MODIFIER_ALT, KIND_PRESS, STATUS_NONE) => return Trigger::AltUp,
+          (KeyCode::Down, MODIFIER_ALT, KIND_PRESS, STATUS_NONE) => return Trigger::AltDown,
           (KeyCode::Right, MODIFIER_NONE, KIND_PRESS, STATUS_NONE) => return Trigger::Right,
+          (KeyCode::Right, MODIFIER_SHIFT, KIND_PRESS, STATUS_NONE) => return Trigger::ShiftRight,
           (KeyCode::Up, MODIFIER_NONE, KIND_PRESS, STATUS_NONE) => return Trigger::Up,
+          (KeyCode::Up, MODIFIER_SHIFT, KIND_PRESS, STATUS_NONE) => return Trigger::ShiftUp,
           (KeyCode::Down, MODIFIER_NONE, KIND_PRESS, STATUS_NONE) => return Trigger::Down,
+          (KeyCode::Down, MODIFIER_SHIFT, KIND_PRESS, STATUS_NONE) => return Trigger::ShiftDown,
           (KeyCode::Backspace, MODIFIER_NONE, KIND_PRESS, STATUS_NONE) => return Trigger::Backspace,
           (KeyCode::Delete, MODIFIER_NONE, KIND_PRESS, STATUS_NONE) => return Trigger::Delete,
+          (KeyCode::Delete, MODIFIER_SHIFT, KIND_PRESS, STATUS_NONE) => return Trigger::ShiftDelete,
           (KeyCode::Insert, MODIFIER_NONE, KIND_PRESS, STATUS_NONE) => return Trigger::Insert,
           (KeyCode::Insert, MODIFIER_SHIFT, KIND_PRESS, STATUS_NONE) => return Trigger::ShiftInsert,
           (KeyCode::Insert, MODIFIER_CTRL, KIND_PRESS, STATUS_NONE) => return Trigger::CtrlInsert,
@@ -65,18 +98,36 @@ pub fn read_trigger() -> Trigger {
           (KeyCode::End, MODIFIER_SHIFT, KIND_PRESS, STATUS_NONE) => return Trigger::ShiftEnd,
           (KeyCode::End, MODIFIER_CTRL, KIND_PRESS, STATUS_NONE) => return Trigger::CtrlEnd,
           (KeyCode::F(1), MODIFIER_NONE, KIND_PRESS, STATUS_NONE) => return Trigger::F1,
+          (KeyCode::F(6), MODIFIER_NONE, KIND_PRESS, STATUS_NONE) => return Trigger::F6,
+          (KeyCode::F(6), MODIFIER_SHIFT, KIND_PRESS, STATUS_NONE) => return Trigger::ShiftF6,
           (KeyCode::Home, MODIFIER_NONE, KIND_PRESS, STATUS_NONE) => return Trigger::Home,
           (KeyCode::Home, MODIFIER_SHIFT, KIND_PRESS, STATUS_NONE) => return Trigger::ShiftHome,
           (KeyCode::Home, MODIFIER_CTRL, KIND_PRESS, STATUS_NONE) => return Trigger::CtrlHome,
           (KeyCode::PageDown, MODIFIER_NONE, KIND_PRESS, STATUS_NONE) => return Trigger::PageDown,
           (KeyCode::PageDown, MODIFIER_SHIFT, KIND_PRESS, STATUS_NONE) => return Trigger::ShiftPageDown,
           (KeyCode::PageDown, MODIFIER_CTRL, KIND_PRESS, STATUS_NONE) => return Trigger::CtrlPageDown,
+          (KeyCode::PageDown, MODIFIER_ALT, KIND_PRESS, STATUS_NONE) => return Trigger::AltPageDown,
           (KeyCode::PageUp, MODIFIER_NONE, KIND_PRESS, STATUS_NONE) => return Trigger::PageUp,
           (KeyCode::PageUp, MODIFIER_SHIFT, KIND_PRESS, STATUS_NONE) => return Trigger::ShiftPageUp,
           (KeyCode::PageUp, MODIFIER_CTRL, KIND_PRESS, STATUS_NONE) => return Trigger::CtrlPageUp,
+          (KeyCode::PageUp, MODIFIER_ALT, KIND_PRESS, STATUS_NONE) => return Trigger::AltPageUp,
           (KeyCode::Tab, MODIFIER_NONE, KIND_PRESS, STATUS_NONE) => return Trigger::Tab,
           (KeyCode::BackTab, MODIFIER_SHIFT, KIND_PRESS, STATUS_NONE) => return Trigger::ShiftTab,
+          (KeyCode::Esc, MODIFIER_NONE, KIND_PRESS, STATUS_NONE) => return Trigger::Escape,
           (KeyCode::Char('q'), MODIFIER_CTRL, KIND_PRESS, STATUS_NONE) => return Trigger::Exit,
+          (KeyCode::Char('s'), MODIFIER_CTRL, KIND_PRESS, STATUS_NONE) => return Trigger::Save,
+          (KeyCode::Char('s'), MODIFIER_ALT, KIND_PRESS, STATUS_NONE) => return Trigger::SaveAs,
+          (KeyCode::Char('z'), MODIFIER_CTRL, KIND_PRESS, STATUS_NONE) => return Trigger::Undo,
+          (KeyCode::Char('y'), MODIFIER_CTRL, KIND_PRESS, STATUS_NONE) => return Trigger::Redo,
+          (KeyCode::Char('r'), MODIFIER_ALT, KIND_PRESS, STATUS_NONE) => return Trigger::InsertRuleBelow,
+          (KeyCode::Char('R'), MODIFIER_ALT_SHIFT, KIND_PRESS, STATUS_NONE) => return Trigger::InsertRuleAbove,
+          (KeyCode::Char('d'), MODIFIER_ALT, KIND_PRESS, STATUS_NONE) => return Trigger::DeleteRule,
+          (KeyCode::Char('i'), MODIFIER_ALT, KIND_PRESS, STATUS_NONE) => return Trigger::InsertInputColumn,
+          (KeyCode::Char('o'), MODIFIER_ALT, KIND_PRESS, STATUS_NONE) => return Trigger::InsertOutputColumn,
+          (KeyCode::Char('a'), MODIFIER_ALT, KIND_PRESS, STATUS_NONE) => return Trigger::InsertAnnotationColumn,
+          (KeyCode::Char('t'), MODIFIER_ALT, KIND_PRESS, STATUS_NONE) => return Trigger::Transpose,
+          (KeyCode::Char('n'), MODIFIER_ALT, KIND_PRESS, STATUS_NONE) => return Trigger::Normalize,
+          (KeyCode::Char('D'), MODIFIER_ALT_SHIFT, KIND_PRESS, STATUS_NONE) => return Trigger::DeleteColumn,
           (KeyCode::Char(ch), _, KIND_PRESS, STATUS_NONE) => return Trigger::Char(ch),
           _ => {}
         },
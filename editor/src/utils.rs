@@ -34,6 +34,11 @@ pub fn t_clear_all() -> crossterm::terminal::Clear {
   crossterm::terminal::Clear(crossterm::terminal::ClearType::All)
 }
 
+/// Returns clear current line action.
+pub fn t_clear_line() -> crossterm::terminal::Clear {
+  crossterm::terminal::Clear(crossterm::terminal::ClearType::CurrentLine)
+}
+
 /// Returns enter alternate screen action.
 pub fn t_enter_alternate_screen() -> crossterm::terminal::EnterAlternateScreen {
   crossterm::terminal::EnterAlternateScreen
@@ -1,14 +1,20 @@
 //! # Controller
 
-use crate::model::{Plane, Row};
+use crate::model::{Alignment, Clipboard, Edit, History, Layout, Plane, Row};
 use crate::region::Region;
 use crate::updates::Updates;
-use crate::{Char, Cursor};
+use crate::{Char, Cursor, DecisionTable, TableError, SPACE};
 
 /// Handler for edit operations.
 pub struct Controller {
-  /// Edited textual content.
-  plane: Plane,
+  /// Edited textual content of each decision table.
+  planes: Vec<Plane>,
+  /// Undo and redo history of each decision table.
+  histories: Vec<History>,
+  /// Index of the currently edited decision table.
+  current: usize,
+  /// Content copied or cut from the decision table.
+  clipboard: Option<Clipboard>,
   /// Visible content viewport.
   viewport: Region,
   margin_left: usize,
@@ -29,10 +35,17 @@ impl Controller {
   /// assert!(!controller.content().is_empty());
   /// ```
   pub fn new<T: ToString>(content: T) -> Self {
-    let mut plane = Plane::new(content);
-    let viewport = *plane.region();
+    let mut planes = Layout::split(&content.to_string())
+      .into_iter()
+      .map(|(layout, lines)| Plane::from_parts(layout, lines))
+      .collect::<Vec<Plane>>();
+    let viewport = *planes[0].region();
+    let histories = planes.iter().map(|_| History::default()).collect();
     Self {
-      plane,
+      planes,
+      histories,
+      current: 0,
+      clipboard: None,
       viewport,
       margin_left: 1,
       margin_right: 2,
@@ -54,6 +67,26 @@ impl Controller {
     self
   }
 
+  /// Saves all decision tables with ASCII frames when `ascii` is `true`.
+  ///
+  /// Otherwise, every decision table is saved with the frames it was loaded with,
+  /// decision tables framed with ASCII characters are saved with ASCII frames.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::Controller;
+  ///
+  /// let controller = Controller::new("┌───┐\n│ A │\n└───┘\n").with_ascii(true);
+  /// assert_eq!("+---+\n| A |\n+---+\n", controller.text());
+  /// ```
+  pub fn with_ascii(mut self, ascii: bool) -> Self {
+    if ascii {
+      self.planes.iter_mut().for_each(|plane| plane.set_ascii(true));
+    }
+    self
+  }
+
   pub fn viewport(&self) -> &Region {
     &self.viewport
   }
@@ -78,11 +111,72 @@ impl Controller {
   /// assert_eq!(1, row);
   /// ```
   pub fn cursor(&self) -> &Cursor {
-    self.plane.cursor()
+    self.planes[self.current].cursor()
   }
 
   pub fn content(&self) -> &[Row] {
-    self.plane.content()
+    self.planes[self.current].content()
+  }
+
+  /// Returns the edited content as text, ready to be saved to a file.
+  ///
+  /// The text surrounding the decision tables, the indentation and line endings
+  /// are restored exactly as they were in the original content.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::Controller;
+  ///
+  /// let controller = Controller::new("Table:\r\n  ┌───┐\r\n  │ A │\r\n  └───┘\r\n");
+  /// assert_eq!("Table:\r\n  ┌───┐\r\n  │ A │\r\n  └───┘\r\n", controller.text());
+  /// ```
+  pub fn text(&self) -> String {
+    self.planes.iter().map(Plane::text).collect()
+  }
+
+  /// Returns the number of decision tables found in the edited content.
+  pub fn table_count(&self) -> usize {
+    self.planes.len()
+  }
+
+  /// Returns the index of the currently edited decision table.
+  pub fn table_index(&self) -> usize {
+    self.current
+  }
+
+  /// Moves the cursor to the next decision table.
+  ///
+  /// Every decision table keeps its own cursor position, selection and undo history,
+  /// so the cursor is placed where it was when the decision table was edited the last time.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::Controller;
+  ///
+  /// let mut controller = Controller::new("┌───┐\n│ A │\n└───┘\n\n┌───┐\n│ B │\n└───┘\n");
+  /// assert_eq!(2, controller.table_count());
+  /// controller.cursor_move_table_next();
+  /// assert_eq!(1, controller.table_index());
+  /// controller.insert_char('C');
+  /// assert_eq!("┌───┐\n│ A │\n└───┘\n\n┌───┐\n│C B│\n└───┘\n", controller.text());
+  /// ```
+  pub fn cursor_move_table_next(&mut self) -> Updates {
+    self.cursor_move_table(self.current + 1)
+  }
+
+  /// Moves the cursor to the previous decision table.
+  pub fn cursor_move_table_prev(&mut self) -> Updates {
+    match self.current.checked_sub(1) {
+      Some(index) => self.cursor_move_table(index),
+      None => Updates::default(),
+    }
+  }
+
+  /// Returns the typed decision table parsed from the edited content.
+  pub fn decision_table(&self) -> Result<DecisionTable, TableError> {
+    self.planes[self.current].decision_table()
   }
 
   pub fn visit_visible_content<F>(&self, mut f: F, fill: Option<Char>, ext_width: Option<usize>, ext_height: Option<usize>)
@@ -90,11 +184,14 @@ impl Controller {
     F: FnMut(usize, usize, &Char),
   {
     let (left, top, width, height) = self.viewport.rect();
+    let space = Char::from(SPACE);
     let mut last_row_index = 0;
-    for row in self.plane.content().iter().skip(top).take(height) {
+    for row in self.planes[self.current].content().iter().skip(top).take(height) {
       let mut last_col_index = 0;
       for chr in row.iter().skip(left).take(width) {
-        f(last_col_index, last_row_index, chr);
+        // halves of wide characters cut by the edges of the viewport are displayed as spaces
+        let is_cut = (last_col_index == 0 && chr.is_continuation()) || (last_col_index + 1 == width && chr.width() > 1);
+        f(last_col_index, last_row_index, if is_cut { &space } else { chr });
         last_col_index += 1;
       }
       if let Some(chr_fill) = fill.as_ref() {
@@ -116,174 +213,517 @@ impl Controller {
   }
 
   pub fn content_region(&mut self) -> &Region {
-    self.plane.region()
+    self.planes[self.current].region()
   }
 
   pub fn cursor_move_left(&mut self) -> Updates {
-    let cursor_before = *self.plane.cursor();
-    if self.plane.cursor_move_left() {
-      self.updates(cursor_before)
-    } else {
-      Updates::default()
-    }
+    self.cursor_move(Plane::cursor_move_left)
   }
 
   pub fn cursor_move_right(&mut self) -> Updates {
-    let cursor_before = *self.plane.cursor();
-    if self.plane.cursor_move_right() {
-      self.updates(cursor_before)
-    } else {
-      Updates::default()
-    }
+    self.cursor_move(Plane::cursor_move_right)
   }
 
   pub fn cursor_move_up(&mut self) -> Updates {
-    let cursor_before = *self.plane.cursor();
-    if self.plane.cursor_move_up() {
-      self.updates(cursor_before)
-    } else {
-      Updates::default()
-    }
+    self.cursor_move(Plane::cursor_move_up)
   }
 
   pub fn cursor_move_down(&mut self) -> Updates {
-    let cursor_before = *self.plane.cursor();
-    if self.plane.cursor_move_down() {
-      self.updates(cursor_before)
-    } else {
-      Updates::default()
-    }
+    self.cursor_move(Plane::cursor_move_down)
   }
 
   pub fn cursor_move_cell_start(&mut self) -> Updates {
-    let cursor_before = *self.plane.cursor();
-    if self.plane.cursor_move_cell_start() {
-      self.updates(cursor_before)
-    } else {
-      Updates::default()
-    }
+    self.cursor_move(Plane::cursor_move_cell_start)
   }
 
   pub fn cursor_move_cell_end(&mut self) -> Updates {
-    let cursor_before = *self.plane.cursor();
-    if self.plane.cursor_move_cell_end() {
-      self.updates(cursor_before)
-    } else {
-      Updates::default()
-    }
+    self.cursor_move(Plane::cursor_move_cell_end)
   }
 
   pub fn cursor_move_cell_top(&mut self) -> Updates {
-    let cursor_before = *self.plane.cursor();
-    if self.plane.cursor_move_cell_top() {
-      self.updates(cursor_before)
-    } else {
-      Updates::default()
-    }
+    self.cursor_move(Plane::cursor_move_cell_top)
   }
 
   pub fn cursor_move_cell_bottom(&mut self) -> Updates {
-    let cursor_before = *self.plane.cursor();
-    if self.plane.cursor_move_cell_bottom() {
-      self.updates(cursor_before)
-    } else {
-      Updates::default()
-    }
+    self.cursor_move(Plane::cursor_move_cell_bottom)
   }
 
   pub fn cursor_move_cell_next(&mut self) -> Updates {
-    let cursor_before = *self.plane.cursor();
-    if self.plane.cursor_move_cell_next() {
-      self.updates(cursor_before)
-    } else {
-      Updates::default()
-    }
+    self.cursor_move(Plane::cursor_move_cell_next)
   }
 
   pub fn cursor_move_cell_prev(&mut self) -> Updates {
-    let cursor_before = *self.plane.cursor();
-    if self.plane.cursor_move_cell_prev() {
-      self.updates(cursor_before)
-    } else {
-      Updates::default()
-    }
+    self.cursor_move(Plane::cursor_move_cell_prev)
+  }
+
+  /// Moves the cursor to the next rule (to the next rule column in vertical decision tables).
+  pub fn cursor_move_rule_next(&mut self) -> Updates {
+    self.cursor_move(Plane::cursor_move_rule_next)
+  }
+
+  /// Moves the cursor to the previous rule (to the previous rule column in vertical decision tables).
+  pub fn cursor_move_rule_prev(&mut self) -> Updates {
+    self.cursor_move(Plane::cursor_move_rule_prev)
   }
 
   pub fn cursor_move_row_start(&mut self) -> Updates {
-    let cursor_before = *self.plane.cursor();
-    if self.plane.cursor_move_row_start() {
-      self.updates(cursor_before)
-    } else {
-      Updates::default()
-    }
+    self.cursor_move(Plane::cursor_move_row_start)
   }
 
   pub fn cursor_move_row_end(&mut self) -> Updates {
-    let cursor_before = *self.plane.cursor();
-    if self.plane.cursor_move_row_end() {
-      self.updates(cursor_before)
-    } else {
-      Updates::default()
-    }
+    self.cursor_move(Plane::cursor_move_row_end)
   }
 
   pub fn cursor_move_col_start(&mut self) -> Updates {
-    let cursor_before = *self.plane.cursor();
-    self.plane.cursor_move_col_start();
-    self.updates(cursor_before)
+    let cursor_before = *self.planes[self.current].cursor();
+    let selection_changed = self.planes[self.current].selection_clear();
+    self.planes[self.current].cursor_move_col_start();
+    self.planes[self.current].cursor_skip_continuation();
+    self.updates(cursor_before).with_selection_changed(selection_changed)
   }
 
   pub fn cursor_move_col_end(&mut self) -> Updates {
-    let cursor_before = *self.plane.cursor();
-    self.plane.cursor_move_col_end();
-    self.updates(cursor_before)
+    let cursor_before = *self.planes[self.current].cursor();
+    let selection_changed = self.planes[self.current].selection_clear();
+    self.planes[self.current].cursor_move_col_end();
+    self.planes[self.current].cursor_skip_continuation();
+    self.updates(cursor_before).with_selection_changed(selection_changed)
+  }
+
+  /// Returns the selected region, if any.
+  ///
+  /// The selection starts at the position of the cursor when the first selecting move
+  /// is done and ends at the current cursor position. When the selection spans multiple cells,
+  /// the region is expanded to cover all selected cells.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::Controller;
+  ///
+  /// let mut controller = Controller::new("┌─────┬─────┐\n│ A B │ C D │\n└─────┴─────┘");
+  /// assert_eq!(None, controller.selection());
+  /// controller.select_right();
+  /// controller.select_right();
+  /// assert_eq!("(1, 1, 2, 1)", controller.selection().unwrap().to_string());
+  /// controller.select_cell_end();
+  /// controller.select_right();
+  /// controller.select_right();
+  /// assert_eq!("(1, 1, 11, 1)", controller.selection().unwrap().to_string());
+  /// ```
+  pub fn selection(&self) -> Option<Region> {
+    self.planes[self.current].selection()
+  }
+
+  /// Clears the selection.
+  pub fn selection_clear(&mut self) -> Updates {
+    let cursor_before = *self.planes[self.current].cursor();
+    let selection_changed = self.planes[self.current].selection_clear();
+    self.updates(cursor_before).with_selection_changed(selection_changed)
+  }
+
+  /// Extends the selection moving the cursor one character left.
+  pub fn select_left(&mut self) -> Updates {
+    self.select(Plane::cursor_move_left)
+  }
+
+  /// Extends the selection moving the cursor one character right.
+  pub fn select_right(&mut self) -> Updates {
+    self.select(Plane::cursor_move_right)
+  }
+
+  /// Extends the selection moving the cursor one row up.
+  pub fn select_up(&mut self) -> Updates {
+    self.select(Plane::cursor_move_up)
+  }
+
+  /// Extends the selection moving the cursor one row down.
+  pub fn select_down(&mut self) -> Updates {
+    self.select(Plane::cursor_move_down)
+  }
+
+  /// Extends the selection moving the cursor to the beginning of the cell.
+  pub fn select_cell_start(&mut self) -> Updates {
+    self.select(Plane::cursor_move_cell_start)
+  }
+
+  /// Extends the selection moving the cursor to the end of the cell.
+  pub fn select_cell_end(&mut self) -> Updates {
+    self.select(Plane::cursor_move_cell_end)
+  }
+
+  /// Extends the selection moving the cursor to the top row of the cell.
+  pub fn select_cell_top(&mut self) -> Updates {
+    self.select(Plane::cursor_move_cell_top)
+  }
+
+  /// Extends the selection moving the cursor to the bottom row of the cell.
+  pub fn select_cell_bottom(&mut self) -> Updates {
+    self.select(Plane::cursor_move_cell_bottom)
   }
 
   pub fn cursor_toggle_caret_block(&mut self) {
-    self.plane.cursor_toggle_caret_block();
+    self.planes[self.current].cursor_toggle_caret_block();
   }
 
   pub fn cursor_toggle_caret_under_score(&mut self) {
-    self.plane.cursor_toggle_caret_under_score();
+    self.planes[self.current].cursor_toggle_caret_under_score();
   }
 
   /// Returns the character pointed by the cursor.
   pub fn cursor_char(&self) -> Option<char> {
-    self.plane.cursor_char().map(|ch| ch.char())
+    self.planes[self.current].cursor_char().map(|ch| ch.char())
   }
 
   /// Inserts a single character.
+  ///
+  /// Consecutive characters typed one after another are undone in a single step.
+  /// The text stays aligned when the alignment is kept in the column, see [Controller::align_column].
   pub fn insert_char(&mut self, ch: char) -> Updates {
-    let cursor_before = *self.plane.cursor();
-    let content_changed = if cursor_before.insert_mode() {
-      self.plane.insert_char(ch)
-    } else if cursor_before.override_mode() {
-      self.plane.override_char(ch)
-    } else {
-      false
-    };
-    self.updates(cursor_before).with_content_changed(content_changed)
+    self.edit(Edit::Typing, |plane| {
+      let cursor = *plane.cursor();
+      let changed = if cursor.insert_mode() {
+        plane.insert_char(ch)
+      } else if cursor.override_mode() {
+        plane.override_char(ch)
+      } else {
+        false
+      };
+      if changed {
+        plane.keep_alignment();
+      }
+      changed
+    })
   }
 
   /// Splits the line at the cursor position.
   pub fn split_line(&mut self) -> Updates {
-    let cursor_before = *self.plane.cursor();
-    let changed = self.plane.split_line();
-    self.updates(cursor_before).with_content_changed(changed)
+    self.edit(Edit::Other, Plane::split_line)
+  }
+
+  /// Joins the current line with the line in a row above.
+  pub fn unsplit_line(&mut self) -> Updates {
+    self.edit(Edit::Other, Plane::unsplit_line)
   }
 
   /// Deletes a single character before the cursor.
+  ///
+  /// When the cursor is placed at the beginning of the line in a cell,
+  /// then the current line is joined with the line above.
   pub fn delete_char_before_cursor(&mut self) -> Updates {
-    let cursor_before = *self.plane.cursor();
-    let changed = self.plane.delete_char_before_cursor();
-    self.updates(cursor_before).with_content_changed(changed)
+    self.edit(Edit::Other, |plane| {
+      let changed = plane.delete_char_before_cursor();
+      if changed {
+        plane.keep_alignment();
+      }
+      changed
+    })
   }
 
   /// Deletes a single character under the cursor.
+  ///
+  /// When the caret is placed at the end of the line in a cell,
+  /// then the line below is joined with the current line.
   pub fn delete_char_under_cursor(&mut self) -> Updates {
-    let cursor_before = *self.plane.cursor();
-    let changed = self.plane.delete_char_under_cursor();
-    self.updates(cursor_before).with_content_changed(changed)
+    self.edit(Edit::Other, |plane| {
+      let changed = plane.delete_char_under_cursor();
+      if changed {
+        plane.keep_alignment();
+      }
+      changed
+    })
+  }
+
+  /// Aligns the text in the selected cells, or in the cell pointed by the cursor when nothing is selected.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::{Alignment, Controller};
+  ///
+  /// let mut controller = Controller::new("┌───────┐\n│ A     │\n└───────┘");
+  /// controller.align(Alignment::Right);
+  /// assert_eq!("┌───────┐\n│     A │\n└───────┘", controller.text());
+  /// ```
+  pub fn align(&mut self, alignment: Alignment) -> Updates {
+    let Some(region) = self.planes[self.current].selection().or_else(|| self.planes[self.current].cursor_cell()) else {
+      return Updates::default();
+    };
+    self.edit(Edit::Other, |plane| plane.align(&region, alignment))
+  }
+
+  /// Aligns the text in all cells of the column pointed by the cursor.
+  ///
+  /// When `keep` is `true`, then the text in this column stays aligned
+  /// while typing and deleting characters.
+  pub fn align_column(&mut self, alignment: Alignment, keep: bool) -> Updates {
+    self.edit(Edit::Other, |plane| plane.align_column(alignment, keep))
+  }
+
+  /// Inserts a new empty rule above the rule pointed by the cursor.
+  pub fn insert_rule_above(&mut self) -> Updates {
+    self.edit(Edit::Other, Plane::insert_rule_above)
+  }
+
+  /// Inserts a new empty rule below the rule pointed by the cursor.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::Controller;
+  ///
+  /// let mut controller = Controller::new("┌───┐\n│ T │\n╞═══╡\n│ 1 │\n└───┘");
+  /// controller.cursor_move_down();
+  /// controller.insert_rule_below();
+  /// assert_eq!("┌───┐\n│ T │\n╞═══╡\n│ 1 │\n├───┤\n│ 2 │\n└───┘", controller.text());
+  /// ```
+  pub fn insert_rule_below(&mut self) -> Updates {
+    self.edit(Edit::Other, Plane::insert_rule_below)
+  }
+
+  /// Deletes the rule pointed by the cursor, the only rule is never deleted.
+  pub fn delete_rule(&mut self) -> Updates {
+    self.edit(Edit::Other, Plane::delete_rule)
+  }
+
+  /// Swaps the rule pointed by the cursor with the rule above it.
+  pub fn move_rule_up(&mut self) -> Updates {
+    self.edit(Edit::Other, Plane::move_rule_up)
+  }
+
+  /// Swaps the rule pointed by the cursor with the rule below it.
+  pub fn move_rule_down(&mut self) -> Updates {
+    self.edit(Edit::Other, Plane::move_rule_down)
+  }
+
+  /// Transposes the decision table between horizontal and vertical orientation.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::Controller;
+  ///
+  /// let mut controller = Controller::new("┌───┬───╥───┐\n│ F │ a ║ b │\n╞═══╪═══╬═══╡\n│ 1 │ 2 ║ 3 │\n└───┴───╨───┘");
+  /// controller.transpose();
+  /// assert_eq!("┌───╥───┐\n│ F ║ 1 │\n├───╫───┤\n│ a ║ 2 │\n╞═══╬═══╡\n│ b ║ 3 │\n└───╨───┘", controller.text());
+  /// ```
+  pub fn transpose(&mut self) -> Updates {
+    self.edit(Edit::Other, Plane::transpose)
+  }
+
+  /// Reformats the decision table in place, with the specified padding on both sides
+  /// of the text in cells, the text keeps its offset in cells, see [Plane::reformat].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::Controller;
+  ///
+  /// let mut controller = Controller::new("┌─────┬───────╥───┐\n│  F  │ a     ║ b │\n╞═════╪═══════╬═══╡\n│ 1   │    2  ║ 3 │\n│     │       ║   │\n└─────┴───────╨───┘");
+  /// controller.reformat(1);
+  /// assert_eq!("┌────┬────╥───┐\n│  F │ a  ║ b │\n╞════╪════╬═══╡\n│ 1  │  2 ║ 3 │\n└────┴────╨───┘", controller.text());
+  /// ```
+  pub fn reformat(&mut self, padding: usize) -> Updates {
+    self.edit(Edit::Other, |plane| plane.reformat(padding))
+  }
+
+  /// Normalizes the frame of the decision table to the canonical light and double lines.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::Controller;
+  ///
+  /// let mut controller = Controller::new("╭───┬───╥───╮\n│ F │ a ║ b │\n╞═══╪═══╬═══╡\n│ 1 │ 2 ║ 3 │\n╰───┴───╨───╯");
+  /// controller.normalize();
+  /// assert_eq!("┌───┬───╥───┐\n│ F │ a ║ b │\n╞═══╪═══╬═══╡\n│ 1 │ 2 ║ 3 │\n└───┴───╨───┘", controller.text());
+  /// ```
+  pub fn normalize(&mut self) -> Updates {
+    self.edit(Edit::Other, Plane::normalize)
+  }
+
+  /// Inserts a new input column on the left side of the double line separating inputs from outputs.
+  pub fn insert_input_column(&mut self) -> Updates {
+    self.edit(Edit::Other, Plane::insert_input_column)
+  }
+
+  /// Inserts a new output column on the right side of the double line separating inputs from outputs.
+  pub fn insert_output_column(&mut self) -> Updates {
+    self.edit(Edit::Other, Plane::insert_output_column)
+  }
+
+  /// Inserts a new annotation column on the right side of the decision table.
+  pub fn insert_annotation_column(&mut self) -> Updates {
+    self.edit(Edit::Other, Plane::insert_annotation_column)
+  }
+
+  /// Deletes the column pointed by the cursor.
+  ///
+  /// The hit policy column, the only input column and the only output column are never deleted.
+  pub fn delete_column(&mut self) -> Updates {
+    self.edit(Edit::Other, Plane::delete_column)
+  }
+
+  /// Moves the column pointed by the cursor one position left, within its section.
+  pub fn move_column_left(&mut self) -> Updates {
+    self.edit(Edit::Other, Plane::move_column_left)
+  }
+
+  /// Moves the column pointed by the cursor one position right, within its section.
+  pub fn move_column_right(&mut self) -> Updates {
+    self.edit(Edit::Other, Plane::move_column_right)
+  }
+
+  /// Copies the selected content to the clipboard.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::{Clipboard, Controller};
+  ///
+  /// let mut controller = Controller::new("┌─────┐\n│ A B │\n└─────┘");
+  /// controller.select_cell_end();
+  /// controller.copy();
+  /// assert_eq!(Some(&Clipboard::Text(vec![" A B".to_string()])), controller.clipboard());
+  /// ```
+  pub fn copy(&mut self) -> Updates {
+    if let Some(region) = self.planes[self.current].selection() {
+      self.clipboard = Some(self.planes[self.current].copy(&region));
+    }
+    Updates::default()
+  }
+
+  /// Moves the selected content to the clipboard.
+  pub fn cut(&mut self) -> Updates {
+    let Some(region) = self.planes[self.current].selection() else {
+      return Updates::default();
+    };
+    self.clipboard = Some(self.planes[self.current].copy(&region));
+    self.edit(Edit::Other, |plane| plane.clear(&region))
+  }
+
+  /// Pastes the content of the clipboard at the cursor position.
+  pub fn paste(&mut self) -> Updates {
+    let Some(clipboard) = self.clipboard.clone() else {
+      return Updates::default();
+    };
+    self.edit(Edit::Other, |plane| plane.paste(&clipboard))
+  }
+
+  /// Returns the content of the clipboard.
+  pub fn clipboard(&self) -> Option<&Clipboard> {
+    self.clipboard.as_ref()
+  }
+
+  /// Reverts the last edit operation, restoring the content and the cursor position.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::Controller;
+  ///
+  /// let mut controller = Controller::new("┌───┐\n│ A │\n└───┘");
+  /// controller.insert_char('B');
+  /// assert_eq!("┌───┐\n│B A│\n└───┘", controller.text());
+  /// controller.undo();
+  /// assert_eq!("┌───┐\n│ A │\n└───┘", controller.text());
+  /// controller.redo();
+  /// assert_eq!("┌───┐\n│B A│\n└───┘", controller.text());
+  /// ```
+  pub fn undo(&mut self) -> Updates {
+    let cursor_before = *self.planes[self.current].cursor();
+    let Some(snapshot) = self.histories[self.current].undo(self.planes[self.current].snapshot()) else {
+      return Updates::default();
+    };
+    self.planes[self.current].restore(snapshot);
+    self.updates(cursor_before).with_content_changed(true)
+  }
+
+  /// Reapplies the last edit operation reverted by [undo](Self::undo).
+  pub fn redo(&mut self) -> Updates {
+    let cursor_before = *self.planes[self.current].cursor();
+    let Some(snapshot) = self.histories[self.current].redo(self.planes[self.current].snapshot()) else {
+      return Updates::default();
+    };
+    self.planes[self.current].restore(snapshot);
+    self.updates(cursor_before).with_content_changed(true)
+  }
+
+  /// Returns `true` when there is an edit operation that can be undone.
+  pub fn can_undo(&self) -> bool {
+    self.histories[self.current].can_undo()
+  }
+
+  /// Returns `true` when there is an edit operation that can be redone.
+  pub fn can_redo(&self) -> bool {
+    self.histories[self.current].can_redo()
+  }
+
+  /// Switches editing to the decision table with the specified index, when such a table exists.
+  fn cursor_move_table(&mut self, index: usize) -> Updates {
+    if index >= self.planes.len() || index == self.current {
+      return Updates::default();
+    }
+    let cursor_before = *self.planes[self.current].cursor();
+    self.current = index;
+    // the viewport is scrolled from the top left corner of the selected decision table to its cursor
+    let offset_before = self.viewport.offset();
+    let (width, height) = self.viewport.size();
+    self.viewport = Region::new(0, 0, width, height);
+    self.viewport.shift_right_when_needed(self.cursor().col(), self.margin_right);
+    self.viewport.shift_down_when_needed(self.cursor().row(), self.margin_bottom);
+    self
+      .updates(cursor_before)
+      .with_viewport_pos(self.viewport.offset() != offset_before)
+      .with_content_changed(true)
+  }
+
+  /// Moves the cursor and clears the selection.
+  fn cursor_move<F>(&mut self, f: F) -> Updates
+  where
+    F: FnOnce(&mut Plane) -> bool,
+  {
+    let cursor_before = *self.planes[self.current].cursor();
+    let selection_changed = self.planes[self.current].selection_clear();
+    let moved = f(&mut self.planes[self.current]);
+    self.planes[self.current].cursor_skip_continuation();
+    if moved || selection_changed {
+      self.updates(cursor_before).with_selection_changed(selection_changed)
+    } else {
+      Updates::default()
+    }
+  }
+
+  /// Moves the cursor and extends the selection.
+  fn select<F>(&mut self, f: F) -> Updates
+  where
+    F: FnOnce(&mut Plane) -> bool,
+  {
+    let cursor_before = *self.planes[self.current].cursor();
+    self.planes[self.current].selection_start();
+    let moved = f(&mut self.planes[self.current]);
+    self.planes[self.current].cursor_skip_continuation();
+    if moved {
+      self.updates(cursor_before).with_selection_changed(true)
+    } else {
+      Updates::default()
+    }
+  }
+
+  /// Performs the edit operation on the plane and records it in the history
+  /// when the content was changed.
+  fn edit<F>(&mut self, edit: Edit, f: F) -> Updates
+  where
+    F: FnOnce(&mut Plane) -> bool,
+  {
+    let cursor_before = *self.planes[self.current].cursor();
+    let selection_changed = self.planes[self.current].selection_clear();
+    let snapshot = (!self.histories[self.current].continues(edit, cursor_before.pos())).then(|| self.planes[self.current].snapshot());
+    let changed = f(&mut self.planes[self.current]);
+    if changed {
+      self.histories[self.current].record(snapshot, edit, self.planes[self.current].cursor().pos());
+    }
+    self.updates(cursor_before).with_content_changed(changed).with_selection_changed(selection_changed)
   }
 
   fn updates(&mut self, cursor_before: Cursor) -> Updates {
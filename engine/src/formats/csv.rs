@@ -0,0 +1,268 @@
+//! # Comma-separated values
+//!
+//! The decision table is written as CSV using the following convention:
+//!
+//! - the first row contains the hit policy followed by the role of each column: `input`, `output` or `annotation`,
+//! - the second row contains the information item name (or an empty cell) followed by the names of columns,
+//! - the name of the compound output is written before the name of each output column, followed by ` / `,
+//! - allowed values (if present) are written in the third row, having an empty first cell,
+//! - each rule is written in a separate row, starting with the rule number,
+//! - lines of multi-line cells are joined with spaces.
+//!
+//! ```text
+//! U,input,input,output
+//! SLA,YearsAsCustomer,NumberOfUnits,SLA
+//! ,[0..100],[0..1000000],"1,2"
+//! 1,<2,<1000,1
+//! 2,>=2,>=1000,2
+//! ```
+
+use super::tabular::{Tabular, LABEL_SEPARATOR};
+use crate::{Cell, DecisionTable, HitPolicy, TableError};
+
+/// Role of input columns.
+const INPUT: &str = "input";
+
+/// Role of output columns.
+const OUTPUT: &str = "output";
+
+/// Role of annotation columns.
+const ANNOTATION: &str = "annotation";
+
+/// Quoting of exported CSV values.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum CsvQuoting {
+  /// Values are quoted only when they contain the delimiter, the quote or a line break.
+  #[default]
+  Necessary,
+  /// All values are quoted.
+  Always,
+}
+
+/// Options of reading and writing CSV.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct CsvOptions {
+  /// Character separating values in a row.
+  delimiter: char,
+  /// Character enclosing quoted values.
+  quote: char,
+  /// Quoting of exported values.
+  quoting: CsvQuoting,
+}
+
+impl Default for CsvOptions {
+  /// Returns options with comma as the delimiter and double quote as the quote.
+  fn default() -> Self {
+    Self {
+      delimiter: ',',
+      quote: '"',
+      quoting: CsvQuoting::default(),
+    }
+  }
+}
+
+impl CsvOptions {
+  /// Sets the character separating values in a row.
+  pub fn with_delimiter(mut self, delimiter: char) -> Self {
+    self.delimiter = delimiter;
+    self
+  }
+
+  /// Sets the character enclosing quoted values.
+  pub fn with_quote(mut self, quote: char) -> Self {
+    self.quote = quote;
+    self
+  }
+
+  /// Sets the quoting of exported values.
+  pub fn with_quoting(mut self, quoting: CsvQuoting) -> Self {
+    self.quoting = quoting;
+    self
+  }
+
+  /// Returns the character separating values in a row.
+  pub fn delimiter(&self) -> char {
+    self.delimiter
+  }
+
+  /// Returns the character enclosing quoted values.
+  pub fn quote(&self) -> char {
+    self.quote
+  }
+
+  /// Returns the quoting of exported values.
+  pub fn quoting(&self) -> CsvQuoting {
+    self.quoting
+  }
+}
+
+/// Exports the decision table as CSV.
+///
+/// # Examples
+///
+/// ```
+/// use dtee::*;
+///
+/// let text = "┌───┬───╥───┐\n│ F │ a ║ b │\n╞═══╪═══╬═══╡\n│ 1 │ x ║ 3 │\n└───┴───╨───┘";
+/// let decision_table = text.parse::<DecisionTable>().unwrap();
+/// assert_eq!("F,input,output\n,a,b\n1,x,3\n", export_csv(&decision_table, CsvOptions::default()));
+/// let options = CsvOptions::default().with_delimiter(';').with_quoting(CsvQuoting::Always);
+/// assert_eq!("\"F\";\"input\";\"output\"\n\"\";\"a\";\"b\"\n\"1\";\"x\";\"3\"\n", export_csv(&decision_table, options));
+/// ```
+pub fn export_csv(decision_table: &DecisionTable, options: CsvOptions) -> String {
+  let input_count = decision_table.input_clauses().len();
+  let output_count = decision_table.output_clauses().len();
+  let annotation_count = decision_table.annotation_clauses().len();
+  let output_label = decision_table.output_label().filter(|_| output_count > 1);
+  let mut rows = vec![];
+
+  let mut roles = vec![decision_table.hit_policy().to_string()];
+  roles.extend([INPUT].repeat(input_count).into_iter().map(String::from));
+  roles.extend([OUTPUT].repeat(output_count).into_iter().map(String::from));
+  roles.extend([ANNOTATION].repeat(annotation_count).into_iter().map(String::from));
+  rows.push(roles);
+
+  let mut names = vec![decision_table.information_item_name().map_or(String::new(), flatten)];
+  names.extend(decision_table.input_clauses().iter().map(|clause| flatten(clause.input_expression())));
+  names.extend(decision_table.output_clauses().iter().map(|clause| match output_label {
+    Some(label) => format!("{}{LABEL_SEPARATOR}{}", flatten(label), flatten(clause.name())),
+    None => flatten(clause.name()),
+  }));
+  names.extend(decision_table.annotation_clauses().iter().map(|clause| flatten(clause.name())));
+  rows.push(names);
+
+  let allowed_values = decision_table
+    .input_clauses()
+    .iter()
+    .map(|clause| clause.allowed_values())
+    .chain(decision_table.output_clauses().iter().map(|clause| clause.allowed_values()))
+    .collect::<Vec<Option<&Cell>>>();
+  if allowed_values.iter().any(Option::is_some) {
+    let mut row = vec![String::new()];
+    row.extend(allowed_values.iter().map(|cell| cell.map_or(String::new(), flatten)));
+    row.extend([""].repeat(annotation_count).into_iter().map(String::from));
+    rows.push(row);
+  }
+
+  for (index, rule) in decision_table.rules().iter().enumerate() {
+    let mut row = vec![(index + 1).to_string()];
+    row.extend(rule.input_entries().iter().chain(rule.output_entries()).chain(rule.annotation_entries()).map(flatten));
+    rows.push(row);
+  }
+
+  rows
+    .iter()
+    .map(|row| {
+      let values = row.iter().map(|value| quote(value, options)).collect::<Vec<String>>();
+      format!("{}\n", values.join(&options.delimiter.to_string()))
+    })
+    .collect()
+}
+
+/// Imports the decision table from CSV.
+///
+/// # Examples
+///
+/// ```
+/// use dtee::*;
+///
+/// let csv = "F;input;output\nAdult;Age;Adult\n1;<18;false\n2;>=18;true\n";
+/// let decision_table = import_csv(csv, CsvOptions::default().with_delimiter(';')).unwrap();
+/// assert_eq!("Adult", decision_table.information_item_name().unwrap().text());
+/// assert_eq!("true", decision_table.rules()[1].output_entries()[0].text());
+/// ```
+pub fn import_csv(csv: &str, options: CsvOptions) -> Result<DecisionTable, TableError> {
+  let mut rows = split_rows(csv, options)?.into_iter();
+  let roles = rows.next().ok_or(TableError::InvalidCsv("missing row with column roles".to_string()))?;
+  let names = rows.next().ok_or(TableError::InvalidCsv("missing row with column names".to_string()))?;
+  let hit_policy = roles[0].parse::<HitPolicy>()?;
+  let column_count = roles.len();
+  if names.len() > column_count {
+    return Err(TableError::InvalidCsv("too many cells in row 2".to_string()));
+  }
+
+  // classify columns by their roles
+  let mut input_names = vec![];
+  let mut output_names = vec![];
+  let mut annotation_names = vec![];
+  for (index, role) in roles.iter().enumerate().skip(1) {
+    let name = names.get(index).cloned().unwrap_or_default();
+    match role.trim().to_lowercase().as_str() {
+      INPUT if output_names.is_empty() && annotation_names.is_empty() => input_names.push(name),
+      OUTPUT if annotation_names.is_empty() => output_names.push(name),
+      ANNOTATION => annotation_names.push(name),
+      INPUT | OUTPUT => return Err(TableError::InvalidCsv(format!("{role} column '{name}' out of order"))),
+      other => return Err(TableError::InvalidCsv(format!("invalid column role '{other}'"))),
+    }
+  }
+  Tabular {
+    hit_policy,
+    name: names.first().cloned(),
+    input_names,
+    output_names,
+    annotation_names,
+  }
+  .build(rows.collect(), 3, TableError::InvalidCsv)
+}
+
+/// Returns the text of the cell with lines joined with spaces.
+fn flatten(cell: &Cell) -> String {
+  cell.lines().join(" ")
+}
+
+/// Quotes the value when required by the options.
+fn quote(value: &str, options: CsvOptions) -> String {
+  let required = value.contains(options.delimiter) || value.contains(options.quote) || value.contains('\n') || value.contains('\r');
+  if required || options.quoting == CsvQuoting::Always {
+    let quote = options.quote.to_string();
+    format!("{quote}{}{quote}", value.replace(&quote, &quote.repeat(2)))
+  } else {
+    value.to_string()
+  }
+}
+
+/// Splits CSV into rows of values, empty lines are skipped.
+fn split_rows(csv: &str, options: CsvOptions) -> Result<Vec<Vec<String>>, TableError> {
+  let mut rows = vec![];
+  let mut row = vec![];
+  let mut value = String::new();
+  let mut quoted = false;
+  let mut chars = csv.chars().peekable();
+  while let Some(ch) = chars.next() {
+    if quoted {
+      if ch == options.quote {
+        if chars.peek() == Some(&options.quote) {
+          value.push(ch);
+          chars.next();
+        } else {
+          quoted = false;
+        }
+      } else {
+        value.push(ch);
+      }
+    } else if ch == options.quote {
+      quoted = true;
+    } else if ch == options.delimiter {
+      row.push(std::mem::take(&mut value).trim().to_string());
+    } else if ch == '\n' || ch == '\r' {
+      if ch == '\r' && chars.peek() == Some(&'\n') {
+        chars.next();
+      }
+      row.push(std::mem::take(&mut value).trim().to_string());
+      if row.iter().any(|value| !value.is_empty()) {
+        rows.push(std::mem::take(&mut row));
+      }
+      row.clear();
+    } else {
+      value.push(ch);
+    }
+  }
+  if quoted {
+    return Err(TableError::InvalidCsv("unterminated quoted value".to_string()));
+  }
+  row.push(value.trim().to_string());
+  if row.iter().any(|value| !value.is_empty()) {
+    rows.push(row);
+  }
+  Ok(rows)
+}
@@ -0,0 +1,293 @@
+//! # DMN 1.x XML
+
+use crate::{AnnotationClause, BuiltinAggregator, Cell, DecisionTable, HitPolicy, InputClause, Orientation, OutputClause, Rule, TableError};
+use roxmltree::Node;
+use std::fmt::Write;
+
+/// Namespace of the DMN model.
+const DMN_NAMESPACE: &str = "https://www.omg.org/spec/DMN/20191111/MODEL/";
+
+/// Namespace of the definitions exported by the editor.
+const DEFINITIONS_NAMESPACE: &str = "https://decision-toolkit.org/dte";
+
+/// Preferred orientation of vertical decision tables, where rules are columns.
+const RULE_AS_COLUMN: &str = "Rule-as-Column";
+
+/// Preferred orientation of decision tables rendered as crosstabs.
+const CROSS_TABLE: &str = "CrossTable";
+
+/// Exports the decision table as DMN 1.x XML.
+///
+/// The result contains the `<definitions>` element with a single `<decision>`,
+/// named after the information item name (or `decision` when the name is absent),
+/// containing the `<decisionTable>` element with inputs, outputs, annotations and rules.
+///
+/// # Examples
+///
+/// ```
+/// use dtee::*;
+///
+/// let text = "┌───┬───╥───┐\n│ F │ a ║ b │\n╞═══╪═══╬═══╡\n│ 1 │<2 ║ 3 │\n└───┴───╨───┘";
+/// let dmn = export_dmn(&text.parse::<DecisionTable>().unwrap());
+/// assert!(dmn.contains(r#"<decisionTable id="_decision_table" hitPolicy="FIRST">"#));
+/// assert!(dmn.contains("<inputEntry id=\"_rule_1_input_entry_1\">\n          <text>&lt;2</text>"));
+/// ```
+pub fn export_dmn(decision_table: &DecisionTable) -> String {
+  let mut xml = String::new();
+  let name = decision_table.information_item_name().map_or("decision".to_string(), single_line);
+  let _ = writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+  let _ = writeln!(
+    xml,
+    r#"<definitions xmlns="{DMN_NAMESPACE}" namespace="{DEFINITIONS_NAMESPACE}" id="_definitions" name="{}">"#,
+    escape(&name)
+  );
+  let _ = writeln!(xml, r#"  <decision id="_decision" name="{}">"#, escape(&name));
+  let mut attributes = format!(r#"id="_decision_table" hitPolicy="{}""#, hit_policy_name(decision_table.hit_policy()));
+  if let HitPolicy::Collect(Some(aggregator)) = decision_table.hit_policy() {
+    let _ = write!(attributes, r#" aggregation="{}""#, aggregator_name(aggregator));
+  }
+  if let Some(output_label) = decision_table.output_label() {
+    let _ = write!(attributes, r#" outputLabel="{}""#, escape(&single_line(output_label)));
+  }
+  match decision_table.orientation() {
+    Orientation::Horizontal => {}
+    Orientation::Vertical => {
+      let _ = write!(attributes, r#" preferredOrientation="{RULE_AS_COLUMN}""#);
+    }
+    Orientation::Crosstab => {
+      let _ = write!(attributes, r#" preferredOrientation="{CROSS_TABLE}""#);
+    }
+  }
+  let _ = writeln!(xml, "    <decisionTable {attributes}>");
+  for (index, input_clause) in decision_table.input_clauses().iter().enumerate() {
+    let id = format!("_input_{}", index + 1);
+    let expression = single_line(input_clause.input_expression());
+    let _ = writeln!(xml, r#"      <input id="{id}" label="{}">"#, escape(&expression));
+    let _ = writeln!(xml, r#"        <inputExpression id="{id}_expression">"#);
+    write_text(&mut xml, 10, &expression);
+    let _ = writeln!(xml, "        </inputExpression>");
+    write_values(&mut xml, "inputValues", &id, input_clause.allowed_values());
+    let _ = writeln!(xml, "      </input>");
+  }
+  for (index, output_clause) in decision_table.output_clauses().iter().enumerate() {
+    let id = format!("_output_{}", index + 1);
+    let name = escape(&single_line(output_clause.name()));
+    match output_clause.allowed_values().filter(|cell| !cell.is_empty()) {
+      Some(_) => {
+        let _ = writeln!(xml, r#"      <output id="{id}" name="{name}" label="{name}">"#);
+        write_values(&mut xml, "outputValues", &id, output_clause.allowed_values());
+        let _ = writeln!(xml, "      </output>");
+      }
+      None => {
+        let _ = writeln!(xml, r#"      <output id="{id}" name="{name}" label="{name}"/>"#);
+      }
+    }
+  }
+  for annotation_clause in decision_table.annotation_clauses() {
+    let _ = writeln!(xml, r#"      <annotation name="{}"/>"#, escape(&single_line(annotation_clause.name())));
+  }
+  for (rule_index, rule) in decision_table.rules().iter().enumerate() {
+    let id = format!("_rule_{}", rule_index + 1);
+    let _ = writeln!(xml, r#"      <rule id="{id}">"#);
+    write_entries(&mut xml, "inputEntry", &format!("{id}_input_entry"), rule.input_entries());
+    write_entries(&mut xml, "outputEntry", &format!("{id}_output_entry"), rule.output_entries());
+    for cell in rule.annotation_entries() {
+      let _ = writeln!(xml, "        <annotationEntry>");
+      write_text(&mut xml, 10, cell.text());
+      let _ = writeln!(xml, "        </annotationEntry>");
+    }
+    let _ = writeln!(xml, "      </rule>");
+  }
+  let _ = writeln!(xml, "    </decisionTable>");
+  let _ = writeln!(xml, "  </decision>");
+  let _ = writeln!(xml, "</definitions>");
+  xml
+}
+
+/// Returns the name of the hit policy used in DMN XML.
+fn hit_policy_name(hit_policy: HitPolicy) -> &'static str {
+  match hit_policy {
+    HitPolicy::Unique => "UNIQUE",
+    HitPolicy::Any => "ANY",
+    HitPolicy::Priority => "PRIORITY",
+    HitPolicy::First => "FIRST",
+    HitPolicy::Collect(_) => "COLLECT",
+    HitPolicy::OutputOrder => "OUTPUT ORDER",
+    HitPolicy::RuleOrder => "RULE ORDER",
+  }
+}
+
+/// Returns the name of the aggregator used in DMN XML.
+fn aggregator_name(aggregator: BuiltinAggregator) -> &'static str {
+  match aggregator {
+    BuiltinAggregator::Count => "COUNT",
+    BuiltinAggregator::Sum => "SUM",
+    BuiltinAggregator::Min => "MIN",
+    BuiltinAggregator::Max => "MAX",
+  }
+}
+
+/// Writes the `<text>` element indented with the specified number of spaces.
+fn write_text(xml: &mut String, indent: usize, text: &str) {
+  let _ = writeln!(xml, "{:indent$}<text>{}</text>", "", escape_text(text));
+}
+
+/// Writes allowed values, empty allowed values are skipped.
+fn write_values(xml: &mut String, element: &str, id: &str, allowed_values: Option<&Cell>) {
+  if let Some(cell) = allowed_values.filter(|cell| !cell.is_empty()) {
+    let _ = writeln!(xml, r#"        <{element} id="{id}_values">"#);
+    write_text(xml, 10, &single_line(cell));
+    let _ = writeln!(xml, "        </{element}>");
+  }
+}
+
+/// Writes input or output entries of the rule.
+fn write_entries(xml: &mut String, element: &str, id: &str, cells: &[Cell]) {
+  for (index, cell) in cells.iter().enumerate() {
+    let _ = writeln!(xml, r#"        <{element} id="{id}_{}">"#, index + 1);
+    write_text(xml, 10, cell.text());
+    let _ = writeln!(xml, "        </{element}>");
+  }
+}
+
+/// Returns the text of the cell with lines joined with spaces,
+/// used for names and expressions, which are split into lines only for presentation.
+fn single_line(cell: &Cell) -> String {
+  cell.lines().join(" ")
+}
+
+/// Escapes characters having special meaning in XML text.
+fn escape_text(text: &str) -> String {
+  text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+/// Escapes characters having special meaning in XML attribute values.
+fn escape(text: &str) -> String {
+  let mut escaped = String::with_capacity(text.len());
+  for ch in text.chars() {
+    match ch {
+      '&' => escaped.push_str("&amp;"),
+      '<' => escaped.push_str("&lt;"),
+      '>' => escaped.push_str("&gt;"),
+      '"' => escaped.push_str("&quot;"),
+      '\'' => escaped.push_str("&apos;"),
+      _ => escaped.push(ch),
+    }
+  }
+  escaped
+}
+
+/// Imports the decision table from DMN XML.
+///
+/// All decisions containing a `<decisionTable>` element are considered. When there are several
+/// of them, the decision must be selected by its identifier or name, otherwise the only decision
+/// is imported. The name of the decision becomes the information item name.
+///
+/// # Examples
+///
+/// ```
+/// use dtee::*;
+///
+/// let text = "┌───┬───╥───┐\n│ F │ a ║ b │\n╞═══╪═══╬═══╡\n│ 1 │<2 ║ 3 │\n└───┴───╨───┘";
+/// let dmn = export_dmn(&text.parse::<DecisionTable>().unwrap());
+/// let decision_table = import_dmn(&dmn, None).unwrap();
+/// assert_eq!(HitPolicy::First, decision_table.hit_policy());
+/// assert_eq!("<2", decision_table.rules()[0].input_entries()[0].text());
+/// ```
+pub fn import_dmn(xml: &str, decision: Option<&str>) -> Result<DecisionTable, TableError> {
+  let document = roxmltree::Document::parse(xml).map_err(|reason| TableError::InvalidDmn(reason.to_string()))?;
+  let decisions = document
+    .descendants()
+    .filter(|node| node.has_tag_name("decision"))
+    .filter_map(|node| child(node, "decisionTable").map(|decision_table| (node, decision_table)))
+    .collect::<Vec<(Node, Node)>>();
+  let (decision_node, decision_table_node) = match decision {
+    Some(decision) => *decisions
+      .iter()
+      .find(|(node, _)| node.attribute("id") == Some(decision) || node.attribute("name") == Some(decision))
+      .ok_or(TableError::DecisionNotFound(decision.to_string()))?,
+    None => match decisions.as_slice() {
+      [] => return Err(TableError::DecisionTableNotFound),
+      [single] => *single,
+      _ => {
+        let names = decisions.iter().map(|(node, _)| decision_name(*node)).collect();
+        return Err(TableError::AmbiguousDecision(names));
+      }
+    },
+  };
+
+  let hit_policy = match decision_table_node.attribute("aggregation") {
+    Some(aggregation) => format!("{} {}", decision_table_node.attribute("hitPolicy").unwrap_or("COLLECT"), aggregation),
+    None => decision_table_node.attribute("hitPolicy").unwrap_or("UNIQUE").to_string(),
+  };
+  let hit_policy = hit_policy.parse::<HitPolicy>()?;
+  let input_clauses = children(decision_table_node, "input")
+    .map(|node| {
+      let input_expression = child(node, "inputExpression")
+        .and_then(text)
+        .or_else(|| node.attribute("label").map(Cell::new))
+        .unwrap_or_default();
+      let input_clause = InputClause::new(input_expression);
+      match child(node, "inputValues").and_then(text) {
+        Some(allowed_values) => input_clause.with_allowed_values(allowed_values),
+        None => input_clause,
+      }
+    })
+    .collect();
+  let output_clauses = children(decision_table_node, "output")
+    .map(|node| {
+      let name = node.attribute("name").or(node.attribute("label")).map(Cell::new).unwrap_or_default();
+      let output_clause = OutputClause::new(name);
+      match child(node, "outputValues").and_then(text) {
+        Some(allowed_values) => output_clause.with_allowed_values(allowed_values),
+        None => output_clause,
+      }
+    })
+    .collect();
+  let annotation_clauses = children(decision_table_node, "annotation")
+    .map(|node| AnnotationClause::new(node.attribute("name").map(Cell::new).unwrap_or_default()))
+    .collect::<Vec<AnnotationClause>>();
+  let rules = children(decision_table_node, "rule")
+    .map(|node| {
+      let entries = |name: &str| children(node, name).map(|entry| text(entry).unwrap_or_default()).collect::<Vec<Cell>>();
+      let mut annotation_entries = entries("annotationEntry");
+      annotation_entries.resize(annotation_clauses.len(), Cell::default());
+      Rule::new(entries("inputEntry"), entries("outputEntry")).with_annotation_entries(annotation_entries)
+    })
+    .collect();
+
+  let mut decision_table = DecisionTable::new(hit_policy, input_clauses, output_clauses, rules)
+    .with_annotation_clauses(annotation_clauses)
+    .with_information_item_name(Cell::new(decision_name(decision_node)));
+  if let Some(output_label) = decision_table_node.attribute("outputLabel") {
+    decision_table = decision_table.with_output_label(Cell::new(output_label));
+  }
+  match decision_table_node.attribute("preferredOrientation") {
+    Some(RULE_AS_COLUMN) => decision_table = decision_table.with_orientation(Orientation::Vertical),
+    Some(CROSS_TABLE) => decision_table = decision_table.with_orientation(Orientation::Crosstab),
+    _ => {}
+  }
+  Ok(decision_table)
+}
+
+/// Returns the name of the decision, or its identifier when the name is absent.
+fn decision_name(node: Node) -> String {
+  node.attribute("name").or(node.attribute("id")).unwrap_or_default().to_string()
+}
+
+/// Returns the first child element with the specified name.
+fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+  children(node, name).next()
+}
+
+/// Returns child elements with the specified name.
+fn children<'a, 'input>(node: Node<'a, 'input>, name: &str) -> impl Iterator<Item = Node<'a, 'input>> {
+  let name = name.to_string();
+  node.children().filter(move |child| child.has_tag_name(name.as_str()))
+}
+
+/// Returns the content of the `<text>` child element, with lines trimmed.
+fn text(node: Node) -> Option<Cell> {
+  let text = child(node, "text")?.text().unwrap_or_default();
+  Some(Cell::new(text.trim().lines().map(|line| line.trim()).collect::<Vec<&str>>().join("\n")))
+}
@@ -0,0 +1,91 @@
+//! # Supported formats
+
+use crate::{
+  export_csv, export_dmn, export_html, export_json, export_markdown, import_csv, import_dmn, import_json, import_markdown, CsvOptions, DecisionTable, JsonOptions, TableError,
+};
+use std::fmt;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Formats the decision table can be exported to and imported from.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Format {
+  /// DMN 1.x XML.
+  Dmn,
+  /// GitHub-flavored Markdown table.
+  Markdown,
+  /// Comma-separated values.
+  Csv(CsvOptions),
+  /// HTML table, supported only for export.
+  Html,
+  /// JSON representation of the decision table.
+  Json(JsonOptions),
+}
+
+impl Format {
+  /// Names of all supported formats, as accepted by [Format::from_str].
+  pub const NAMES: [&'static str; 5] = ["dmn", "markdown", "csv", "html", "json"];
+
+  /// Exports the decision table to this format.
+  pub fn export(&self, decision_table: &DecisionTable) -> String {
+    match self {
+      Format::Dmn => export_dmn(decision_table),
+      Format::Markdown => export_markdown(decision_table),
+      Format::Csv(options) => export_csv(decision_table, *options),
+      Format::Html => export_html(decision_table),
+      Format::Json(options) => export_json(decision_table, *options),
+    }
+  }
+
+  /// Imports the decision table from content in this format.
+  ///
+  /// The decision selects one of several decision tables by identifier or name,
+  /// it is ignored by formats containing a single decision table.
+  pub fn import(&self, content: &str, decision: Option<&str>) -> Result<DecisionTable, TableError> {
+    match self {
+      Format::Dmn => import_dmn(content, decision),
+      Format::Markdown => import_markdown(content),
+      Format::Csv(options) => import_csv(content, *options),
+      Format::Html => Err(TableError::UnsupportedImport(self.to_string())),
+      Format::Json(_) => import_json(content),
+    }
+  }
+}
+
+impl FromStr for Format {
+  type Err = TableError;
+
+  /// Parses the format from its name.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::Format;
+  ///
+  /// assert_eq!(Format::Dmn, "dmn".parse::<Format>().unwrap());
+  /// assert!("xls".parse::<Format>().is_err());
+  /// ```
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.trim().to_lowercase().as_str() {
+      "dmn" => Ok(Format::Dmn),
+      "markdown" | "md" => Ok(Format::Markdown),
+      "csv" => Ok(Format::Csv(CsvOptions::default())),
+      "html" => Ok(Format::Html),
+      "json" => Ok(Format::Json(JsonOptions::default())),
+      other => Err(TableError::UnknownFormat(other.to_string())),
+    }
+  }
+}
+
+impl Display for Format {
+  /// Implements [Display] trait for [Format].
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Format::Dmn => write!(f, "dmn"),
+      Format::Markdown => write!(f, "markdown"),
+      Format::Csv(_) => write!(f, "csv"),
+      Format::Html => write!(f, "html"),
+      Format::Json(_) => write!(f, "json"),
+    }
+  }
+}
@@ -0,0 +1,223 @@
+//! # HTML tables
+
+use crate::{Cell, DecisionTable};
+use std::fmt::Write;
+
+/// Style of the table.
+const TABLE_STYLE: &str = "border-collapse: collapse;";
+
+/// Style of all cells.
+const CELL_STYLE: &str = "border: 1px solid; padding: 2px 6px; vertical-align: top;";
+
+/// Style of cells following the double line separating input, output and annotation columns (`║`).
+const COLUMN_SEPARATOR_STYLE: &str = " border-left: 3px double;";
+
+/// Style of cells following the double line separating the header from the rules (`╞═══╡`).
+const ROW_SEPARATOR_STYLE: &str = " border-top: 3px double;";
+
+/// Cell of the HTML table.
+struct HtmlCell<'a> {
+  /// Element name, `th` or `td`.
+  tag: &'static str,
+  /// CSS classes.
+  class: String,
+  /// Text of the cell.
+  text: &'a str,
+  /// Number of spanned columns.
+  colspan: usize,
+  /// Number of spanned rows.
+  rowspan: usize,
+  /// Additional style of the cell borders.
+  style: String,
+}
+
+impl<'a> HtmlCell<'a> {
+  /// Creates a cell with the specified element name, CSS classes and text.
+  fn new(tag: &'static str, class: &str, text: &'a str) -> Self {
+    Self {
+      tag,
+      class: class.to_string(),
+      text,
+      colspan: 1,
+      rowspan: 1,
+      style: String::new(),
+    }
+  }
+
+  /// Sets the number of spanned columns.
+  fn colspan(mut self, colspan: usize) -> Self {
+    self.colspan = colspan;
+    self
+  }
+
+  /// Sets the number of spanned rows.
+  fn rowspan(mut self, rowspan: usize) -> Self {
+    self.rowspan = rowspan;
+    self
+  }
+
+  /// Appends the style of the cell borders.
+  fn style(mut self, style: &str) -> Self {
+    self.style.push_str(style);
+    self
+  }
+
+  /// Writes the cell as an HTML element.
+  fn write(&self, html: &mut String) {
+    let _ = write!(html, r#"      <{} class="{}""#, self.tag, self.class);
+    if self.colspan > 1 {
+      let _ = write!(html, r#" colspan="{}""#, self.colspan);
+    }
+    if self.rowspan > 1 {
+      let _ = write!(html, r#" rowspan="{}""#, self.rowspan);
+    }
+    let lines = self.text.lines().map(escape).collect::<Vec<String>>();
+    let _ = writeln!(html, r#" style="{CELL_STYLE}{}">{}</{}>"#, self.style, lines.join("<br>"), self.tag);
+  }
+}
+
+/// Exports the decision table as a standalone HTML table.
+///
+/// The table has the `decision-table` CSS class. Header cells have `hit-policy`, `input`, `output`,
+/// `output-label` or `annotation` classes, allowed values have the additional `allowed-values` class,
+/// rule cells have `rule-number`, `input`, `output` or `annotation` classes. The information item name
+/// is placed in a merged cell with the `information-item-name` class, spanning all columns.
+/// Double lines separating input, output and annotation columns, and separating
+/// the header from the rules, are rendered as double borders using inline styles.
+///
+/// # Examples
+///
+/// ```
+/// use dtee::*;
+///
+/// let text = "┌───┬───╥───┐\n│ F │ a ║ b │\n╞═══╪═══╬═══╡\n│ 1 │<2 ║ 3 │\n└───┴───╨───┘";
+/// let html = export_html(&text.parse::<DecisionTable>().unwrap());
+/// assert!(html.starts_with(r#"<table class="decision-table""#));
+/// assert!(html.contains(r#"<td class="input" style="border: 1px solid; padding: 2px 6px; vertical-align: top; border-top: 3px double;">&lt;2</td>"#));
+/// ```
+pub fn export_html(decision_table: &DecisionTable) -> String {
+  let input_count = decision_table.input_clauses().len();
+  let output_count = decision_table.output_clauses().len();
+  let annotation_count = decision_table.annotation_clauses().len();
+  let output_label = decision_table.output_label().filter(|_| output_count > 1);
+  let allowed_values = decision_table
+    .input_clauses()
+    .iter()
+    .map(|clause| clause.allowed_values())
+    .chain(decision_table.output_clauses().iter().map(|clause| clause.allowed_values()))
+    .collect::<Vec<Option<&Cell>>>();
+  let has_allowed_values = allowed_values.iter().any(Option::is_some);
+  let label_rows = usize::from(output_label.is_some());
+  let header_rows = 1 + label_rows + usize::from(has_allowed_values);
+  let column_count = 1 + input_count + output_count + annotation_count;
+  // returns the style of the cell in the specified column (hit policy column has index 0)
+  let column_style = |index: usize| {
+    if (index == 1 + input_count && output_count > 0) || (index == 1 + input_count + output_count && annotation_count > 0) {
+      COLUMN_SEPARATOR_STYLE
+    } else {
+      ""
+    }
+  };
+  let hit_policy = decision_table.hit_policy().to_string();
+  let mut rows: Vec<Vec<HtmlCell>> = vec![];
+
+  // header
+  let mut row = vec![HtmlCell::new("th", "hit-policy", &hit_policy).rowspan(header_rows)];
+  for (index, clause) in decision_table.input_clauses().iter().enumerate() {
+    row.push(
+      HtmlCell::new("th", "input", clause.input_expression().text())
+        .rowspan(1 + label_rows)
+        .style(column_style(1 + index)),
+    );
+  }
+  match output_label {
+    Some(label) => row.push(
+      HtmlCell::new("th", "output output-label", label.text())
+        .colspan(output_count)
+        .style(column_style(1 + input_count)),
+    ),
+    None => {
+      for (index, clause) in decision_table.output_clauses().iter().enumerate() {
+        row.push(HtmlCell::new("th", "output", clause.name().text()).style(column_style(1 + input_count + index)));
+      }
+    }
+  }
+  for (index, clause) in decision_table.annotation_clauses().iter().enumerate() {
+    row.push(
+      HtmlCell::new("th", "annotation", clause.name().text())
+        .rowspan(header_rows)
+        .style(column_style(1 + input_count + output_count + index)),
+    );
+  }
+  rows.push(row);
+  if output_label.is_some() {
+    let row = decision_table
+      .output_clauses()
+      .iter()
+      .enumerate()
+      .map(|(index, clause)| HtmlCell::new("th", "output", clause.name().text()).style(column_style(1 + input_count + index)))
+      .collect();
+    rows.push(row);
+  }
+  if has_allowed_values {
+    let row = allowed_values
+      .iter()
+      .enumerate()
+      .map(|(index, cell)| {
+        let class = if index < input_count { "input allowed-values" } else { "output allowed-values" };
+        HtmlCell::new("td", class, cell.map_or("", |cell| cell.text())).style(column_style(1 + index))
+      })
+      .collect();
+    rows.push(row);
+  }
+  let header_row_count = rows.len();
+
+  // rules
+  let numbers = (1..=decision_table.rules().len()).map(|number| number.to_string()).collect::<Vec<String>>();
+  for (rule_index, rule) in decision_table.rules().iter().enumerate() {
+    let row_style = if rule_index == 0 { ROW_SEPARATOR_STYLE } else { "" };
+    let mut row = vec![HtmlCell::new("td", "rule-number", &numbers[rule_index]).style(row_style)];
+    let classes = ["input"]
+      .repeat(input_count)
+      .into_iter()
+      .chain(["output"].repeat(output_count))
+      .chain(["annotation"].repeat(annotation_count));
+    let cells = rule.input_entries().iter().chain(rule.output_entries()).chain(rule.annotation_entries());
+    for (index, (class, cell)) in classes.zip(cells).enumerate() {
+      row.push(HtmlCell::new("td", class, cell.text()).style(column_style(1 + index)).style(row_style));
+    }
+    rows.push(row);
+  }
+
+  let mut html = String::new();
+  let _ = writeln!(html, r#"<table class="decision-table" style="{TABLE_STYLE}">"#);
+  let _ = writeln!(html, "  <thead>");
+  if let Some(name) = decision_table.information_item_name() {
+    let _ = writeln!(html, "    <tr>");
+    HtmlCell::new("th", "information-item-name", name.text()).colspan(column_count).write(&mut html);
+    let _ = writeln!(html, "    </tr>");
+  }
+  for (index, row) in rows.iter().enumerate() {
+    if index == header_row_count {
+      let _ = writeln!(html, "  </thead>");
+      let _ = writeln!(html, "  <tbody>");
+    }
+    let _ = writeln!(html, "    <tr>");
+    for cell in row {
+      cell.write(&mut html);
+    }
+    let _ = writeln!(html, "    </tr>");
+  }
+  if rows.len() == header_row_count {
+    let _ = writeln!(html, "  </thead>");
+    let _ = writeln!(html, "  <tbody>");
+  }
+  let _ = writeln!(html, "  </tbody>");
+  let _ = writeln!(html, "</table>");
+  html
+}
+
+/// Escapes characters having special meaning in HTML.
+fn escape(text: &str) -> String {
+  text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
@@ -0,0 +1,265 @@
+//! # JSON
+//!
+//! The decision table is written as a JSON object with the following members:
+//!
+//! - `version` - version of the representation, currently `1`,
+//! - `informationItemName` - information item name (omitted when not present),
+//! - `hitPolicy` - abbreviation of the hit policy, like `U`, `F` or `C+`,
+//! - `outputLabel` - name of the compound output (omitted when not present),
+//! - `inputs` - input clauses, each having `inputExpression` and optional `allowedValues`,
+//! - `outputs` - output clauses, each having `name` and optional `allowedValues`,
+//! - `annotations` - annotation clauses, each having `name`,
+//! - `rules` - rules, each having `inputEntries`, `outputEntries` and `annotationEntries`.
+//!
+//! Every cell is an object with the `text` member, lines of multi-line cells are separated with `\n`.
+//! When requested, cells also contain the `region` member, with `left`, `top`, `width` and `height`
+//! of the region occupied by the cell in the box-drawing text.
+//!
+//! ```text
+//! {
+//!   "version": 1,
+//!   "informationItemName": { "text": "Adult" },
+//!   "hitPolicy": "F",
+//!   "inputs": [ { "inputExpression": { "text": "Age" } } ],
+//!   "outputs": [ { "name": { "text": "Adult" } } ],
+//!   "annotations": [],
+//!   "rules": [ { "inputEntries": [ { "text": "<18" } ], "outputEntries": [ { "text": "false" } ], "annotationEntries": [] } ]
+//! }
+//! ```
+
+use crate::{AnnotationClause, Cell, DecisionTable, HitPolicy, InputClause, OutputClause, Plane, Rule, TableError};
+use serde::{Deserialize, Serialize};
+
+/// Current version of the JSON representation.
+const VERSION: u32 = 1;
+
+/// Options of writing JSON.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct JsonOptions {
+  /// Flag indicating if regions of cells are written.
+  regions: bool,
+}
+
+impl JsonOptions {
+  /// Sets the flag indicating if regions of cells are written.
+  pub fn with_regions(mut self, regions: bool) -> Self {
+    self.regions = regions;
+    self
+  }
+
+  /// Returns `true` when regions of cells are written.
+  pub fn regions(&self) -> bool {
+    self.regions
+  }
+}
+
+/// JSON representation of the region.
+#[derive(Serialize, Deserialize)]
+struct JsonRegion {
+  left: usize,
+  top: usize,
+  width: usize,
+  height: usize,
+}
+
+/// JSON representation of the cell.
+#[derive(Serialize, Deserialize)]
+struct JsonCell {
+  text: String,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  region: Option<JsonRegion>,
+}
+
+/// JSON representation of the input clause.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonInput {
+  input_expression: JsonCell,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  allowed_values: Option<JsonCell>,
+}
+
+/// JSON representation of the output clause.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonOutput {
+  name: JsonCell,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  allowed_values: Option<JsonCell>,
+}
+
+/// JSON representation of the annotation clause.
+#[derive(Serialize, Deserialize)]
+struct JsonAnnotation {
+  name: JsonCell,
+}
+
+/// JSON representation of the rule.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonRule {
+  input_entries: Vec<JsonCell>,
+  output_entries: Vec<JsonCell>,
+  #[serde(default)]
+  annotation_entries: Vec<JsonCell>,
+}
+
+/// JSON representation of the decision table.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonDecisionTable {
+  version: u32,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  information_item_name: Option<JsonCell>,
+  hit_policy: String,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  output_label: Option<JsonCell>,
+  inputs: Vec<JsonInput>,
+  outputs: Vec<JsonOutput>,
+  #[serde(default)]
+  annotations: Vec<JsonAnnotation>,
+  #[serde(default)]
+  rules: Vec<JsonRule>,
+}
+
+/// Exports the decision table as JSON.
+///
+/// # Examples
+///
+/// ```
+/// use dtee::*;
+///
+/// let text = "┌───┬───╥───┐\n│ F │ a ║ b │\n╞═══╪═══╬═══╡\n│ 1 │<2 ║ 3 │\n└───┴───╨───┘";
+/// let decision_table = text.parse::<DecisionTable>().unwrap();
+/// let json = export_json(&decision_table, JsonOptions::default());
+/// assert!(json.contains(r#""hitPolicy": "F""#));
+/// assert!(!json.contains("region"));
+/// let json = export_json(&decision_table, JsonOptions::default().with_regions(true));
+/// assert!(json.contains(r#""region": {"#));
+/// ```
+pub fn export_json(decision_table: &DecisionTable, options: JsonOptions) -> String {
+  let cell = |cell: &Cell| JsonCell {
+    text: cell.text().to_string(),
+    region: cell.region().filter(|_| options.regions).map(|region| JsonRegion {
+      left: region.left(),
+      top: region.top(),
+      width: region.width(),
+      height: region.height(),
+    }),
+  };
+  let cells = |cells: &[Cell]| cells.iter().map(cell).collect();
+  let json = JsonDecisionTable {
+    version: VERSION,
+    information_item_name: decision_table.information_item_name().map(cell),
+    hit_policy: decision_table.hit_policy().to_string(),
+    output_label: decision_table.output_label().filter(|_| decision_table.output_clauses().len() > 1).map(cell),
+    inputs: decision_table
+      .input_clauses()
+      .iter()
+      .map(|clause| JsonInput {
+        input_expression: cell(clause.input_expression()),
+        allowed_values: clause.allowed_values().map(cell),
+      })
+      .collect(),
+    outputs: decision_table
+      .output_clauses()
+      .iter()
+      .map(|clause| JsonOutput {
+        name: cell(clause.name()),
+        allowed_values: clause.allowed_values().map(cell),
+      })
+      .collect(),
+    annotations: decision_table
+      .annotation_clauses()
+      .iter()
+      .map(|clause| JsonAnnotation { name: cell(clause.name()) })
+      .collect(),
+    rules: decision_table
+      .rules()
+      .iter()
+      .map(|rule| JsonRule {
+        input_entries: cells(rule.input_entries()),
+        output_entries: cells(rule.output_entries()),
+        annotation_entries: cells(rule.annotation_entries()),
+      })
+      .collect(),
+  };
+  // serializing structures with string keys never fails
+  let mut json = serde_json::to_string_pretty(&json).unwrap_or_default();
+  json.push('\n');
+  json
+}
+
+/// Imports the decision table from JSON.
+///
+/// Regions present in JSON are ignored, the decision table is rendered as box-drawing text
+/// and parsed back from the text plane, so the regions of all cells refer to the rendered text.
+///
+/// # Examples
+///
+/// ```
+/// use dtee::*;
+///
+/// let json = r#"{
+///   "version": 1,
+///   "hitPolicy": "F",
+///   "inputs": [{ "inputExpression": { "text": "Age" } }],
+///   "outputs": [{ "name": { "text": "Adult" } }],
+///   "rules": [{ "inputEntries": [{ "text": "<18" }], "outputEntries": [{ "text": "false" }] }]
+/// }"#;
+/// let decision_table = import_json(json).unwrap();
+/// assert_eq!("false", decision_table.rules()[0].output_entries()[0].text());
+/// ```
+pub fn import_json(json: &str) -> Result<DecisionTable, TableError> {
+  let json = serde_json::from_str::<JsonDecisionTable>(json).map_err(|reason| TableError::InvalidJson(reason.to_string()))?;
+  if json.version != VERSION {
+    return Err(TableError::InvalidJson(format!("unsupported version {}", json.version)));
+  }
+  let hit_policy = json.hit_policy.parse::<HitPolicy>()?;
+  if json.outputs.is_empty() {
+    return Err(TableError::MissingInputOutputSeparator);
+  }
+  let cell = |cell: JsonCell| Cell::new(cell.text);
+  let counts = (json.inputs.len(), json.outputs.len(), json.annotations.len());
+  let mut rules = vec![];
+  for (index, rule) in json.rules.into_iter().enumerate() {
+    if (rule.input_entries.len(), rule.output_entries.len(), rule.annotation_entries.len()) != counts {
+      return Err(TableError::InvalidJson(format!("invalid number of entries in rule {}", index + 1)));
+    }
+    let input_entries = rule.input_entries.into_iter().map(cell).collect();
+    let output_entries = rule.output_entries.into_iter().map(cell).collect();
+    let annotation_entries = rule.annotation_entries.into_iter().map(cell).collect();
+    rules.push(Rule::new(input_entries, output_entries).with_annotation_entries(annotation_entries));
+  }
+  let input_clauses = json
+    .inputs
+    .into_iter()
+    .map(|input| {
+      let input_clause = InputClause::new(cell(input.input_expression));
+      match input.allowed_values {
+        Some(allowed_values) => input_clause.with_allowed_values(cell(allowed_values)),
+        None => input_clause,
+      }
+    })
+    .collect();
+  let output_clauses = json
+    .outputs
+    .into_iter()
+    .map(|output| {
+      let output_clause = OutputClause::new(cell(output.name));
+      match output.allowed_values {
+        Some(allowed_values) => output_clause.with_allowed_values(cell(allowed_values)),
+        None => output_clause,
+      }
+    })
+    .collect();
+  let annotation_clauses = json.annotations.into_iter().map(|annotation| AnnotationClause::new(cell(annotation.name))).collect();
+  let mut decision_table = DecisionTable::new(hit_policy, input_clauses, output_clauses, rules).with_annotation_clauses(annotation_clauses);
+  if let Some(name) = json.information_item_name.filter(|name| !name.text.is_empty()) {
+    decision_table = decision_table.with_information_item_name(cell(name));
+  }
+  if let Some(output_label) = json.output_label {
+    decision_table = decision_table.with_output_label(cell(output_label));
+  }
+  Plane::new(decision_table.to_string()).decision_table()
+}
@@ -0,0 +1,216 @@
+//! # GitHub-flavored Markdown tables
+//!
+//! The decision table is written as a Markdown table using the following convention:
+//!
+//! - the information item name (if present) is written in bold (or as a heading), in a separate paragraph before the table,
+//! - the first header cell contains the hit policy, the first cell in each rule contains the rule number,
+//! - names of input columns are written as they are,
+//! - names of output columns end with ` (output)`, names of annotation columns end with ` (annotation)`,
+//! - the name of the compound output is written before the name of each output column, followed by ` / `,
+//! - allowed values (if present) are written in the first row, having an empty first cell,
+//! - lines of multi-line cells are separated with `<br>`, vertical bars are escaped with `\|`.
+//!
+//! ```text
+//! **SLA**
+//!
+//! | U | YearsAsCustomer | NumberOfUnits | SLA (output) |
+//! | - | --------------- | ------------- | ------------ |
+//! |   | [0..100]        | [0..1000000]  | 1,2          |
+//! | 1 | <2              | <1000         | 1            |
+//! | 2 | >=2             | >=1000        | 2            |
+//! ```
+
+use super::tabular::{Tabular, LABEL_SEPARATOR};
+use crate::{display_width, Cell, DecisionTable, HitPolicy, TableError};
+
+/// Suffix of the names of output columns.
+const OUTPUT_SUFFIX: &str = " (output)";
+
+/// Suffix of the names of annotation columns.
+const ANNOTATION_SUFFIX: &str = " (annotation)";
+
+/// Exports the decision table as a GitHub-flavored Markdown table.
+///
+/// # Examples
+///
+/// ```
+/// use dtee::*;
+///
+/// let text = "┌───┬───╥───┐\n│ F │ a ║ b │\n╞═══╪═══╬═══╡\n│ 1 │<2 ║ 3 │\n└───┴───╨───┘";
+/// let markdown = export_markdown(&text.parse::<DecisionTable>().unwrap());
+/// assert_eq!("| F | a  | b (output) |\n| - | -- | ---------- |\n| 1 | <2 | 3          |\n", markdown);
+/// ```
+pub fn export_markdown(decision_table: &DecisionTable) -> String {
+  let output_label = decision_table.output_label().filter(|_| decision_table.output_clauses().len() > 1);
+  let mut header = vec![decision_table.hit_policy().to_string()];
+  header.extend(decision_table.input_clauses().iter().map(|clause| clause.input_expression().text().to_string()));
+  header.extend(decision_table.output_clauses().iter().map(|clause| match output_label {
+    Some(label) => format!("{}{LABEL_SEPARATOR}{}{OUTPUT_SUFFIX}", label.text(), clause.name().text()),
+    None => format!("{}{OUTPUT_SUFFIX}", clause.name().text()),
+  }));
+  header.extend(
+    decision_table
+      .annotation_clauses()
+      .iter()
+      .map(|clause| format!("{}{ANNOTATION_SUFFIX}", clause.name().text())),
+  );
+  let mut rows = vec![header];
+  let allowed_values = decision_table
+    .input_clauses()
+    .iter()
+    .map(|clause| clause.allowed_values())
+    .chain(decision_table.output_clauses().iter().map(|clause| clause.allowed_values()))
+    .collect::<Vec<Option<&Cell>>>();
+  if allowed_values.iter().any(Option::is_some) {
+    let mut row = vec![String::new()];
+    row.extend(allowed_values.iter().map(|cell| cell.map_or("", |cell| cell.text()).to_string()));
+    row.extend(decision_table.annotation_clauses().iter().map(|_| String::new()));
+    rows.push(row);
+  }
+  for (index, rule) in decision_table.rules().iter().enumerate() {
+    let mut row = vec![(index + 1).to_string()];
+    row.extend(
+      rule
+        .input_entries()
+        .iter()
+        .chain(rule.output_entries())
+        .chain(rule.annotation_entries())
+        .map(|cell| cell.text().to_string()),
+    );
+    rows.push(row);
+  }
+  let rows = rows
+    .iter()
+    .map(|row| row.iter().map(|text| escape(text)).collect::<Vec<String>>())
+    .collect::<Vec<Vec<String>>>();
+  let column_count = rows.iter().map(Vec::len).max().unwrap_or(0);
+  let widths = (0..column_count)
+    .map(|index| rows.iter().filter_map(|row| row.get(index)).map(|text| display_width(text)).max().unwrap_or(0).max(1))
+    .collect::<Vec<usize>>();
+  let mut markdown = String::new();
+  if let Some(name) = decision_table.information_item_name() {
+    markdown.push_str(&format!("**{}**\n\n", name.lines().join(" ")));
+  }
+  let delimiter = widths.iter().map(|width| "-".repeat(*width)).collect::<Vec<String>>();
+  for (index, row) in rows.iter().enumerate() {
+    markdown.push_str(&markdown_row(row, &widths));
+    if index == 0 {
+      markdown.push_str(&markdown_row(&delimiter, &widths));
+    }
+  }
+  markdown
+}
+
+/// Imports the decision table from a GitHub-flavored Markdown table.
+///
+/// # Examples
+///
+/// ```
+/// use dtee::*;
+///
+/// let markdown = "**Adult**\n\n| F | Age | Adult (output) |\n|---|-----|-------|\n| 1 | <18 | false |\n";
+/// let decision_table = import_markdown(markdown).unwrap();
+/// assert_eq!("Adult", decision_table.information_item_name().unwrap().text());
+/// assert_eq!("false", decision_table.rules()[0].output_entries()[0].text());
+/// ```
+pub fn import_markdown(markdown: &str) -> Result<DecisionTable, TableError> {
+  let lines = markdown.lines().map(str::trim).collect::<Vec<&str>>();
+  let start = lines
+    .iter()
+    .position(|line| line.starts_with('|'))
+    .ok_or(TableError::InvalidMarkdown("table not found".to_string()))?;
+  let name = lines[..start]
+    .iter()
+    .rev()
+    .find(|line| !line.is_empty())
+    .filter(|line| line.starts_with('#') || (line.len() > 4 && line.starts_with("**") && line.ends_with("**")))
+    .map(|line| line.trim_start_matches('#').trim().trim_matches('*').trim())
+    .filter(|name| !name.is_empty());
+  let mut rows = lines[start..].iter().take_while(|line| line.starts_with('|')).map(|line| split_row(line));
+  let header = rows.next().unwrap_or_default();
+  let delimiter = rows.next().unwrap_or_default();
+  if delimiter.is_empty() || !delimiter.iter().all(|cell| !cell.is_empty() && cell.chars().all(|ch| ch == '-' || ch == ':')) {
+    return Err(TableError::InvalidMarkdown("missing delimiter row".to_string()));
+  }
+  let hit_policy = header[0].parse::<HitPolicy>()?;
+
+  // classify columns by the suffix of their names
+  let mut input_names = vec![];
+  let mut output_names = vec![];
+  let mut annotation_names = vec![];
+  for name in &header[1..] {
+    if let Some(name) = name.strip_suffix(ANNOTATION_SUFFIX) {
+      annotation_names.push(name.to_string());
+    } else if let Some(name) = name.strip_suffix(OUTPUT_SUFFIX) {
+      if !annotation_names.is_empty() {
+        return Err(TableError::InvalidMarkdown(format!("output column '{name}' after annotation columns")));
+      }
+      output_names.push(name.to_string());
+    } else {
+      if !output_names.is_empty() || !annotation_names.is_empty() {
+        return Err(TableError::InvalidMarkdown(format!("input column '{name}' after output columns")));
+      }
+      input_names.push(name.to_string());
+    }
+  }
+  Tabular {
+    hit_policy,
+    name: name.map(str::to_string),
+    input_names,
+    output_names,
+    annotation_names,
+  }
+  .build(rows.collect(), 1, TableError::InvalidMarkdown)
+}
+
+/// Returns a row of the Markdown table, with cells padded to the specified widths.
+fn markdown_row(cells: &[String], widths: &[usize]) -> String {
+  let cells = widths
+    .iter()
+    .enumerate()
+    .map(|(index, width)| {
+      let text = cells.get(index).map_or("", |text| text.as_str());
+      format!("{text}{}", " ".repeat(width.saturating_sub(display_width(text))))
+    })
+    .collect::<Vec<String>>();
+  format!("| {} |\n", cells.join(" | "))
+}
+
+/// Splits the row of the Markdown table into cells, with escaped characters restored.
+fn split_row(line: &str) -> Vec<String> {
+  let line = line.trim().trim_start_matches('|');
+  let line = line.strip_suffix('|').filter(|line| !line.ends_with('\\')).unwrap_or(line);
+  let mut cells = vec![];
+  let mut cell = String::new();
+  let mut chars = line.chars().peekable();
+  while let Some(ch) = chars.next() {
+    match ch {
+      '\\' if chars.peek() == Some(&'|') => {
+        cell.push('|');
+        chars.next();
+      }
+      '|' => cells.push(unescape(&std::mem::take(&mut cell))),
+      _ => cell.push(ch),
+    }
+  }
+  cells.push(unescape(&cell));
+  cells
+}
+
+/// Escapes the text of the cell, so it fits in a single row of the Markdown table.
+fn escape(text: &str) -> String {
+  text.replace('|', "\\|").replace('\n', "<br>")
+}
+
+/// Restores line breaks in the text of the cell.
+fn unescape(text: &str) -> String {
+  text
+    .trim()
+    .replace("<br/>", "\n")
+    .replace("<br />", "\n")
+    .replace("<br>", "\n")
+    .lines()
+    .map(str::trim)
+    .collect::<Vec<&str>>()
+    .join("\n")
+}
@@ -0,0 +1,16 @@
+//! # Formats of decision tables
+
+mod csv;
+mod dmn;
+mod format;
+mod html;
+mod json;
+mod markdown;
+mod tabular;
+
+pub use csv::*;
+pub use dmn::*;
+pub use format::*;
+pub use html::*;
+pub use json::*;
+pub use markdown::*;
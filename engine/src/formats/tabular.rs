@@ -0,0 +1,101 @@
+//! # Decision tables in tabular formats
+
+use crate::{AnnotationClause, Cell, DecisionTable, HitPolicy, InputClause, OutputClause, Plane, Rule, TableError};
+
+/// Separator of the compound output name and the name of the output column.
+pub const LABEL_SEPARATOR: &str = " / ";
+
+/// Decision table read from a tabular format (Markdown table, CSV), where each rule is a row of values.
+pub struct Tabular {
+  /// Hit policy.
+  pub hit_policy: HitPolicy,
+  /// Information item name, if present.
+  pub name: Option<String>,
+  /// Names of input columns.
+  pub input_names: Vec<String>,
+  /// Names of output columns, optionally preceded by the compound output name and [LABEL_SEPARATOR].
+  pub output_names: Vec<String>,
+  /// Names of annotation columns.
+  pub annotation_names: Vec<String>,
+}
+
+impl Tabular {
+  /// Builds the decision table from rows of values, each row starting with the rule number.
+  ///
+  /// When the first row has an empty first value, it contains allowed values.
+  /// Rows having too many values are reported using the `invalid` error,
+  /// rows are numbered starting with `first_row`. The decision table is rendered
+  /// as box-drawing text and parsed back from the text plane, so the regions
+  /// of all cells refer to the rendered text.
+  pub fn build(self, rows: Vec<Vec<String>>, first_row: usize, invalid: fn(String) -> TableError) -> Result<DecisionTable, TableError> {
+    if self.output_names.is_empty() {
+      return Err(TableError::MissingInputOutputSeparator);
+    }
+    let input_count = self.input_names.len();
+    let output_count = self.output_names.len();
+    let column_count = 1 + input_count + output_count + self.annotation_names.len();
+
+    // compound output name is present when all output columns share the same prefix
+    let mut output_label = None;
+    let mut output_names = self.output_names;
+    if output_count > 1 {
+      let labels = output_names.iter().filter_map(|name| name.split_once(LABEL_SEPARATOR)).collect::<Vec<(&str, &str)>>();
+      if labels.len() == output_count && labels.iter().all(|(label, _)| *label == labels[0].0) {
+        output_label = Some(Cell::new(labels[0].0));
+        output_names = labels.iter().map(|(_, name)| name.to_string()).collect();
+      }
+    }
+
+    // allowed values and rules
+    let mut allowed_values = None;
+    let mut rules = vec![];
+    for (index, mut row) in rows.into_iter().enumerate() {
+      if row.len() > column_count {
+        return Err(invalid(format!("too many cells in row {}", index + first_row)));
+      }
+      row.resize(column_count, String::new());
+      let is_allowed_values = index == 0 && row[0].is_empty();
+      let mut cells = row.into_iter().skip(1).map(Cell::new).collect::<Vec<Cell>>();
+      let annotation_entries = cells.split_off(input_count + output_count);
+      let output_entries = cells.split_off(input_count);
+      if is_allowed_values {
+        allowed_values = Some((cells, output_entries));
+      } else {
+        rules.push(Rule::new(cells, output_entries).with_annotation_entries(annotation_entries));
+      }
+    }
+    let (input_values, output_values) = allowed_values.unzip();
+    let input_clauses = self
+      .input_names
+      .iter()
+      .enumerate()
+      .map(|(index, name)| {
+        let input_clause = InputClause::new(Cell::new(name));
+        match input_values.as_ref().and_then(|values| values.get(index)) {
+          Some(cell) => input_clause.with_allowed_values(cell.clone()),
+          None => input_clause,
+        }
+      })
+      .collect();
+    let output_clauses = output_names
+      .iter()
+      .enumerate()
+      .map(|(index, name)| {
+        let output_clause = OutputClause::new(Cell::new(name));
+        match output_values.as_ref().and_then(|values| values.get(index)) {
+          Some(cell) => output_clause.with_allowed_values(cell.clone()),
+          None => output_clause,
+        }
+      })
+      .collect();
+    let annotation_clauses = self.annotation_names.iter().map(|name| AnnotationClause::new(Cell::new(name))).collect();
+    let mut decision_table = DecisionTable::new(self.hit_policy, input_clauses, output_clauses, rules).with_annotation_clauses(annotation_clauses);
+    if let Some(name) = self.name.filter(|name| !name.is_empty()) {
+      decision_table = decision_table.with_information_item_name(Cell::new(name));
+    }
+    if let Some(output_label) = output_label {
+      decision_table = decision_table.with_output_label(output_label);
+    }
+    Plane::new(decision_table.to_string()).decision_table()
+  }
+}
@@ -5,11 +5,15 @@
 // #![warn(rustdoc::missing_crate_level_docs)]
 
 mod controller;
+mod formats;
 mod model;
 mod region;
+mod table;
 mod updates;
 
 pub use controller::*;
+pub use formats::*;
 pub use model::*;
 pub use region::*;
+pub use table::*;
 pub use updates::*;
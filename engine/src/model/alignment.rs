@@ -0,0 +1,35 @@
+//! # Alignment of the text in cells
+
+/// Horizontal alignment of the text in cells.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Alignment {
+  /// Text is aligned to the left side of the cell, after a single space.
+  Left,
+  /// Text is centered in the cell, the remaining space goes to the right side.
+  Center,
+  /// Text is aligned to the right side of the cell, before a single space.
+  Right,
+}
+
+impl Alignment {
+  /// Returns the offset of the text having the specified length in a cell having the specified width.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::Alignment;
+  ///
+  /// assert_eq!(1, Alignment::Left.offset(7, 3));
+  /// assert_eq!(2, Alignment::Center.offset(7, 3));
+  /// assert_eq!(3, Alignment::Right.offset(7, 3));
+  /// assert_eq!(0, Alignment::Right.offset(3, 3));
+  /// ```
+  pub fn offset(&self, width: usize, length: usize) -> usize {
+    let space = width.saturating_sub(length);
+    match self {
+      Alignment::Left => space.min(1),
+      Alignment::Center => space / 2,
+      Alignment::Right => space - space.min(1),
+    }
+  }
+}
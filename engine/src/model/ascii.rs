@@ -0,0 +1,134 @@
+//! # ASCII frames
+
+use crate::{to_chars, Arms, Line};
+
+/// `+` Crossing or corner of lines in ASCII frames.
+pub const ASCII_CROSSING: char = '+';
+
+/// `-` Single horizontal line in ASCII frames.
+pub const ASCII_SINGLE_HORIZONTAL: char = '-';
+
+/// `=` Double horizontal line in ASCII frames.
+pub const ASCII_DOUBLE_HORIZONTAL: char = '=';
+
+/// `|` Single vertical line in ASCII frames.
+pub const ASCII_SINGLE_VERTICAL: char = '|';
+
+/// `#` Double vertical line in ASCII frames.
+pub const ASCII_DOUBLE_VERTICAL: char = '#';
+
+/// Returns `true` when the line (after skipping leading whitespaces) starts like a row of an ASCII frame.
+pub fn is_ascii_frame_line(line: &str) -> bool {
+  line.trim_start().starts_with([ASCII_CROSSING, ASCII_SINGLE_VERTICAL, ASCII_DOUBLE_VERTICAL])
+}
+
+/// Converts ASCII frames into box-drawing characters.
+///
+/// Only lines running between two crossings (`+`) are converted, so characters like `-`, `|` or `+`
+/// placed inside cells are left untouched. Horizontal lines are made of `-` (single) or `=` (double),
+/// vertical lines are made of `|` (single) or `#` (double). Crossings are replaced with box-drawing
+/// characters having arms matching the attached lines.
+///
+/// # Examples
+///
+/// ```
+/// use dtee::frame_from_ascii;
+///
+/// let lines = ["+---+---+---+", "| U | a # b |", "+===+===+===+", "| 1 | - # + |", "+---+---+---+"];
+/// let expected = ["┌───┬───╥───┐", "│ U │ a ║ b │", "╞═══╪═══╬═══╡", "│ 1 │ - ║ + │", "└───┴───╨───┘"];
+/// assert_eq!(expected.to_vec(), frame_from_ascii(&lines));
+/// ```
+pub fn frame_from_ascii<T: AsRef<str>>(lines: &[T]) -> Vec<String> {
+  // characters are placed in terminal columns, so vertical lines stay aligned after wide characters
+  let rows = lines.iter().map(|line| to_chars(line.as_ref())).collect::<Vec<_>>();
+  let grid = rows.iter().map(|row| row.iter().map(|chr| chr.char()).collect()).collect::<Vec<Vec<char>>>();
+  let mut arms = grid.iter().map(|row| vec![Arms::default(); row.len()]).collect::<Vec<Vec<Arms>>>();
+  for (row_index, row) in grid.iter().enumerate() {
+    for (left_index, right_index, line) in runs(row, ASCII_SINGLE_HORIZONTAL, ASCII_DOUBLE_HORIZONTAL) {
+      arms[row_index][left_index].right = line;
+      for arms in &mut arms[row_index][left_index + 1..right_index] {
+        arms.left = line;
+        arms.right = line;
+      }
+      arms[row_index][right_index].left = line;
+    }
+  }
+  let width = grid.iter().map(|row| row.len()).max().unwrap_or_default();
+  for col_index in 0..width {
+    let column = grid.iter().map(|row| row.get(col_index).copied().unwrap_or_default()).collect::<Vec<char>>();
+    for (top_index, bottom_index, line) in runs(&column, ASCII_SINGLE_VERTICAL, ASCII_DOUBLE_VERTICAL) {
+      arms[top_index][col_index].down = line;
+      for row in &mut arms[top_index + 1..bottom_index] {
+        row[col_index].up = line;
+        row[col_index].down = line;
+      }
+      arms[bottom_index][col_index].up = line;
+    }
+  }
+  rows
+    .iter()
+    .zip(arms)
+    .map(|(row, arms)| {
+      row
+        .iter()
+        .zip(arms)
+        .map(|(chr, arms)| if arms == Arms::default() { chr.to_string() } else { arms.char().to_string() })
+        .collect()
+    })
+    .collect()
+}
+
+/// Converts box-drawing characters into ASCII frames, all other characters are left untouched.
+///
+/// # Examples
+///
+/// ```
+/// use dtee::frame_to_ascii;
+///
+/// let text = "┌───┬───╥───┐\n│ U │ a ║ b │\n╞═══╪═══╬═══╡\n│ 1 │ - ║ + │\n└───┴───╨───┘";
+/// let expected = "+---+---+---+\n| U | a # b |\n+===+===+===+\n| 1 | - # + |\n+---+---+---+";
+/// assert_eq!(expected, frame_to_ascii(text));
+/// ```
+pub fn frame_to_ascii(text: &str) -> String {
+  text
+    .chars()
+    .map(|ch| {
+      let arms = Arms::from(ch);
+      let vert = arms.up.max(arms.down);
+      let horz = arms.left.max(arms.right);
+      match (vert, horz) {
+        (Line::Empty, Line::Empty) => ch,
+        (Line::Empty, Line::Single) => ASCII_SINGLE_HORIZONTAL,
+        (Line::Empty, Line::Double) => ASCII_DOUBLE_HORIZONTAL,
+        (Line::Single, Line::Empty) => ASCII_SINGLE_VERTICAL,
+        (Line::Double, Line::Empty) => ASCII_DOUBLE_VERTICAL,
+        _ => ASCII_CROSSING,
+      }
+    })
+    .collect()
+}
+
+/// Returns the indexes of the crossings at both ends and the style of all lines
+/// running between two crossings, made entirely of single or double line characters.
+fn runs(chars: &[char], single: char, double: char) -> Vec<(usize, usize, Line)> {
+  let mut runs = vec![];
+  let crossings = chars
+    .iter()
+    .enumerate()
+    .filter(|(_, ch)| **ch == ASCII_CROSSING)
+    .map(|(index, _)| index)
+    .collect::<Vec<usize>>();
+  for pair in crossings.windows(2) {
+    let (start, end) = (pair[0], pair[1]);
+    let between = &chars[start + 1..end];
+    if between.is_empty() {
+      continue;
+    }
+    if between.iter().all(|ch| *ch == single) {
+      runs.push((start, end, Line::Single));
+    } else if between.iter().all(|ch| *ch == double) {
+      runs.push((start, end, Line::Double));
+    }
+  }
+  runs
+}
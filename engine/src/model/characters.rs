@@ -3,6 +3,8 @@
 use std::cell::RefCell;
 use std::fmt;
 use std::fmt::Display;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 /// ` ` `U+0020` Space.
 /// ```
@@ -12,6 +14,14 @@ use std::fmt::Display;
 /// ```
 pub const SPACE: char = '\u{0020}';
 
+/// `U+0000` Placeholder occupying the second terminal column of a wide character (like CJK or emoji).
+/// It is never displayed, the wide character placed before covers both columns.
+/// ```
+/// # use dtee::*;
+/// assert_eq!('\u{0000}', CONTINUATION);
+/// ```
+pub const CONTINUATION: char = '\u{0000}';
+
 /// `─` `U+2500` Box drawings light horizontal.+
 ///
 /// ```
@@ -238,38 +248,298 @@ pub const ATTRIBUTE_JOIN: u8 = 0x01;
 /// ```
 pub const ATTRIBUTE_FULL_JOIN: u8 = 0x02;
 
+/// Variants of box-drawing characters (heavy, mixed light and heavy, rounded corners)
+/// together with their canonical light equivalents.
+const VARIANTS: [(char, char); 65] = [
+  ('\u{2501}', LIGHT_HORIZONTAL),
+  ('\u{2503}', LIGHT_VERTICAL),
+  ('\u{250D}', LIGHT_DOWN_AND_RIGHT),
+  ('\u{250E}', LIGHT_DOWN_AND_RIGHT),
+  ('\u{250F}', LIGHT_DOWN_AND_RIGHT),
+  ('\u{2511}', LIGHT_DOWN_AND_LEFT),
+  ('\u{2512}', LIGHT_DOWN_AND_LEFT),
+  ('\u{2513}', LIGHT_DOWN_AND_LEFT),
+  ('\u{2515}', LIGHT_UP_AND_RIGHT),
+  ('\u{2516}', LIGHT_UP_AND_RIGHT),
+  ('\u{2517}', LIGHT_UP_AND_RIGHT),
+  ('\u{2519}', LIGHT_UP_AND_LEFT),
+  ('\u{251A}', LIGHT_UP_AND_LEFT),
+  ('\u{251B}', LIGHT_UP_AND_LEFT),
+  ('\u{251D}', LIGHT_VERTICAL_AND_RIGHT),
+  ('\u{251E}', LIGHT_VERTICAL_AND_RIGHT),
+  ('\u{251F}', LIGHT_VERTICAL_AND_RIGHT),
+  ('\u{2520}', LIGHT_VERTICAL_AND_RIGHT),
+  ('\u{2521}', LIGHT_VERTICAL_AND_RIGHT),
+  ('\u{2522}', LIGHT_VERTICAL_AND_RIGHT),
+  ('\u{2523}', LIGHT_VERTICAL_AND_RIGHT),
+  ('\u{2525}', LIGHT_VERTICAL_AND_LEFT),
+  ('\u{2526}', LIGHT_VERTICAL_AND_LEFT),
+  ('\u{2527}', LIGHT_VERTICAL_AND_LEFT),
+  ('\u{2528}', LIGHT_VERTICAL_AND_LEFT),
+  ('\u{2529}', LIGHT_VERTICAL_AND_LEFT),
+  ('\u{252A}', LIGHT_VERTICAL_AND_LEFT),
+  ('\u{252B}', LIGHT_VERTICAL_AND_LEFT),
+  ('\u{252D}', LIGHT_DOWN_AND_HORIZONTAL),
+  ('\u{252E}', LIGHT_DOWN_AND_HORIZONTAL),
+  ('\u{252F}', LIGHT_DOWN_AND_HORIZONTAL),
+  ('\u{2530}', LIGHT_DOWN_AND_HORIZONTAL),
+  ('\u{2531}', LIGHT_DOWN_AND_HORIZONTAL),
+  ('\u{2532}', LIGHT_DOWN_AND_HORIZONTAL),
+  ('\u{2533}', LIGHT_DOWN_AND_HORIZONTAL),
+  ('\u{2535}', LIGHT_UP_AND_HORIZONTAL),
+  ('\u{2536}', LIGHT_UP_AND_HORIZONTAL),
+  ('\u{2537}', LIGHT_UP_AND_HORIZONTAL),
+  ('\u{2538}', LIGHT_UP_AND_HORIZONTAL),
+  ('\u{2539}', LIGHT_UP_AND_HORIZONTAL),
+  ('\u{253A}', LIGHT_UP_AND_HORIZONTAL),
+  ('\u{253B}', LIGHT_UP_AND_HORIZONTAL),
+  ('\u{253D}', LIGHT_VERTICAL_AND_HORIZONTAL),
+  ('\u{253E}', LIGHT_VERTICAL_AND_HORIZONTAL),
+  ('\u{253F}', LIGHT_VERTICAL_AND_HORIZONTAL),
+  ('\u{2540}', LIGHT_VERTICAL_AND_HORIZONTAL),
+  ('\u{2541}', LIGHT_VERTICAL_AND_HORIZONTAL),
+  ('\u{2542}', LIGHT_VERTICAL_AND_HORIZONTAL),
+  ('\u{2543}', LIGHT_VERTICAL_AND_HORIZONTAL),
+  ('\u{2544}', LIGHT_VERTICAL_AND_HORIZONTAL),
+  ('\u{2545}', LIGHT_VERTICAL_AND_HORIZONTAL),
+  ('\u{2546}', LIGHT_VERTICAL_AND_HORIZONTAL),
+  ('\u{2547}', LIGHT_VERTICAL_AND_HORIZONTAL),
+  ('\u{2548}', LIGHT_VERTICAL_AND_HORIZONTAL),
+  ('\u{2549}', LIGHT_VERTICAL_AND_HORIZONTAL),
+  ('\u{254A}', LIGHT_VERTICAL_AND_HORIZONTAL),
+  ('\u{254B}', LIGHT_VERTICAL_AND_HORIZONTAL),
+  ('\u{256D}', LIGHT_DOWN_AND_RIGHT),
+  ('\u{256E}', LIGHT_DOWN_AND_LEFT),
+  ('\u{256F}', LIGHT_UP_AND_LEFT),
+  ('\u{2570}', LIGHT_UP_AND_RIGHT),
+  ('\u{257C}', LIGHT_HORIZONTAL),
+  ('\u{257D}', LIGHT_VERTICAL),
+  ('\u{257E}', LIGHT_HORIZONTAL),
+  ('\u{257F}', LIGHT_VERTICAL),
+];
+
+/// Returns the canonical light or double box-drawing character for the variant
+/// of the box-drawing character, all other characters are returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use dtee::*;
+///
+/// assert_eq!(LIGHT_DOWN_AND_RIGHT, canonical_frame('╭'));
+/// assert_eq!(LIGHT_HORIZONTAL, canonical_frame('━'));
+/// assert_eq!(LIGHT_VERTICAL_AND_HORIZONTAL, canonical_frame('╋'));
+/// assert_eq!(DOUBLE_VERTICAL, canonical_frame(DOUBLE_VERTICAL));
+/// assert_eq!('a', canonical_frame('a'));
+/// ```
+pub fn canonical_frame(ch: char) -> char {
+  VARIANTS.iter().find(|(variant, _)| *variant == ch).map_or(ch, |(_, canonical)| *canonical)
+}
+
+/// Style of the line attached to a side of the box-drawing character.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Line {
+  /// No line is attached.
+  #[default]
+  Empty,
+  /// Single line is attached.
+  Single,
+  /// Double line is attached.
+  Double,
+}
+
+/// Lines attached to the sides of the box-drawing character.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct Arms {
+  /// Line attached to the top side.
+  pub up: Line,
+  /// Line attached to the right side.
+  pub right: Line,
+  /// Line attached to the bottom side.
+  pub down: Line,
+  /// Line attached to the left side.
+  pub left: Line,
+}
+
+/// Box-drawing characters with lines attached to their sides (up, right, down, left).
+const ARMS: [(char, [Line; 4]); 24] = {
+  use Line::{Double as D, Empty as E, Single as S};
+  [
+    (LIGHT_HORIZONTAL, [E, S, E, S]),
+    (LIGHT_VERTICAL, [S, E, S, E]),
+    (LIGHT_DOWN_AND_RIGHT, [E, S, S, E]),
+    (LIGHT_DOWN_AND_LEFT, [E, E, S, S]),
+    (LIGHT_UP_AND_RIGHT, [S, S, E, E]),
+    (LIGHT_UP_AND_LEFT, [S, E, E, S]),
+    (LIGHT_VERTICAL_AND_RIGHT, [S, S, S, E]),
+    (LIGHT_VERTICAL_AND_LEFT, [S, E, S, S]),
+    (LIGHT_DOWN_AND_HORIZONTAL, [E, S, S, S]),
+    (LIGHT_UP_AND_HORIZONTAL, [S, S, E, S]),
+    (LIGHT_VERTICAL_AND_HORIZONTAL, [S, S, S, S]),
+    (DOUBLE_HORIZONTAL, [E, D, E, D]),
+    (DOUBLE_VERTICAL, [D, E, D, E]),
+    (VERTICAL_SINGLE_AND_RIGHT_DOUBLE, [S, D, S, E]),
+    (VERTICAL_DOUBLE_AND_RIGHT_SINGLE, [D, S, D, E]),
+    (VERTICAL_SINGLE_AND_LEFT_DOUBLE, [S, E, S, D]),
+    (VERTICAL_DOUBLE_AND_LEFT_SINGLE, [D, E, D, S]),
+    (DOWN_SINGLE_AND_HORIZONTAL_DOUBLE, [E, D, S, D]),
+    (DOWN_DOUBLE_AND_HORIZONTAL_SINGLE, [E, S, D, S]),
+    (UP_SINGLE_AND_HORIZONTAL_DOUBLE, [S, D, E, D]),
+    (UP_DOUBLE_AND_HORIZONTAL_SINGLE, [D, S, E, S]),
+    (VERTICAL_SINGLE_AND_HORIZONTAL_DOUBLE, [S, D, S, D]),
+    (VERTICAL_DOUBLE_AND_HORIZONTAL_SINGLE, [D, S, D, S]),
+    (DOUBLE_VERTICAL_AND_HORIZONTAL, [D, D, D, D]),
+  ]
+};
+
+impl From<char> for Arms {
+  /// Returns the lines attached to the sides of the character,
+  /// characters other than box-drawing characters have no lines attached.
+  /// Variants of box-drawing characters have the lines of their canonical equivalents.
+  fn from(ch: char) -> Self {
+    let ch = canonical_frame(ch);
+    ARMS.iter().find(|(frame, _)| *frame == ch).map_or(Self::default(), |(_, [up, right, down, left])| Self {
+      up: *up,
+      right: *right,
+      down: *down,
+      left: *left,
+    })
+  }
+}
+
+impl Arms {
+  /// Returns the box-drawing character having the attached lines.
+  ///
+  /// Box-drawing characters mixing styles on the same axis do not exist, so in such a case
+  /// the stronger style is used for the whole axis. When there are no lines attached,
+  /// then a space is returned.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::*;
+  ///
+  /// let arms = Arms::from(LIGHT_DOWN_AND_LEFT);
+  /// assert_eq!(LIGHT_DOWN_AND_HORIZONTAL, Arms { right: Line::Single, ..arms }.char());
+  /// assert_eq!(DOWN_DOUBLE_AND_HORIZONTAL_SINGLE, Arms { right: Line::Single, down: Line::Double, ..arms }.char());
+  /// assert_eq!(VERTICAL_DOUBLE_AND_HORIZONTAL_SINGLE, Arms { up: Line::Single, right: Line::Single, down: Line::Double, ..arms }.char());
+  /// assert_eq!(SPACE, Arms::default().char());
+  /// ```
+  pub fn char(&self) -> char {
+    let vert = self.up.max(self.down);
+    let horz = self.left.max(self.right);
+    let uniform = |line: Line, style: Line| if line == Line::Empty { line } else { style };
+    let candidates = [
+      [self.up, self.right, self.down, self.left],
+      [uniform(self.up, vert), uniform(self.right, horz), uniform(self.down, vert), uniform(self.left, horz)],
+    ];
+    for lines in candidates {
+      if let Some((ch, _)) = ARMS.iter().find(|(_, arms)| *arms == lines) {
+        return *ch;
+      }
+    }
+    match (vert, horz) {
+      (Line::Double, _) => DOUBLE_VERTICAL,
+      (Line::Single, _) => LIGHT_VERTICAL,
+      (_, Line::Double) => DOUBLE_HORIZONTAL,
+      (_, Line::Single) => LIGHT_HORIZONTAL,
+      _ => SPACE,
+    }
+  }
+
+  /// Returns `true` when a line is attached to the top or to the bottom side.
+  pub fn is_vert(&self) -> bool {
+    self.up != Line::Empty || self.down != Line::Empty
+  }
+}
+
+/// Returns the number of terminal columns occupied by the text.
+///
+/// # Examples
+///
+/// ```
+/// use dtee::display_width;
+///
+/// assert_eq!(5, display_width("Preis"));
+/// assert_eq!(5, display_width("Ta\u{308}ter"));
+/// assert_eq!(4, display_width("価格"));
+/// ```
+pub fn display_width(text: &str) -> usize {
+  text.graphemes(true).map(grapheme_width).sum()
+}
+
+/// Returns the number of terminal columns occupied by a single grapheme cluster, at most two.
+fn grapheme_width(grapheme: &str) -> usize {
+  grapheme.width().min(2)
+}
+
+/// Converts the text into characters, one character per terminal column.
+///
+/// Grapheme clusters (like a letter followed by combining marks) are kept in a single character,
+/// wide grapheme clusters are followed by [CONTINUATION] and zero-width grapheme clusters
+/// are attached to the preceding character.
+///
+/// # Examples
+///
+/// ```
+/// use dtee::{to_chars, CONTINUATION};
+///
+/// let chars = to_chars("a\u{308}価");
+/// assert_eq!(3, chars.len());
+/// assert_eq!("a\u{308}", chars[0].to_string());
+/// assert_eq!("価", chars[1].to_string());
+/// assert_eq!(CONTINUATION, chars[2].char());
+/// ```
+pub fn to_chars(text: &str) -> Vec<Char> {
+  let mut chars: Vec<Char> = vec![];
+  for grapheme in text.graphemes(true) {
+    match (grapheme_width(grapheme), chars.last()) {
+      (0, Some(chr)) => grapheme.chars().for_each(|ch| chr.push_mark(ch)),
+      (2, _) => {
+        chars.push(Char::grapheme(grapheme));
+        chars.push(Char::from(CONTINUATION));
+      }
+      _ => chars.push(Char::grapheme(grapheme)),
+    }
+  }
+  chars
+}
+
 /// A character with associated attributes.
 ///
 /// The [Char] structure encapsulates a Unicode character and a set of attributes
-/// that define its visual and behavioral properties.
-/// Both the character and its attributes are stored in [RefCell] wrappers,
+/// that define its visual and behavioral properties. The character may be followed
+/// by marks (like combining diacritics) forming a single grapheme cluster.
+/// The character, its marks and its attributes are stored in [RefCell] wrappers,
 /// allowing for interior mutability.
 #[derive(Debug, Clone)]
 pub struct Char {
   /// Unicode character.
   ch: RefCell<char>,
+  /// Characters following the Unicode character in the same grapheme cluster, usually empty.
+  marks: RefCell<String>,
   /// Attributes associated with the character.
   attributes: RefCell<u8>,
 }
 
 impl Display for Char {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    write!(f, "{}", self.ch.borrow())
+    if self.is_continuation() {
+      return Ok(());
+    }
+    write!(f, "{}{}", self.ch.borrow(), self.marks.borrow())
   }
 }
 
 impl PartialEq for Char {
   fn eq(&self, other: &Self) -> bool {
-    self.ch == other.ch
+    self.ch == other.ch && self.marks == other.marks
   }
 }
 
 impl From<char> for Char {
   fn from(ch: char) -> Self {
-    Self {
-      ch: RefCell::new(ch),
-      attributes: RefCell::new(ATTRIBUTE_CLEAR),
-    }
+    Self::new(ch, ATTRIBUTE_CLEAR)
   }
 }
 
@@ -277,10 +547,20 @@ impl Char {
   pub fn new(ch: char, attributes: u8) -> Self {
     Self {
       ch: RefCell::new(ch),
+      marks: RefCell::new(String::new()),
       attributes: RefCell::new(attributes),
     }
   }
 
+  /// Creates a character from the grapheme cluster, the first Unicode character
+  /// becomes the character, all remaining ones become its marks.
+  pub fn grapheme(grapheme: &str) -> Self {
+    let mut chars = grapheme.chars();
+    let chr = Char::from(chars.next().unwrap_or(SPACE));
+    *chr.marks.borrow_mut() = chars.collect();
+    chr
+  }
+
   pub fn char(&self) -> char {
     *self.ch.borrow()
   }
@@ -289,8 +569,56 @@ impl Char {
     *self.attributes.borrow()
   }
 
+  /// Replaces the character, the marks are removed.
   pub fn set_char(&self, ch: char) {
     *self.ch.borrow_mut() = ch;
+    self.marks.borrow_mut().clear();
+  }
+
+  /// Replaces the character and its marks with the ones of the other character, attributes are retained.
+  pub fn set_content(&self, other: &Char) {
+    *self.ch.borrow_mut() = other.char();
+    *self.marks.borrow_mut() = other.marks.borrow().clone();
+  }
+
+  /// Appends a mark (like a combining diacritic) to the character.
+  pub fn push_mark(&self, ch: char) {
+    self.marks.borrow_mut().push(ch);
+  }
+
+  /// Returns `true` when the character is the placeholder in the second column of a wide character.
+  pub fn is_continuation(&self) -> bool {
+    self.char() == CONTINUATION
+  }
+
+  /// Returns the number of terminal columns occupied by the character,
+  /// the placeholder of the wide character occupies none.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use dtee::*;
+  /// assert_eq!(1, Char::from('a').width());
+  /// assert_eq!(2, Char::from('価').width());
+  /// assert_eq!(0, Char::from(CONTINUATION).width());
+  /// ```
+  pub fn width(&self) -> usize {
+    if self.is_continuation() {
+      0
+    } else {
+      grapheme_width(&self.to_string()).max(1)
+    }
+  }
+
+  /// Returns the canonical light or double equivalent of the underlying character,
+  /// see [canonical_frame].
+  pub fn canonical(&self) -> char {
+    canonical_frame(*self.ch.borrow())
+  }
+
+  /// Returns the lines attached to the sides of the character.
+  pub fn arms(&self) -> Arms {
+    Arms::from(*self.ch.borrow())
   }
 
   pub fn horz_fill(&self) -> Self {
@@ -365,10 +693,18 @@ impl Char {
   /// assert_eq!(true, Char::from(VERTICAL_SINGLE_AND_HORIZONTAL_DOUBLE).is_frame());
   /// assert_eq!(true, Char::from(VERTICAL_DOUBLE_AND_HORIZONTAL_SINGLE).is_frame());
   /// assert_eq!(true, Char::from(DOUBLE_VERTICAL_AND_HORIZONTAL).is_frame());
+  ///
+  /// // heavy and rounded variants are box-drawing characters too
+  /// assert_eq!(true, Char::from('━').is_frame());
+  /// assert_eq!(true, Char::from('┃').is_frame());
+  /// assert_eq!(true, Char::from('╭').is_frame());
+  /// assert_eq!(true, Char::from('╯').is_frame());
+  ///
+  /// assert_eq!(false, Char::from('a').is_frame());
   /// ```
   pub fn is_frame(&self) -> bool {
     matches!(
-      *self.ch.borrow(),
+      self.canonical(),
       LIGHT_HORIZONTAL
         | LIGHT_VERTICAL
         | LIGHT_DOWN_AND_RIGHT
@@ -427,10 +763,15 @@ impl Char {
   /// assert_eq!(false, Char::from(LIGHT_VERTICAL).is_crossing());
   /// assert_eq!(false, Char::from(DOUBLE_HORIZONTAL).is_crossing());
   /// assert_eq!(false, Char::from(DOUBLE_VERTICAL).is_crossing());
+  ///
+  /// assert_eq!(true, Char::from('┏').is_crossing());
+  /// assert_eq!(true, Char::from('╋').is_crossing());
+  /// assert_eq!(true, Char::from('╰').is_crossing());
+  /// assert_eq!(false, Char::from('━').is_crossing());
   /// ```
   pub fn is_crossing(&self) -> bool {
     matches!(
-      *self.ch.borrow(),
+      self.canonical(),
       LIGHT_DOWN_AND_RIGHT
         | LIGHT_DOWN_AND_LEFT
         | LIGHT_UP_AND_RIGHT
@@ -467,7 +808,7 @@ impl Char {
   /// assert_eq!(false, Char::from('═').is_vert_line());
   /// ```
   pub fn is_vert_line(&self) -> bool {
-    matches!(*self.ch.borrow(), LIGHT_VERTICAL | DOUBLE_VERTICAL)
+    matches!(self.canonical(), LIGHT_VERTICAL | DOUBLE_VERTICAL)
   }
 
   /// Checks whether the character is a vertical line or any crossing.
@@ -475,6 +816,37 @@ impl Char {
     self.is_vert_line() || self.is_crossing()
   }
 
+  /// Returns the character crossing the vertical line with a single horizontal line.
+  ///
+  /// The horizontal line is attached to the left side, to the right side or to both sides
+  /// of the vertical line, the style of the vertical line is preserved.
+  /// When the character is not a vertical line, then the single horizontal line is returned.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::*;
+  ///
+  /// assert_eq!(LIGHT_VERTICAL_AND_HORIZONTAL, Char::from(LIGHT_VERTICAL).horz_crossing(true, true));
+  /// assert_eq!(LIGHT_VERTICAL_AND_RIGHT, Char::from(LIGHT_VERTICAL).horz_crossing(false, true));
+  /// assert_eq!(LIGHT_VERTICAL_AND_LEFT, Char::from(LIGHT_VERTICAL).horz_crossing(true, false));
+  /// assert_eq!(VERTICAL_DOUBLE_AND_HORIZONTAL_SINGLE, Char::from(DOUBLE_VERTICAL).horz_crossing(true, true));
+  /// assert_eq!(VERTICAL_DOUBLE_AND_RIGHT_SINGLE, Char::from(DOUBLE_VERTICAL).horz_crossing(false, true));
+  /// assert_eq!(VERTICAL_DOUBLE_AND_LEFT_SINGLE, Char::from(DOUBLE_VERTICAL).horz_crossing(true, false));
+  /// assert_eq!(LIGHT_HORIZONTAL, Char::from(SPACE).horz_crossing(true, true));
+  /// ```
+  pub fn horz_crossing(&self, left: bool, right: bool) -> char {
+    match (self.canonical(), left, right) {
+      (LIGHT_VERTICAL, true, true) => LIGHT_VERTICAL_AND_HORIZONTAL,
+      (LIGHT_VERTICAL, false, true) => LIGHT_VERTICAL_AND_RIGHT,
+      (LIGHT_VERTICAL, true, false) => LIGHT_VERTICAL_AND_LEFT,
+      (DOUBLE_VERTICAL, true, true) => VERTICAL_DOUBLE_AND_HORIZONTAL_SINGLE,
+      (DOUBLE_VERTICAL, false, true) => VERTICAL_DOUBLE_AND_RIGHT_SINGLE,
+      (DOUBLE_VERTICAL, true, false) => VERTICAL_DOUBLE_AND_LEFT_SINGLE,
+      _ => LIGHT_HORIZONTAL,
+    }
+  }
+
   /// Checks whether the character is a single vertical line.
   ///
   /// # Examples
@@ -486,7 +858,7 @@ impl Char {
   /// assert_eq!(false, Char::from('║').is_single_vert_line());
   /// ```
   pub fn is_single_vert_line(&self) -> bool {
-    matches!(*self.ch.borrow(), LIGHT_VERTICAL)
+    matches!(self.canonical(), LIGHT_VERTICAL)
   }
 
   /// Checks whether the character is a double vertical line.
@@ -500,12 +872,12 @@ impl Char {
   /// assert_eq!(false, Char::from('│').is_double_vert_line());
   /// ```
   pub fn is_double_vert_line(&self) -> bool {
-    matches!(*self.ch.borrow(), DOUBLE_VERTICAL)
+    matches!(self.canonical(), DOUBLE_VERTICAL)
   }
 
   pub fn is_single_vert_line_crossing_left(&self) -> bool {
     matches!(
-      *self.ch.borrow(),
+      self.canonical(),
       LIGHT_VERTICAL_AND_HORIZONTAL
         | LIGHT_DOWN_AND_HORIZONTAL
         | LIGHT_UP_AND_HORIZONTAL
@@ -521,7 +893,7 @@ impl Char {
 
   pub fn is_double_vert_line_crossing_left(&self) -> bool {
     matches!(
-      *self.ch.borrow(),
+      self.canonical(),
       VERTICAL_SINGLE_AND_HORIZONTAL_DOUBLE
         | DOUBLE_VERTICAL_AND_HORIZONTAL
         | VERTICAL_SINGLE_AND_LEFT_DOUBLE
@@ -545,13 +917,15 @@ impl Char {
   /// assert_eq!(true, Char::from('├').is_vert_line_left());
   /// assert_eq!(true, Char::from('║').is_vert_line_left());
   /// assert_eq!(true, Char::from('╟').is_vert_line_left());
+  /// assert_eq!(true, Char::from('┃').is_vert_line_left());
+  /// assert_eq!(true, Char::from('┣').is_vert_line_left());
   ///
   /// assert_eq!(false, Char::from('┼').is_vert_line_left());
   /// assert_eq!(false, Char::from('╢').is_vert_line_left());
   /// ```
   pub fn is_vert_line_left(&self) -> bool {
     matches!(
-      *self.ch.borrow(),
+      self.canonical(),
       LIGHT_VERTICAL | LIGHT_VERTICAL_AND_RIGHT | DOUBLE_VERTICAL | VERTICAL_DOUBLE_AND_RIGHT_SINGLE
     )
   }
@@ -573,7 +947,7 @@ impl Char {
   /// ```
   pub fn is_vert_line_right(&self) -> bool {
     matches!(
-      *self.ch.borrow(),
+      self.canonical(),
       LIGHT_VERTICAL | LIGHT_VERTICAL_AND_LEFT | DOUBLE_VERTICAL | VERTICAL_DOUBLE_AND_LEFT_SINGLE
     )
   }
@@ -591,7 +965,7 @@ impl Char {
   /// assert_eq!(false, Char::from('║').is_horz_line());
   /// ```
   pub fn is_horz_line(&self) -> bool {
-    matches!(*self.ch.borrow(), LIGHT_HORIZONTAL | DOUBLE_HORIZONTAL)
+    matches!(self.canonical(), LIGHT_HORIZONTAL | DOUBLE_HORIZONTAL)
   }
 
   /// Checks whether the character is a horizontal line or any crossing.
@@ -610,6 +984,6 @@ impl Char {
   /// assert_eq!(false, Char::from('║').is_space());
   /// ```
   pub fn is_space(&self) -> bool {
-    matches!(*self.ch.borrow(), SPACE)
+    matches!(self.canonical(), SPACE)
   }
 }
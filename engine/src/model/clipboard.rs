@@ -0,0 +1,10 @@
+//! # Clipboard
+
+/// Content copied from the decision table.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Clipboard {
+  /// Lines of text copied from a single cell.
+  Text(Vec<String>),
+  /// Rectangular block of cells, stored as rows of cells, each cell containing its lines of text.
+  Block(Vec<Vec<Vec<String>>>),
+}
@@ -0,0 +1,103 @@
+//! # History of changes
+
+use crate::model::{Alignment, Cursor, Row};
+use std::collections::BTreeMap;
+
+/// Maximum number of steps remembered in the history.
+const HISTORY_LIMIT: usize = 1000;
+
+/// Kind of the edit operation recorded in the history.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Edit {
+  /// Typing a single character.
+  Typing,
+  /// Any other edit operation.
+  Other,
+}
+
+/// State of the edited content that can be restored.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+  /// Rows of the edited content.
+  rows: Vec<Row>,
+  /// Cursor position and attributes.
+  cursor: Cursor,
+  /// Alignments kept in columns, by the index of the column counted from the left.
+  alignments: BTreeMap<usize, Alignment>,
+}
+
+impl Snapshot {
+  /// Creates a new snapshot of the content, cursor and alignments kept in columns.
+  pub fn new(rows: Vec<Row>, cursor: Cursor, alignments: BTreeMap<usize, Alignment>) -> Self {
+    Self { rows, cursor, alignments }
+  }
+
+  /// Returns the stored rows, cursor and alignments kept in columns.
+  pub fn into_parts(self) -> (Vec<Row>, Cursor, BTreeMap<usize, Alignment>) {
+    (self.rows, self.cursor, self.alignments)
+  }
+}
+
+/// Undo and redo history of the edited content.
+///
+/// Every step stores the snapshot of the content taken before the edit.
+/// Consecutive typing (characters inserted one after another without moving the cursor
+/// in between) is grouped into a single step, so it is undone at once.
+#[derive(Debug, Default)]
+pub struct History {
+  /// Steps that can be undone.
+  undo: Vec<Snapshot>,
+  /// Steps that can be redone.
+  redo: Vec<Snapshot>,
+  /// Cursor position after the last typing, used for grouping consecutive typing.
+  typing: Option<(usize, usize)>,
+}
+
+impl History {
+  /// Returns `true` when the edit of the specified kind, starting at the specified cursor position,
+  /// continues the previously recorded step, so no new snapshot is needed.
+  pub fn continues(&self, edit: Edit, pos: (usize, usize)) -> bool {
+    edit == Edit::Typing && self.typing == Some(pos)
+  }
+
+  /// Records the edit.
+  ///
+  /// When the snapshot is `None`, then the edit continues the last step.
+  /// Recording a new edit always clears the redo steps.
+  pub fn record(&mut self, snapshot: Option<Snapshot>, edit: Edit, pos_after: (usize, usize)) {
+    if let Some(snapshot) = snapshot {
+      if self.undo.len() == HISTORY_LIMIT {
+        self.undo.remove(0);
+      }
+      self.undo.push(snapshot);
+    }
+    self.redo.clear();
+    self.typing = if edit == Edit::Typing { Some(pos_after) } else { None };
+  }
+
+  /// Takes the snapshot to be restored by undo and stores the current state for redo.
+  pub fn undo(&mut self, current: Snapshot) -> Option<Snapshot> {
+    let snapshot = self.undo.pop()?;
+    self.redo.push(current);
+    self.typing = None;
+    Some(snapshot)
+  }
+
+  /// Takes the snapshot to be restored by redo and stores the current state for undo.
+  pub fn redo(&mut self, current: Snapshot) -> Option<Snapshot> {
+    let snapshot = self.redo.pop()?;
+    self.undo.push(current);
+    self.typing = None;
+    Some(snapshot)
+  }
+
+  /// Returns `true` when there is a step that can be undone.
+  pub fn can_undo(&self) -> bool {
+    !self.undo.is_empty()
+  }
+
+  /// Returns `true` when there is a step that can be redone.
+  pub fn can_redo(&self) -> bool {
+    !self.redo.is_empty()
+  }
+}
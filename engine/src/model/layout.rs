@@ -0,0 +1,273 @@
+//! # Layout of the edited file
+
+use crate::model::{frame_from_ascii, frame_to_ascii, is_ascii_frame_line, Row, ASCII_CROSSING, ASCII_DOUBLE_HORIZONTAL, ASCII_SINGLE_HORIZONTAL};
+use crate::Char;
+
+/// Byte order mark.
+const BOM: char = '\u{FEFF}';
+
+/// Line endings used in the edited file.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum LineEnding {
+  /// Lines are terminated with `\n`.
+  #[default]
+  Lf,
+  /// Lines are terminated with `\r\n`.
+  CrLf,
+}
+
+impl LineEnding {
+  /// Returns the characters terminating a line.
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      LineEnding::Lf => "\n",
+      LineEnding::CrLf => "\r\n",
+    }
+  }
+}
+
+/// Layout of the text surrounding the edited decision table.
+///
+/// The layout remembers everything that is not the part of the decision table's frame,
+/// so the original text can be restored exactly when the content is serialized:
+/// the text placed before and after the decision table, the text surrounding each line
+/// of the decision table (like indentation and trailing whitespaces) and the line ending of each line.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Layout {
+  /// Flag indicating if the text starts with a byte order mark.
+  bom: bool,
+  /// Line ending of the first line in the text, used for lines added while editing.
+  line_ending: LineEnding,
+  /// Text placed before the decision table, including line endings.
+  before: String,
+  /// Text placed before and after each line of the decision table, the text after a line includes its line ending.
+  margins: Vec<(String, String)>,
+  /// Text placed after the decision table, including line endings.
+  after: String,
+  /// Flag indicating if the rows of the decision table are joined with ASCII frames.
+  ascii: bool,
+}
+
+impl Layout {
+  /// Splits the text into the layout and the lines constituting the decision table.
+  ///
+  /// The decision table is the first block of consecutive lines starting with a frame character
+  /// (leading whitespaces are ignored), or the first block of consecutive lines framed with ASCII characters,
+  /// starting with an ASCII border like `+---+`. All other lines are stored in the layout.
+  /// The block framed with ASCII characters is converted into box-drawing characters (see [frame_from_ascii])
+  /// and the layout remembers it, so the decision table is joined back with ASCII frames.
+  /// When there is no such block, then all non-empty lines are treated as the content,
+  /// and blank lines are stored in the layout.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::{Layout, LineEnding};
+  ///
+  /// let (layout, lines) = Layout::new("Text\r\n  ┌───┐\r\n  └───┘\r\n");
+  /// assert_eq!(LineEnding::CrLf, layout.line_ending());
+  /// assert_eq!("  ", layout.indent());
+  /// assert_eq!(vec!["┌───┐", "└───┘"], lines);
+  /// ```
+  pub fn new(text: &str) -> (Self, Vec<String>) {
+    Self::layouts(text, false).remove(0)
+  }
+
+  /// Splits the text into layouts and lines of all decision tables found in the text.
+  ///
+  /// Decision tables are blocks of consecutive lines detected like in [Layout::new],
+  /// separated with blank lines or any other text. The layout of each decision table
+  /// holds the text placed between the preceding decision table and this one,
+  /// the text placed after the last decision table is held by the last layout.
+  /// Joining each layout with its rows and concatenating the results restores the original text.
+  /// The returned list is never empty.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::Layout;
+  ///
+  /// let layouts = Layout::split("┌───┐\n└───┘\nText\n┌─┐\n└─┘\n");
+  /// assert_eq!(2, layouts.len());
+  /// assert_eq!(vec!["┌───┐", "└───┘"], layouts[0].1);
+  /// assert_eq!(vec!["┌─┐", "└─┘"], layouts[1].1);
+  /// ```
+  pub fn split(text: &str) -> Vec<(Self, Vec<String>)> {
+    Self::layouts(text, true)
+  }
+
+  /// Splits the text into layouts and lines of the first decision table, or of all decision tables.
+  fn layouts(text: &str, all: bool) -> Vec<(Self, Vec<String>)> {
+    let (bom, text) = match text.strip_prefix(BOM) {
+      Some(text) => (true, text),
+      None => (false, text),
+    };
+    let line_ending = match text.find('\n') {
+      Some(index) if text[..index].ends_with('\r') => LineEnding::CrLf,
+      _ => LineEnding::Lf,
+    };
+    // every line is split into the text of the line and its line ending (empty for the last line without line ending)
+    let lines = text
+      .split_inclusive('\n')
+      .map(|line| {
+        let length = line.strip_suffix("\r\n").or_else(|| line.strip_suffix('\n')).unwrap_or(line).len();
+        line.split_at(length)
+      })
+      .collect::<Vec<(&str, &str)>>();
+    let raw = |lines: &[(&str, &str)]| lines.iter().map(|(line, ending)| format!("{line}{ending}")).collect::<String>();
+    let layout = Self {
+      bom,
+      line_ending,
+      ..Default::default()
+    };
+    let is_first = |line: &str| is_table_line(line) || is_ascii_table_line(line);
+    let mut blocks = vec![];
+    let mut start = 0;
+    while let Some(first) = lines[start..].iter().position(|(line, _)| is_first(line)).map(|offset| start + offset) {
+      // every block is framed either with box-drawing characters or with ASCII characters
+      let ascii = !is_table_line(lines[first].0);
+      let is_line = if ascii { is_ascii_frame_line } else { is_table_line };
+      let last = lines[first..].iter().position(|(line, _)| !is_line(line)).map_or(lines.len(), |offset| first + offset);
+      blocks.push((first, last, ascii));
+      start = last;
+      if !all {
+        break;
+      }
+    }
+    if blocks.is_empty() {
+      // there is no decision table, so all non-empty lines are the content,
+      // blank lines are stored in the text placed before the content or after the preceding line
+      let mut layout = layout;
+      let mut content = vec![];
+      for (line, ending) in lines {
+        let trimmed = line.trim();
+        if !trimmed.is_empty() {
+          layout.margins.push(margins(line, ending));
+          content.push(trimmed.to_string());
+        } else if let Some((_, after)) = layout.margins.last_mut() {
+          after.push_str(&format!("{line}{ending}"));
+        } else {
+          layout.before.push_str(&format!("{line}{ending}"));
+        }
+      }
+      return vec![(layout, content)];
+    }
+    let count = blocks.len();
+    let mut previous = 0;
+    let mut layouts = Vec::with_capacity(count);
+    for (index, (first, last, ascii)) in blocks.into_iter().enumerate() {
+      let is_last = index + 1 == count;
+      let content = lines[first..last].iter().map(|(line, _)| line.trim().to_string()).collect::<Vec<String>>();
+      let content = if ascii { frame_from_ascii(&content) } else { content };
+      let table_layout = Self {
+        bom: bom && index == 0,
+        before: raw(&lines[previous..first]),
+        margins: lines[first..last].iter().map(|(line, ending)| margins(line, ending)).collect(),
+        after: if is_last { raw(&lines[last..]) } else { String::new() },
+        ascii,
+        ..layout.clone()
+      };
+      layouts.push((table_layout, content));
+      previous = last;
+    }
+    layouts
+  }
+
+  /// Joins the rows of the decision table with the surrounding text.
+  ///
+  /// Box-drawing characters in rows are converted into ASCII frames when the layout is ASCII,
+  /// see [Layout::set_ascii], the surrounding text is never converted.
+  ///
+  /// Each row is surrounded by the text remembered for the line at the same position.
+  /// Rows added while editing are indented like the first line and terminated
+  /// with the line ending of the first line in the text, the last row is always
+  /// terminated like the last line of the original decision table.
+  pub fn join(&self, rows: &[Row]) -> String {
+    let mut text = String::new();
+    if self.bom {
+      text.push(BOM);
+    }
+    text.push_str(&self.before);
+    for (index, row) in rows.iter().enumerate() {
+      let (before, after) = self.margins(index, rows.len());
+      text.push_str(before);
+      text.push_str(&if self.ascii { frame_to_ascii(&row.text()) } else { row.text() });
+      text.push_str(after);
+    }
+    text.push_str(&self.after);
+    text
+  }
+
+  /// Returns the text placed before and after the row with the specified index.
+  fn margins(&self, index: usize, count: usize) -> (&str, &str) {
+    let before = self.margins.get(index).or(self.margins.first()).map_or("", |(before, _)| before.as_str());
+    let after = if index + 1 == count {
+      self.margins.last().map_or("", |(_, after)| after.as_str())
+    } else if index + 1 < self.margins.len() {
+      self.margins[index].1.as_str()
+    } else {
+      self.line_ending.as_str()
+    };
+    (before, after)
+  }
+
+  /// Returns `true` when the text starts with a byte order mark.
+  pub fn bom(&self) -> bool {
+    self.bom
+  }
+
+  /// Returns the line ending of the first line in the text.
+  pub fn line_ending(&self) -> LineEnding {
+    self.line_ending
+  }
+
+  /// Returns `true` when the rows of the decision table are joined with ASCII frames.
+  pub fn ascii(&self) -> bool {
+    self.ascii
+  }
+
+  /// Sets the flag indicating if the rows of the decision table are joined with ASCII frames.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::{Layout, Row};
+  ///
+  /// let (mut layout, lines) = Layout::new("Table ─\n┌───┐\n│ A │\n└───┘\n");
+  /// let rows = lines.into_iter().map(Row::from).collect::<Vec<Row>>();
+  /// layout.set_ascii(true);
+  /// assert_eq!("Table ─\n+---+\n| A |\n+---+\n", layout.join(&rows));
+  /// ```
+  pub fn set_ascii(&mut self, ascii: bool) {
+    self.ascii = ascii;
+  }
+
+  /// Returns the left indentation of the first line of the decision table.
+  pub fn indent(&self) -> &str {
+    self.margins.first().map_or("", |(before, _)| before.as_str())
+  }
+}
+
+/// Returns the text placed before and after the trimmed line, the text after the line ends with the line ending.
+fn margins(line: &str, ending: &str) -> (String, String) {
+  let start = line.len() - line.trim_start().len();
+  let end = line.trim_end().len().max(start);
+  (line[..start].to_string(), format!("{}{ending}", &line[end..]))
+}
+
+/// Returns `true` when the line (after skipping surrounding whitespaces) is an ASCII border,
+/// like the first line of a decision table framed with ASCII characters: a run of horizontal lines
+/// (`-` or `=`) and crossings, starting and ending with a crossing, e.g. `+---+===+`.
+fn is_ascii_table_line(line: &str) -> bool {
+  let line = line.trim();
+  line.len() > 2
+    && line.starts_with(ASCII_CROSSING)
+    && line.ends_with(ASCII_CROSSING)
+    && line[1..].starts_with([ASCII_SINGLE_HORIZONTAL, ASCII_DOUBLE_HORIZONTAL])
+    && line.chars().all(|ch| matches!(ch, ASCII_CROSSING | ASCII_SINGLE_HORIZONTAL | ASCII_DOUBLE_HORIZONTAL))
+}
+
+/// Returns `true` when the line (after skipping leading whitespaces) starts with a frame character.
+fn is_table_line(line: &str) -> bool {
+  line.trim_start().chars().next().is_some_and(|ch| Char::from(ch).is_frame())
+}
@@ -1,11 +1,21 @@
 //! # Model
 
+mod alignment;
+mod ascii;
 mod characters;
+mod clipboard;
 pub mod cursor;
+mod history;
+mod layout;
 mod plane;
 mod row;
 
+pub use alignment::*;
+pub use ascii::*;
 pub use characters::*;
+pub use clipboard::*;
 pub use cursor::*;
+pub use history::*;
+pub use layout::*;
 pub use plane::*;
 pub use row::*;
@@ -2,11 +2,19 @@
 
 use crate::model::characters::*;
 use crate::model::cursor::{Cursor, CursorShape};
-use crate::model::Row;
-use crate::{Region, SPACE};
+use crate::model::{Alignment, Clipboard, Layout, Row, Snapshot};
+use crate::{parse_decision_table, table_orientation, DecisionTable, Orientation, Region, TableError, SPACE};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
 
 type JoinRowInfo = Option<(usize, bool)>;
 
+/// Line of text in a cell: indexes of the frames on both sides and of the first and the last character of the text.
+type CellLine = (usize, usize, Option<(usize, usize)>);
+
+/// Width of the newly inserted column, including margins.
+const COLUMN_WIDTH: usize = 5;
+
 /// Edited text with cursor.
 pub struct Plane {
   /// Edited text stored as rows of characters.
@@ -15,17 +23,26 @@ pub struct Plane {
   cursor: Cursor,
   /// Calculated size of the textual content.
   size: Option<Region>,
+  /// Layout of the text surrounding the edited content.
+  layout: Layout,
+  /// Position where the selection starts, the selection ends at the cursor position.
+  anchor: Option<(usize, usize)>,
+  /// Alignments kept in columns while editing, by the index of the column counted from the left.
+  alignments: BTreeMap<usize, Alignment>,
 }
 
 fn join_index(content: &[Row]) -> Option<usize> {
-  let left_top = content.first().and_then(|row| row.first().map(|chr| chr.char()))?;
+  let left_top = content.first().and_then(|row| row.first().map(|chr| chr.canonical()))?;
   if left_top == LIGHT_DOWN_AND_RIGHT {
-    let right_top = content.first().and_then(|row| row.last().map(|chr| chr.char()))?;
+    let right_top = content.first().and_then(|row| row.last().map(|chr| chr.canonical()))?;
     if right_top == LIGHT_DOWN_AND_LEFT {
       let col_index = content.first().unwrap().len() - 1;
       for (index, row) in content.iter().enumerate() {
         if let Some(chr) = row.get(col_index) {
-          if chr.char() == LIGHT_UP_AND_HORIZONTAL || chr.char() == LIGHT_VERTICAL_AND_HORIZONTAL || chr.char() == VERTICAL_DOUBLE_AND_HORIZONTAL_SINGLE {
+          if matches!(
+            chr.canonical(),
+            LIGHT_UP_AND_HORIZONTAL | LIGHT_VERTICAL_AND_HORIZONTAL | VERTICAL_DOUBLE_AND_HORIZONTAL_SINGLE
+          ) {
             return Some(index);
           }
         }
@@ -35,21 +52,46 @@ fn join_index(content: &[Row]) -> Option<usize> {
   None
 }
 
+/// Returns the lines of text in cells of the specified row, the text is `None` when the line is blank.
+fn cell_lines(row: &Row) -> Vec<CellLine> {
+  let frames = (0..row.len()).filter(|col_index| row[*col_index].is_frame()).collect::<Vec<usize>>();
+  frames
+    .windows(2)
+    .filter(|pair| pair[1] > pair[0] + 1)
+    .map(|pair| {
+      let first = (pair[0] + 1..pair[1]).find(|col_index| !row[*col_index].is_space());
+      let last = (pair[0] + 1..pair[1]).rfind(|col_index| !row[*col_index].is_space());
+      (pair[0], pair[1], first.zip(last))
+    })
+    .collect()
+}
+
+/// Returns the line of text in the cell covering the specified column index, see [cell_lines].
+fn cell_line(row: &Row, col_index: usize) -> Option<CellLine> {
+  cell_lines(row).into_iter().find(|(left, right, _)| *left < col_index && col_index < *right)
+}
+
+/// Checks whether the specified row is a line of text in cells, crossed only by vertical lines.
+fn is_text_row(row: &Row) -> bool {
+  row.iter().all(|chr| !chr.is_frame() || (chr.arms().left == Line::Empty && chr.arms().right == Line::Empty))
+}
+
+/// Checks whether both rows are lines of text in the same cells.
+fn is_same_cells(upper: &Row, lower: &Row) -> bool {
+  upper.len() == lower.len() && is_text_row(upper) && is_text_row(lower) && upper.iter().zip(lower.iter()).all(|(a, b)| a.is_frame() == b.is_frame())
+}
+
 impl Plane {
   /// Creates a new text plane populated with the specified content.
   pub fn new<T: ToString>(content: T) -> Self {
-    let content = content
-      .to_string()
-      .lines()
-      .filter_map(|line| {
-        let line = line.trim();
-        if line.is_empty() {
-          None
-        } else {
-          Some(line.into())
-        }
-      })
-      .collect::<Vec<Row>>();
+    let (layout, lines) = Layout::new(&content.to_string());
+    Self::from_parts(layout, lines)
+  }
+
+  /// Creates a new text plane populated with the lines of a single decision table,
+  /// surrounded by the text remembered in the layout, see [Layout::split].
+  pub fn from_parts(layout: Layout, lines: Vec<String>) -> Self {
+    let content = lines.into_iter().map(Row::from).collect::<Vec<Row>>();
 
     //FIXME start: setting join this way is temporary
     if let Some(index) = join_index(&content) {
@@ -62,6 +104,9 @@ impl Plane {
       rows: content,
       cursor,
       size: None,
+      layout,
+      anchor: None,
+      alignments: BTreeMap::new(),
     }
   }
 
@@ -70,6 +115,450 @@ impl Plane {
     &self.rows
   }
 
+  /// Returns the content of the text plane as a single string,
+  /// with the surrounding text, indentation and line endings restored.
+  pub fn text(&self) -> String {
+    self.layout.join(&self.rows)
+  }
+
+  /// Returns the typed decision table parsed from the content of the text plane.
+  pub fn decision_table(&self) -> Result<DecisionTable, TableError> {
+    parse_decision_table(&self.rows)
+  }
+
+  /// Returns a reference to the layout of the text surrounding the content.
+  pub fn layout(&self) -> &Layout {
+    &self.layout
+  }
+
+  /// Sets the flag indicating if the content is saved with ASCII frames, see [Layout::set_ascii].
+  pub fn set_ascii(&mut self, ascii: bool) {
+    self.layout.set_ascii(ascii);
+  }
+
+  /// Returns the snapshot of the content, the cursor and the alignments kept in columns.
+  pub fn snapshot(&self) -> Snapshot {
+    Snapshot::new(self.rows.clone(), self.cursor, self.alignments.clone())
+  }
+
+  /// Restores the content, the cursor position and the alignments kept in columns from the snapshot.
+  ///
+  /// The shape of the cursor is not restored, it remains as it was set by the user.
+  pub fn restore(&mut self, snapshot: Snapshot) {
+    let (rows, cursor, alignments) = snapshot.into_parts();
+    self.rows = rows;
+    self.alignments = alignments;
+    self.cursor.set(cursor.col(), cursor.row());
+    self.anchor = None;
+    self.invalidate_content_region();
+  }
+
+  /// Starts the selection at the current cursor position, unless the selection is already started.
+  pub fn selection_start(&mut self) {
+    if self.anchor.is_none() {
+      self.anchor = Some(self.cursor.pos());
+    }
+  }
+
+  /// Clears the selection, returns `true` when there was a selection to clear.
+  pub fn selection_clear(&mut self) -> bool {
+    self.anchor.take().is_some()
+  }
+
+  /// Returns the selected region.
+  ///
+  /// When the selection starts and ends in the same cell, then the region covers
+  /// only the selected text inside this cell. Otherwise, the region is expanded
+  /// to cover all the cells crossed by the selection.
+  pub fn selection(&self) -> Option<Region> {
+    let (anchor_col, anchor_row) = self.anchor?;
+    let (cursor_col, cursor_row) = self.cursor.pos();
+    // Calculate the columns of the selected characters, where the selection starts and ends.
+    let (anchor_col, cursor_col) = if self.cursor.is_caret() {
+      // The caret is placed between characters, so the character on the right side of the selection is not selected.
+      match anchor_col.cmp(&cursor_col) {
+        Ordering::Less => (anchor_col, cursor_col - 1),
+        Ordering::Greater => (anchor_col - 1, cursor_col),
+        // The caret may be placed on the vertical line closing the cell, then the character before is selected.
+        Ordering::Equal if self.is_frame_at(cursor_col, cursor_row) || self.is_frame_at(anchor_col, anchor_row) => (anchor_col - 1, cursor_col - 1),
+        Ordering::Equal => (anchor_col, cursor_col),
+      }
+    } else {
+      (anchor_col, cursor_col)
+    };
+    let (left, right) = (anchor_col.min(cursor_col), anchor_col.max(cursor_col));
+    let (top, bottom) = (anchor_row.min(cursor_row), anchor_row.max(cursor_row));
+    let selection = Region::new(left, top, right - left + 1, bottom - top + 1);
+    // Retrieve the cells, where the selection starts and ends.
+    let anchor_cell = self.cell_region(anchor_col, anchor_row);
+    let cursor_cell = self.cell_region(cursor_col, cursor_row);
+    if anchor_cell.is_some() && anchor_cell == cursor_cell {
+      return Some(selection);
+    }
+    // Expand the selection until all crossed cells are fully covered.
+    let mut expanded = [anchor_cell, cursor_cell].iter().flatten().fold(selection, |region, cell| region.union(cell));
+    loop {
+      let mut region = expanded;
+      for row_index in expanded.top()..=expanded.bottom() {
+        for col_index in expanded.left()..=expanded.right() {
+          if let Some(cell) = self.cell_region(col_index, row_index) {
+            region = region.union(&cell);
+          }
+        }
+      }
+      if region == expanded {
+        return Some(expanded);
+      }
+      expanded = region;
+    }
+  }
+
+  /// Returns the region covering the content of the cell containing the specified character.
+  fn cell_region(&self, col_index: usize, row_index: usize) -> Option<Region> {
+    if self.is_frame_at(col_index, row_index) {
+      return None;
+    }
+    let (left_index, right_index) = self.rows.get(row_index)?.cell_range(col_index)?;
+    let mut top_index = row_index;
+    while top_index > 0 && !self.is_frame_at(col_index, top_index - 1) {
+      top_index -= 1;
+    }
+    let mut bottom_index = row_index;
+    while bottom_index + 1 < self.rows.len() && !self.is_frame_at(col_index, bottom_index + 1) {
+      bottom_index += 1;
+    }
+    Some(Region::new(left_index, top_index, right_index - left_index + 1, bottom_index - top_index + 1))
+  }
+
+  /// Returns `true` when the character at the specified position is a frame character.
+  /// Positions outside the content are treated like frame characters.
+  fn is_frame_at(&self, col_index: usize, row_index: usize) -> bool {
+    self.rows.get(row_index).and_then(|row| row.get(col_index)).is_none_or(|chr| chr.is_frame())
+  }
+
+  /// Copies the content of the specified region.
+  ///
+  /// When the region lies inside a single cell, then the selected text is copied.
+  /// Otherwise, the whole content of all the cells in the region is copied.
+  pub fn copy(&self, region: &Region) -> Clipboard {
+    if self.is_single_cell(region) {
+      Clipboard::Text(
+        (region.top()..=region.bottom())
+          .map(|row_index| self.text_range(row_index, region.left(), region.right()))
+          .collect(),
+      )
+    } else {
+      Clipboard::Block(self.cells(region).iter().map(|cells| cells.iter().map(|cell| self.cell_lines(cell)).collect()).collect())
+    }
+  }
+
+  /// Clears the content of the specified region.
+  ///
+  /// When the region lies inside a single cell, then the selected text is deleted
+  /// and the text on the right side is shifted left. Otherwise, all the cells
+  /// in the region are filled with whitespaces.
+  pub fn clear(&mut self, region: &Region) -> bool {
+    if self.is_single_cell(region) {
+      let mut changed = false;
+      for row_index in region.top()..=region.bottom() {
+        self.cursor.set(region.left(), row_index);
+        for _ in 0..region.width() {
+          changed |= self.delete_single_char_under_cursor();
+        }
+      }
+      self.cursor.set(region.left(), region.top());
+      changed
+    } else {
+      for cell in self.cells(region).iter().flatten() {
+        self.fill_cell(cell);
+      }
+      if let Some(cell) = self.cells(region).first().and_then(|cells| cells.first()) {
+        self.cursor.set(cell.left(), cell.top());
+      }
+      true
+    }
+  }
+
+  /// Pastes the content of the clipboard at the cursor position.
+  ///
+  /// The text is inserted at the cursor position, the block of cells replaces
+  /// the content of the cells starting from the cell pointed by the cursor.
+  /// Columns are widened and lines are split when needed.
+  pub fn paste(&mut self, clipboard: &Clipboard) -> bool {
+    match clipboard {
+      Clipboard::Text(lines) => {
+        let mut changed = false;
+        for (index, line) in lines.iter().enumerate() {
+          if index > 0 && !self.split_line() {
+            break;
+          }
+          for ch in line.chars() {
+            changed |= self.insert_char(ch);
+          }
+        }
+        changed
+      }
+      Clipboard::Block(rows) => {
+        let (col_index, row_index) = self.cursor.pos();
+        // The caret may be placed on the vertical line closing the cell.
+        let col_index = if self.is_frame_at(col_index, row_index) {
+          col_index.saturating_sub(1)
+        } else {
+          col_index
+        };
+        let Some(start) = self.cell_region(col_index, row_index) else {
+          return false;
+        };
+        let mut first = Some(start);
+        for cells in rows {
+          let Some(first_cell) = first else {
+            break;
+          };
+          let mut target = Some(first_cell);
+          for lines in cells {
+            let Some(cell) = target else {
+              break;
+            };
+            self.write_cell(&cell, lines);
+            // the cell may be resized after writing, so the next cell is searched using the updated region
+            target = self.cell_region(cell.left(), cell.top()).and_then(|cell| self.cell_region(cell.right() + 2, cell.top()));
+          }
+          first = self
+            .cell_region(first_cell.left(), first_cell.top())
+            .and_then(|cell| self.cell_region(cell.left(), cell.bottom() + 2));
+        }
+        self.cursor.set(start.left(), start.top());
+        true
+      }
+    }
+  }
+
+  /// Returns `true` when the region lies inside a single cell.
+  fn is_single_cell(&self, region: &Region) -> bool {
+    let cell = self.cell_region(region.left(), region.top());
+    cell.is_some() && cell == self.cell_region(region.right(), region.bottom())
+  }
+
+  /// Returns the regions of all cells in the specified region, grouped in rows.
+  fn cells(&self, region: &Region) -> Vec<Vec<Region>> {
+    let mut cells: Vec<Region> = vec![];
+    for row_index in region.top()..=region.bottom() {
+      for col_index in region.left()..=region.right() {
+        if let Some(cell) = self.cell_region(col_index, row_index) {
+          if !cells.contains(&cell) {
+            cells.push(cell);
+          }
+        }
+      }
+    }
+    cells.sort_by_key(|cell| (cell.top(), cell.left()));
+    let mut rows: Vec<Vec<Region>> = vec![];
+    for cell in cells {
+      match rows.last_mut() {
+        Some(row) if row[0].top() == cell.top() => row.push(cell),
+        _ => rows.push(vec![cell]),
+      }
+    }
+    rows
+  }
+
+  /// Returns the lines of text in the cell, without trailing empty lines.
+  fn cell_lines(&self, cell: &Region) -> Vec<String> {
+    let mut lines = (cell.top()..=cell.bottom())
+      .map(|row_index| self.text_range(row_index, cell.left(), cell.right()))
+      .collect::<Vec<String>>();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+      lines.pop();
+    }
+    lines
+  }
+
+  /// Returns the text in the specified row between specified columns, without trailing whitespaces.
+  fn text_range(&self, row_index: usize, left_index: usize, right_index: usize) -> String {
+    self.rows[row_index][left_index..=right_index]
+      .iter()
+      .map(|chr| chr.to_string())
+      .collect::<String>()
+      .trim_end()
+      .to_string()
+  }
+
+  /// Fills the cell with whitespaces.
+  fn fill_cell(&self, cell: &Region) {
+    for row_index in cell.top()..=cell.bottom() {
+      for col_index in cell.left()..=cell.right() {
+        self.rows[row_index][col_index].set_char(SPACE);
+      }
+    }
+  }
+
+  /// Replaces the content of the cell with specified lines of text,
+  /// the cell is widened and lines are split when needed.
+  fn write_cell(&mut self, cell: &Region, lines: &[String]) {
+    self.fill_cell(cell);
+    // add lines to the cell when it is not high enough
+    let mut height = cell.height();
+    while height < lines.len() {
+      self.cursor.set(cell.left(), cell.top() + height - 1);
+      if !self.split_line() {
+        break;
+      }
+      height += 1;
+    }
+    for (offset, line) in lines.iter().enumerate().take(height) {
+      self.cursor.set(cell.left(), cell.top() + offset);
+      for ch in line.chars() {
+        self.insert_char(ch);
+      }
+    }
+  }
+
+  /// Aligns the text in all cells in the specified region, every line of text is aligned separately.
+  ///
+  /// The cursor placed in the aligned line is moved together with the text.
+  pub fn align(&mut self, region: &Region, alignment: Alignment) -> bool {
+    let mut changed = false;
+    for cell in self.cells(region).iter().flatten() {
+      changed |= self.align_cell(cell, alignment);
+    }
+    changed
+  }
+
+  /// Aligns the text in all cells of the column pointed by the cursor.
+  ///
+  /// Columns are bounded by vertical lines in the last row of the decision table, so cells
+  /// spanning several columns (like the information item name) are not aligned.
+  /// When `keep` is `true`, then the alignment is kept in this column while editing,
+  /// see [Plane::keep_alignment], otherwise the previously kept alignment is forgotten.
+  pub fn align_column(&mut self, alignment: Alignment, keep: bool) -> bool {
+    let Some((column_index, left_index, right_index)) = self.cursor_cell().and_then(|cell| self.column(&cell)) else {
+      return false;
+    };
+    if keep {
+      self.alignments.insert(column_index, alignment);
+    } else {
+      self.alignments.remove(&column_index);
+    }
+    self.align_column_cells(left_index, right_index, alignment)
+  }
+
+  /// Aligns the text in all cells of the column pointed by the cursor, when the alignment is kept in this column.
+  ///
+  /// Called after typing or deleting characters, so the text stays aligned while editing.
+  /// All cells are aligned, because typing or deleting may widen or narrow the whole column.
+  pub fn keep_alignment(&mut self) -> bool {
+    let Some((column_index, left_index, right_index)) = self.cursor_cell().and_then(|cell| self.column(&cell)) else {
+      return false;
+    };
+    let Some(alignment) = self.alignments.get(&column_index).copied() else {
+      return false;
+    };
+    self.align_column_cells(left_index, right_index, alignment)
+  }
+
+  /// Aligns the text in all cells having specified indexes of the first and the last character.
+  fn align_column_cells(&mut self, left_index: usize, right_index: usize, alignment: Alignment) -> bool {
+    let mut cells: Vec<Region> = vec![];
+    for row_index in 0..self.rows.len() {
+      if let Some(cell) = self.cell_region(left_index, row_index) {
+        if cell.left() == left_index && cell.right() == right_index && !cells.contains(&cell) {
+          cells.push(cell);
+        }
+      }
+    }
+    let mut changed = false;
+    for cell in cells {
+      changed |= self.align_cell(&cell, alignment);
+    }
+    changed
+  }
+
+  /// Returns the region of the cell pointed by the cursor.
+  pub fn cursor_cell(&self) -> Option<Region> {
+    let (col_index, row_index) = self.cursor.pos();
+    // The caret may be placed on the vertical line closing the cell.
+    let col_index = if self.is_frame_at(col_index, row_index) {
+      col_index.saturating_sub(1)
+    } else {
+      col_index
+    };
+    self.cell_region(col_index, row_index)
+  }
+
+  /// Returns the indexes of vertical lines in the last row of the decision table,
+  /// these lines bound the columns spanning all rows.
+  fn column_lines(&self) -> Vec<usize> {
+    self
+      .rows
+      .last()
+      .map(|row| {
+        row
+          .iter()
+          .enumerate()
+          .filter(|(_, chr)| chr.arms().up != Line::Empty)
+          .map(|(col_index, _)| col_index)
+          .collect()
+      })
+      .unwrap_or_default()
+  }
+
+  /// Returns the index of the column containing the specified cell,
+  /// together with the indexes of the first and the last character of this column.
+  fn column(&self, cell: &Region) -> Option<(usize, usize, usize)> {
+    let lines = self.column_lines();
+    let column_index = lines.iter().position(|col_index| col_index + 1 == cell.left())?;
+    let right_index = lines.get(column_index + 1)? - 1;
+    (right_index == cell.right()).then_some((column_index, cell.left(), right_index))
+  }
+
+  /// Updates the indexes of columns with kept alignments after inserting (`shift` is `1`)
+  /// or deleting (`shift` is `-1`) the column with the specified index.
+  fn shift_alignments(&mut self, column_index: usize, shift: isize) {
+    self.alignments = std::mem::take(&mut self.alignments)
+      .into_iter()
+      .filter(|(index, _)| shift > 0 || *index != column_index)
+      .map(|(index, alignment)| {
+        if index < column_index {
+          (index, alignment)
+        } else {
+          (index.saturating_add_signed(shift), alignment)
+        }
+      })
+      .collect();
+  }
+
+  /// Aligns every line of text in the cell, returns `true` when any line was moved.
+  fn align_cell(&mut self, cell: &Region, alignment: Alignment) -> bool {
+    let mut changed = false;
+    for row_index in cell.top()..=cell.bottom() {
+      let chars = self.rows[row_index][cell.left()..=cell.right()].to_vec();
+      let Some(start) = chars.iter().position(|chr| !chr.is_space()) else {
+        continue;
+      };
+      let end = chars.iter().rposition(|chr| !chr.is_space()).unwrap_or(start);
+      let length = end - start + 1;
+      let offset = alignment.offset(cell.width(), length);
+      if offset == start {
+        continue;
+      }
+      for (index, chr) in self.rows[row_index][cell.left()..=cell.right()].iter().enumerate() {
+        if (offset..offset + length).contains(&index) {
+          chr.set_content(&chars[index + start - offset]);
+        } else {
+          chr.set_char(SPACE);
+        }
+      }
+      // the cursor placed in the aligned line is moved together with the text
+      let (col_index, cursor_row_index) = self.cursor.pos();
+      let last_index = if self.cursor.is_caret() { cell.right() + 1 } else { cell.right() };
+      if cursor_row_index == row_index && (cell.left()..=last_index).contains(&col_index) {
+        self.cursor.set_col((col_index + offset).saturating_sub(start).clamp(cell.left(), last_index));
+      }
+      changed = true;
+    }
+    changed
+  }
+
   /// Returns the region of the content.
   pub fn region(&mut self) -> &Region {
     if self.size.is_none() {
@@ -356,6 +845,10 @@ impl Plane {
   fn is_allowed_position(&self, row_offset: isize, col_offset: isize) -> bool {
     let (col, row) = self.cursor.offset(col_offset, row_offset);
     if row > 0 && row < self.rows.len() - 1 && col > 0 && col < self.rows[row].len() {
+      // moving horizontally, the cursor jumps over the second column of a wide character
+      if col_offset != 0 && self.rows[row][col].is_continuation() {
+        return false;
+      }
       if self.cursor.is_caret() {
         return !self.rows[row][col].is_frame() || self.rows[row][col].is_vert_line_left();
       } else if col < self.rows[row].len() - 1 {
@@ -365,6 +858,13 @@ impl Plane {
     false
   }
 
+  /// Moves the cursor one position left, when it points to the second column of a wide character.
+  pub fn cursor_skip_continuation(&mut self) {
+    if self.cursor_char().is_some_and(|chr| chr.is_continuation()) {
+      self.cursor.dec_col(1);
+    }
+  }
+
   pub fn cursor_toggle_caret_block(&mut self) {
     let shape = self.cursor.toggle_caret_block();
     if matches!(shape, CursorShape::Block | CursorShape::UnderScore) {
@@ -387,7 +887,38 @@ impl Plane {
     }
   }
 
+  /// Inserts the character at the cursor position.
+  ///
+  /// Zero-width characters (like combining diacritics) are attached to the character before the cursor,
+  /// wide characters occupy two columns, the second one is filled with [CONTINUATION].
   pub fn insert_char(&mut self, ch: char) -> bool {
+    match display_width(&ch.to_string()) {
+      0 => self.attach_mark(ch),
+      1 => self.insert_single_char(ch),
+      _ => self.insert_single_char(ch) && self.insert_single_char(CONTINUATION),
+    }
+  }
+
+  /// Attaches the zero-width character to the character before the cursor.
+  fn attach_mark(&mut self, ch: char) -> bool {
+    let (col_index, row_index) = self.cursor.pos();
+    let Some(row) = self.rows.get(row_index) else {
+      return false;
+    };
+    let mut mark_index = col_index.saturating_sub(1);
+    if row.get(mark_index).is_some_and(|chr| chr.is_continuation()) {
+      mark_index = mark_index.saturating_sub(1);
+    }
+    match row.get(mark_index) {
+      Some(chr) if col_index > 0 && !chr.is_frame() => {
+        chr.push_mark(ch);
+        true
+      }
+      _ => false,
+    }
+  }
+
+  fn insert_single_char(&mut self, ch: char) -> bool {
     // get the current cursor position
     let (col_index, row_index) = self.cursor.pos();
     // find the index of the first `left vertical line` starting from the current cursor position
@@ -442,7 +973,36 @@ impl Plane {
     false
   }
 
+  /// Overrides the character under the cursor.
+  ///
+  /// Zero-width characters are attached to the character before the cursor. Wide characters
+  /// override two columns, so they are not placed in the last column of a cell.
+  /// Halves of overridden wide characters are replaced with spaces.
   pub fn override_char(&mut self, ch: char) -> bool {
+    let (col_index, row_index) = self.cursor.pos();
+    let width = display_width(&ch.to_string());
+    if width == 0 {
+      return self.attach_mark(ch);
+    }
+    let Some(row) = self.rows.get(row_index) else {
+      return false;
+    };
+    if width > 1 && row.get(col_index + 1).is_none_or(|chr| chr.is_frame()) {
+      return false;
+    }
+    for index in col_index..col_index + width {
+      if row.get(index + 1).is_some_and(|chr| chr.is_continuation()) {
+        row[index + 1].set_char(SPACE);
+      }
+    }
+    if width > 1 {
+      self.override_single_char(ch) && self.override_single_char(CONTINUATION)
+    } else {
+      self.override_single_char(ch)
+    }
+  }
+
+  fn override_single_char(&mut self, ch: char) -> bool {
     // Get the current cursor position.
     let (col_index, row_index) = self.cursor.pos();
     // Find the index of the first `left vertical line` starting from the current cursor position.
@@ -464,7 +1024,14 @@ impl Plane {
     false
   }
 
+  /// Deletes the character before the cursor, wide characters are deleted together with their second column.
   pub fn delete_char_before_cursor(&mut self) -> bool {
+    let (col_index, row_index) = self.cursor.pos();
+    let is_wide = col_index > 0 && self.rows.get(row_index).and_then(|row| row.get(col_index - 1)).is_some_and(|chr| chr.is_continuation());
+    self.delete_single_char_before_cursor() && (!is_wide || self.delete_single_char_before_cursor())
+  }
+
+  fn delete_single_char_before_cursor(&mut self) -> bool {
     // get the current cursor position
     let (col_index, row_index) = self.cursor.pos();
     // get the character before the cursor, otherwise there is nothing to do
@@ -496,7 +1063,14 @@ impl Plane {
     false
   }
 
+  /// Deletes the character under the cursor, wide characters are deleted together with their second column.
   pub fn delete_char_under_cursor(&mut self) -> bool {
+    let (col_index, row_index) = self.cursor.pos();
+    let is_wide = self.rows.get(row_index).and_then(|row| row.get(col_index + 1)).is_some_and(|chr| chr.is_continuation());
+    self.delete_single_char_under_cursor() && (!is_wide || self.delete_single_char_under_cursor())
+  }
+
+  fn delete_single_char_under_cursor(&mut self) -> bool {
     // Get the current cursor position.
     let (col_index, row_index) = self.cursor.pos();
     // Get the character under the cursor, otherwise there is nothing to do.
@@ -518,13 +1092,17 @@ impl Plane {
             // When the cursor is block or underscore, then check if after removing spaces,
             // the cursor is not positioned on the frame. If this is the case,
             // then move the cursor one position left.
-            if (self.cursor.is_block() || self.cursor().is_under_score()) && self.cursor_char().map_or(false, |chr| chr.is_frame()) {
+            if (self.cursor.is_block() || self.cursor().is_under_score()) && self.cursor_char().is_some_and(|chr| chr.is_frame()) {
               self.cursor.dec_col(1);
             }
             // Return `true` to signal that a character was deleted.
             return true;
           }
         }
+      } else if chr.is_vert_line() && self.cursor.is_caret() {
+        // When the caret is placed just before the right vertical line of the cell,
+        // then try to move the content from the line below to the current line.
+        return self.unsplit_line_below();
       }
     }
     false
@@ -567,7 +1145,7 @@ impl Plane {
             // shift down the content and insert whitespace at the top of the shifted area
             for (ix_col, top_row_index) in top_row_indexes.iter().enumerate() {
               for ix_row in (*top_row_index + 1..=bottom_row_index).rev() {
-                self.rows[ix_row][ix_col].set_char(self.rows[ix_row - 1][ix_col].char());
+                self.rows[ix_row][ix_col].set_content(&self.rows[ix_row - 1][ix_col]);
               }
               let chr = &self.rows[*top_row_index - 1][ix_col];
               let ch = if chr.is_frame() { chr.char() } else { SPACE };
@@ -579,14 +1157,14 @@ impl Plane {
           // shift the content of the edited cell one row down starting below the current row
           for ix_col in left_index..=right_index {
             for ix_row in (row_index + 1..=last_row_index).rev() {
-              self.rows[ix_row][ix_col].set_char(self.rows[ix_row - 1][ix_col].char());
+              self.rows[ix_row][ix_col].set_content(&self.rows[ix_row - 1][ix_col]);
             }
           }
           // move text after current cursor position to line below
           let mut ix_col = col_index;
           let mut ix_col_below = left_index;
           while ix_col <= right_index {
-            self.rows[row_index + 1][ix_col_below].set_char(self.rows[row_index][ix_col].char());
+            self.rows[row_index + 1][ix_col_below].set_content(&self.rows[row_index][ix_col]);
             self.rows[row_index][ix_col].set_char(SPACE);
             ix_col += 1;
             ix_col_below += 1;
@@ -626,7 +1204,7 @@ impl Plane {
             if right_index - left_index + 1 >= text_len_above + text_len_below {
               // If there is enough space, append the content from the current row to the end of the row above.
               for i in 0..text_len_below {
-                row_above[left_index + text_len_above + i].set_char(row[left_index + i].char());
+                row_above[left_index + text_len_above + i].set_content(&row[left_index + i]);
                 row[left_index + i].set_char(SPACE);
               }
               // Shift the rest of the cell up by one row, leaving only whitespace before the horizontal line.
@@ -634,7 +1212,7 @@ impl Plane {
                 let last_row_index = horz_line_row_index.saturating_sub(1);
                 for ix_col in left_index..=right_index {
                   for ix_row in row_index..last_row_index {
-                    self.rows[ix_row][ix_col].set_char(self.rows[ix_row + 1][ix_col].char());
+                    self.rows[ix_row][ix_col].set_content(&self.rows[ix_row + 1][ix_col]);
                   }
                   self.rows[last_row_index][ix_col].set_char(SPACE);
                 }
@@ -653,7 +1231,1092 @@ impl Plane {
     false
   }
 
-  /// aaa
+  /// Unsplits (joins) the line in a row below with the current line.
+  ///
+  /// The cursor must be placed just before the right vertical line of the cell.
+  /// When the lines can not be joined, the cursor position remains unchanged.
+  pub fn unsplit_line_below(&mut self) -> bool {
+    // Retrieve the current cursor position.
+    let (col_index, row_index) = self.cursor.pos();
+    // Retrieve the index of the first character in the current cell.
+    let Some((left_index, _)) = self.rows.get(row_index).and_then(|row| row.cell_range(col_index.saturating_sub(1))) else {
+      return false;
+    };
+    // Make sure, the row below belongs to the same cell.
+    if self.rows.get(row_index + 1).and_then(|row| row.get(left_index)).is_none_or(|chr| chr.is_frame()) {
+      return false;
+    }
+    // Join the lines as if the cursor was placed at the beginning of the cell in the row below.
+    self.cursor.set(left_index, row_index + 1);
+    if self.unsplit_line() {
+      return true;
+    }
+    // Restore the cursor position when the lines could not be joined.
+    self.cursor.set(col_index, row_index);
+    false
+  }
+
+  /// Inserts a new empty rule above the rule pointed by the cursor.
+  ///
+  /// In vertical decision tables, the new rule column is inserted on the left side of the current rule.
+  /// In crosstabs, the new row of values is inserted above the current row.
+  pub fn insert_rule_above(&mut self) -> bool {
+    if self.is_crosstab() {
+      return self.insert_value_row(false);
+    }
+    if self.is_vertical() {
+      let Some((left_index, _)) = self.rule_column_range() else {
+        return false;
+      };
+      return self.insert_rule_column(left_index - 1);
+    }
+    let Some((top_index, bottom_index)) = self.rule_range() else {
+      return false;
+    };
+    let (separator, content) = self.rule_template(bottom_index);
+    self.rows.insert(top_index, content);
+    self.rows.insert(top_index + 1, separator);
+    self.cursor.set_row(top_index);
+    self.renumber_rules();
+    self.invalidate_content_region();
+    true
+  }
+
+  /// Inserts a new empty rule below the rule pointed by the cursor.
+  ///
+  /// In vertical decision tables, the new rule column is inserted on the right side of the current rule.
+  /// In crosstabs, the new row of values is inserted below the current row.
+  pub fn insert_rule_below(&mut self) -> bool {
+    if self.is_crosstab() {
+      return self.insert_value_row(true);
+    }
+    if self.is_vertical() {
+      let Some((_, right_index)) = self.rule_column_range() else {
+        return false;
+      };
+      return self.insert_rule_column(right_index + 1);
+    }
+    let Some((_, bottom_index)) = self.rule_range() else {
+      return false;
+    };
+    let (separator, content) = self.rule_template(bottom_index);
+    self.rows.insert(bottom_index + 1, separator);
+    self.rows.insert(bottom_index + 2, content);
+    self.cursor.set_row(bottom_index + 2);
+    self.renumber_rules();
+    self.invalidate_content_region();
+    true
+  }
+
+  /// Deletes the rule pointed by the cursor.
+  ///
+  /// The rule is deleted together with the separator below it. When the deleted rule
+  /// is the last one, then the separator above it is deleted. The only rule in the decision table
+  /// can not be deleted. In vertical decision tables, the rule column is deleted
+  /// together with its right vertical line. Rules of crosstabs are not deleted.
+  pub fn delete_rule(&mut self) -> bool {
+    if self.is_crosstab() {
+      return false;
+    }
+    if self.is_vertical() {
+      let rule_columns = self.rule_columns();
+      let Some((left_index, right_index)) = self.rule_column_range() else {
+        return false;
+      };
+      let edge_index = rule_columns.last().map_or(right_index, |(_, last_index)| *last_index) + 1;
+      if rule_columns.len() < 2 || !self.drain_column(left_index - 1, right_index + 1, edge_index) {
+        return false;
+      }
+      self.renumber_rules();
+      return true;
+    }
+    let Some((top_index, bottom_index)) = self.rule_range() else {
+      return false;
+    };
+    let rules = self.rules();
+    if rules.len() < 2 {
+      return false;
+    }
+    if rules.last() == Some(&(top_index, bottom_index)) {
+      // delete the separator above the last rule and move the cursor to the previous rule
+      self.rows.drain(top_index - 1..=bottom_index);
+      self.cursor.set_row(top_index - 2);
+    } else {
+      // delete the separator below the rule and leave the cursor in the next rule
+      self.rows.drain(top_index..=bottom_index + 1);
+      self.cursor.set_row(top_index);
+    }
+    self.renumber_rules();
+    self.invalidate_content_region();
+    true
+  }
+
+  /// Swaps the rule pointed by the cursor with the rule above it
+  /// (with the rule on the left side in vertical decision tables).
+  pub fn move_rule_up(&mut self) -> bool {
+    if self.is_crosstab() {
+      return false;
+    }
+    if self.is_vertical() {
+      let rule_columns = self.rule_columns();
+      let col_index = self.cursor.col();
+      return match rule_columns.iter().position(|(left_index, right_index)| (*left_index..=*right_index).contains(&col_index)) {
+        Some(index) if index > 0 => self.swap_rule_columns(rule_columns[index - 1], rule_columns[index]),
+        _ => false,
+      };
+    }
+    let rules = self.rules();
+    let row_index = self.cursor.row();
+    match rules.iter().position(|(top_index, bottom_index)| (*top_index..=*bottom_index).contains(&row_index)) {
+      Some(index) if index > 0 => self.swap_rules(rules[index - 1], rules[index]),
+      _ => false,
+    }
+  }
+
+  /// Swaps the rule pointed by the cursor with the rule below it
+  /// (with the rule on the right side in vertical decision tables).
+  pub fn move_rule_down(&mut self) -> bool {
+    if self.is_crosstab() {
+      return false;
+    }
+    if self.is_vertical() {
+      let rule_columns = self.rule_columns();
+      let col_index = self.cursor.col();
+      return match rule_columns.iter().position(|(left_index, right_index)| (*left_index..=*right_index).contains(&col_index)) {
+        Some(index) if index + 1 < rule_columns.len() => self.swap_rule_columns(rule_columns[index], rule_columns[index + 1]),
+        _ => false,
+      };
+    }
+    let rules = self.rules();
+    let row_index = self.cursor.row();
+    match rules.iter().position(|(top_index, bottom_index)| (*top_index..=*bottom_index).contains(&row_index)) {
+      Some(index) if index + 1 < rules.len() => self.swap_rules(rules[index], rules[index + 1]),
+      _ => false,
+    }
+  }
+
+  /// Swaps two adjacent rules, the cursor is moved together with the rule it points to.
+  fn swap_rules(&mut self, upper: (usize, usize), lower: (usize, usize)) -> bool {
+    let upper_height = upper.1 - upper.0 + 1;
+    let lower_height = lower.1 - lower.0 + 1;
+    let separator_height = lower.0 - upper.1 - 1;
+    let row_index = self.cursor.row();
+    if row_index <= upper.1 {
+      self.cursor.set_row(row_index + lower_height + separator_height);
+    } else {
+      self.cursor.set_row(row_index - lower_height - separator_height);
+    }
+    // upper rule, separator, lower rule => separator, lower rule, upper rule => lower rule, separator, upper rule
+    self.rows[upper.0..=lower.1].rotate_left(upper_height);
+    self.rows[upper.0..upper.0 + separator_height + lower_height].rotate_left(separator_height);
+    self.renumber_rules();
+    true
+  }
+
+  /// Places the cursor in the first row of the next rule, in the same column
+  /// (at the first character of the next rule column in vertical decision tables).
+  ///
+  /// When the cursor is placed in the header, it is moved to the first rule.
+  /// In crosstabs, the cursor is moved to the next row of values.
+  pub fn cursor_move_rule_next(&mut self) -> bool {
+    if self.is_vertical() {
+      let col_index = self.cursor.col();
+      let row_index = self.cursor.row().max(self.body_index() + 1);
+      return match self.rule_columns().into_iter().find(|(left_index, _)| *left_index > col_index) {
+        Some((left_index, _)) => {
+          self.cursor.set(left_index, row_index);
+          true
+        }
+        None => false,
+      };
+    }
+    let row_index = self.cursor.row();
+    let rules = if self.is_crosstab() { self.value_rows() } else { self.rules() };
+    match rules.into_iter().find(|(top_index, _)| *top_index > row_index) {
+      Some((top_index, _)) => {
+        self.cursor.set_row(top_index);
+        true
+      }
+      None => false,
+    }
+  }
+
+  /// Places the cursor in the first row of the previous rule, in the same column
+  /// (at the first character of the previous rule column in vertical decision tables).
+  /// In crosstabs, the cursor is moved to the previous row of values.
+  pub fn cursor_move_rule_prev(&mut self) -> bool {
+    if self.is_vertical() {
+      let col_index = self.cursor.col();
+      let row_index = self.cursor.row().max(self.body_index() + 1);
+      return match self.rule_columns().into_iter().rev().find(|(_, right_index)| *right_index < col_index) {
+        Some((left_index, _)) => {
+          self.cursor.set(left_index, row_index);
+          true
+        }
+        None => false,
+      };
+    }
+    let row_index = self.cursor.row();
+    let rules = if self.is_crosstab() { self.value_rows() } else { self.rules() };
+    match rules.into_iter().rev().find(|(_, bottom_index)| *bottom_index < row_index) {
+      Some((top_index, _)) => {
+        self.cursor.set_row(top_index);
+        true
+      }
+      None => false,
+    }
+  }
+
+  /// Returns the orientation of the edited decision table.
+  fn orientation(&self) -> Orientation {
+    table_orientation(&self.rows)
+  }
+
+  /// Returns `true` when the edited decision table is vertical, i.e. rules are columns.
+  fn is_vertical(&self) -> bool {
+    self.orientation() == Orientation::Vertical
+  }
+
+  /// Returns `true` when the edited decision table is a crosstab.
+  fn is_crosstab(&self) -> bool {
+    self.orientation() == Orientation::Crosstab
+  }
+
+  /// Returns the index of the line separating the header of the crosstab from the grid (`╞═══╡`),
+  /// the index of the vertical line on the left side of the values placed in rows
+  /// and the index of the double line on the right side of them.
+  fn crosstab_lines(&self) -> Option<(usize, usize, usize)> {
+    let header_index = self
+      .rows
+      .iter()
+      .position(|row| row.first().is_some_and(|chr| chr.canonical() == VERTICAL_SINGLE_AND_RIGHT_DOUBLE))?;
+    let row = &self.rows[header_index];
+    let values_index = row.iter().skip(1).position(|chr| chr.arms().down != Line::Empty)? + 1;
+    let io_index = row.iter().position(|chr| chr.arms().up == Line::Double)?;
+    Some((header_index, values_index, io_index))
+  }
+
+  /// Returns the indexes of the first and the last row of all rows of values in the crosstab.
+  fn value_rows(&self) -> Vec<(usize, usize)> {
+    let Some((header_index, values_index, _)) = self.crosstab_lines() else {
+      return vec![];
+    };
+    let mut value_rows = vec![];
+    let mut top_index = None;
+    for (row_index, row) in self.rows.iter().enumerate().skip(header_index + 1) {
+      let is_value_row = row.get(values_index + 1).is_some_and(|chr| !chr.is_frame());
+      match (is_value_row, top_index) {
+        (true, None) => top_index = Some(row_index),
+        (false, Some(index)) => {
+          value_rows.push((index, row_index - 1));
+          top_index = None;
+        }
+        _ => {}
+      }
+    }
+    value_rows
+  }
+
+  /// Returns the indexes of the first and the last character of all columns of values in the crosstab.
+  fn value_columns(&self) -> Vec<(usize, usize)> {
+    let Some((header_index, _, io_index)) = self.crosstab_lines() else {
+      return vec![];
+    };
+    let lines = self.rows[header_index]
+      .iter()
+      .enumerate()
+      .skip(io_index)
+      .filter(|(_, chr)| chr.arms().is_vert())
+      .map(|(col_index, _)| col_index)
+      .collect::<Vec<usize>>();
+    lines.windows(2).map(|pair| (pair[0] + 1, pair[1] - 1)).collect()
+  }
+
+  /// Inserts a new empty row of values above or below the row of the crosstab pointed by the cursor.
+  ///
+  /// The expression of the input placed in rows spans all rows of values,
+  /// so its text is written again to the enlarged cell, starting from the top.
+  fn insert_value_row(&mut self, below: bool) -> bool {
+    let Some((header_index, values_index, _)) = self.crosstab_lines() else {
+      return false;
+    };
+    let value_rows = self.value_rows();
+    let row_index = self.cursor.row();
+    let Some(&(top_index, bottom_index)) = value_rows.iter().find(|(top_index, bottom_index)| (*top_index..=*bottom_index).contains(&row_index)) else {
+      return false;
+    };
+    let Some(&(_, last_index)) = value_rows.last() else {
+      return false;
+    };
+    let expression = Region::new(1, header_index + 1, values_index - 1, last_index - header_index);
+    let lines = self.cell_lines(&expression);
+    let row = &self.rows[bottom_index];
+    let last_col_index = row.len().saturating_sub(1);
+    let separator = Row::from(
+      row
+        .iter()
+        .enumerate()
+        .map(|(col_index, chr)| match col_index.cmp(&values_index) {
+          Ordering::Less if chr.is_vert_line() => chr.char(),
+          Ordering::Less => SPACE,
+          Ordering::Equal => chr.horz_crossing(false, true),
+          Ordering::Greater => chr.horz_crossing(true, col_index < last_col_index),
+        })
+        .collect::<String>(),
+    );
+    let content = Row::from(row.iter().map(|chr| if chr.is_vert_line() { chr.char() } else { SPACE }).collect::<String>());
+    let content_index = if below {
+      self.rows.insert(bottom_index + 1, separator);
+      self.rows.insert(bottom_index + 2, content);
+      bottom_index + 2
+    } else {
+      self.rows.insert(top_index, content);
+      self.rows.insert(top_index + 1, separator);
+      top_index
+    };
+    let cursor = self.cursor;
+    self.write_cell(&Region::new(1, header_index + 1, values_index - 1, last_index - header_index + 2), &lines);
+    self.cursor = cursor;
+    self.cursor.set_row(content_index);
+    self.invalidate_content_region();
+    true
+  }
+
+  /// Inserts a new empty column of values on the left or right side of the column
+  /// of the crosstab pointed by the cursor.
+  ///
+  /// The expression of the input placed in columns spans all columns of values,
+  /// so its text is written again to the widened cell.
+  fn insert_value_column(&mut self, right: bool) -> bool {
+    let Some((_, _, io_index)) = self.crosstab_lines() else {
+      return false;
+    };
+    let col_index = self.cursor.col();
+    let Some((left_index, right_index)) = self
+      .value_columns()
+      .into_iter()
+      .find(|(left_index, right_index)| (*left_index..=*right_index).contains(&col_index))
+    else {
+      return false;
+    };
+    let top_index = self.body_index() + 1;
+    let Some(bottom_index) = (top_index..self.rows.len()).find(|row_index| self.rows[*row_index].get(io_index).is_some_and(|chr| chr.arms().right != Line::Empty)) else {
+      return false;
+    };
+    let width = self.rows[top_index].len() - io_index - 2;
+    let expression = Region::new(io_index + 1, top_index, width, bottom_index - top_index);
+    let lines = self.cell_lines(&expression);
+    if !self.insert_column(if right { right_index + 1 } else { left_index - 1 }, false) {
+      return false;
+    }
+    let cursor = self.cursor;
+    self.write_cell(&Region::new(io_index + 1, top_index, width + COLUMN_WIDTH + 1, bottom_index - top_index), &lines);
+    self.cursor = cursor;
+    true
+  }
+
+  /// Returns the indexes of the first and the last character of all rule columns
+  /// in the vertical decision table.
+  ///
+  /// Rule columns are placed on the right side of the double line separating
+  /// the header from the rules (`║`) in the first row of the body, containing rule numbers.
+  fn rule_columns(&self) -> Vec<(usize, usize)> {
+    let Some(row) = self.rows.get(self.body_index() + 1) else {
+      return vec![];
+    };
+    let Some(header_index) = row.iter().position(|chr| chr.is_double_vert_line()) else {
+      return vec![];
+    };
+    let lines = row
+      .iter()
+      .enumerate()
+      .skip(header_index)
+      .filter(|(_, chr)| chr.is_vert_line())
+      .map(|(col_index, _)| col_index)
+      .collect::<Vec<usize>>();
+    lines.windows(2).map(|pair| (pair[0] + 1, pair[1] - 1)).collect()
+  }
+
+  /// Returns the indexes of the first and the last character of the rule column pointed by the cursor.
+  fn rule_column_range(&self) -> Option<(usize, usize)> {
+    let (col_index, row_index) = self.cursor.pos();
+    if row_index <= self.body_index() {
+      return None;
+    }
+    self
+      .rule_columns()
+      .into_iter()
+      .find(|(left_index, right_index)| (*left_index..=*right_index).contains(&col_index))
+  }
+
+  /// Inserts a new empty rule column on the right side of the vertical line with the specified index.
+  fn insert_rule_column(&mut self, vert_index: usize) -> bool {
+    if !self.insert_column(vert_index, false) {
+      return false;
+    }
+    self.renumber_rules();
+    true
+  }
+
+  /// Swaps two adjacent rule columns, the cursor is moved together with the rule it points to.
+  fn swap_rule_columns(&mut self, left: (usize, usize), right: (usize, usize)) -> bool {
+    let col_index = self.cursor.col();
+    self.swap_columns(left.0 - 1, right.0 - 1, right.1 + 1);
+    if col_index <= left.1 {
+      self.cursor.inc_col(right.1 - left.1);
+    } else {
+      self.cursor.dec_col(left.1 + 1 - left.0 + 1);
+    }
+    self.renumber_rules();
+    true
+  }
+
+  /// Returns the indexes of the first and the last row of the rule pointed by the cursor.
+  fn rule_range(&self) -> Option<(usize, usize)> {
+    let row_index = self.cursor.row();
+    self.rules().into_iter().find(|(top_index, bottom_index)| (*top_index..=*bottom_index).contains(&row_index))
+  }
+
+  /// Returns the indexes of the first and the last row of all rules in the decision table.
+  ///
+  /// Rules are placed below the line separating the header from the rules (`╞═══╡`),
+  /// each rule is a group of rows starting with the vertical line.
+  fn rules(&self) -> Vec<(usize, usize)> {
+    let mut rules = vec![];
+    let Some(header_index) = self
+      .rows
+      .iter()
+      .position(|row| row.first().is_some_and(|chr| chr.canonical() == VERTICAL_SINGLE_AND_RIGHT_DOUBLE))
+    else {
+      return rules;
+    };
+    let mut top_index = None;
+    for (row_index, row) in self.rows.iter().enumerate().skip(header_index + 1) {
+      let is_rule_row = row.first().is_some_and(|chr| chr.is_vert_line());
+      match (is_rule_row, top_index) {
+        (true, None) => top_index = Some(row_index),
+        (false, Some(index)) => {
+          rules.push((index, row_index - 1));
+          top_index = None;
+        }
+        _ => {}
+      }
+    }
+    if let Some(index) = top_index {
+      rules.push((index, self.rows.len() - 1));
+    }
+    rules
+  }
+
+  /// Returns the separator and the empty content row, having the frame structure
+  /// of the row with the specified index.
+  fn rule_template(&self, row_index: usize) -> (Row, Row) {
+    let row = &self.rows[row_index];
+    let last_index = row.len().saturating_sub(1);
+    let separator = Row::from(
+      row
+        .iter()
+        .enumerate()
+        .map(|(col_index, chr)| chr.horz_crossing(col_index > 0, col_index < last_index))
+        .collect::<String>(),
+    );
+    let content = Row::from(row.iter().map(|chr| if chr.is_vert_line() { chr.char() } else { SPACE }).collect::<String>());
+    (separator, content)
+  }
+
+  /// Updates the numbers of rules in the first column of the decision table
+  /// (in the first row of the body in vertical decision tables),
+  /// but only when all the rules are already numbered (new rules are empty).
+  fn renumber_rules(&mut self) {
+    let is_vertical = self.is_vertical();
+    let ranges = if is_vertical {
+      let row_index = self.body_index() + 1;
+      // numbers are written from right to left, so widening a column does not shift the remaining ones
+      self
+        .rule_columns()
+        .into_iter()
+        .rev()
+        .map(|(left_index, right_index)| (row_index, left_index, right_index))
+        .collect()
+    } else {
+      let mut ranges = vec![];
+      for (top_index, _) in self.rules() {
+        let Some((left_index, right_index)) = self.rows[top_index].cell_range(1) else {
+          return;
+        };
+        ranges.push((top_index, left_index, right_index));
+      }
+      ranges
+    };
+    let mut cells = Vec::with_capacity(ranges.len());
+    for (row_index, left_index, right_index) in ranges {
+      let text = self.text_range(row_index, left_index, right_index);
+      if !text.is_empty() && text.trim().parse::<usize>().is_err() {
+        return;
+      }
+      cells.push((row_index, left_index, right_index, text.len() - text.trim_start().len()));
+    }
+    // use the indentation of the numbered rules for the new (empty) rules
+    let Some(indent) = cells
+      .iter()
+      .find(|(row_index, left_index, right_index, _)| !self.rows[*row_index].is_empty_range(*left_index, *right_index))
+      .map(|cell| cell.3)
+    else {
+      return;
+    };
+    let count = cells.len();
+    let cursor = self.cursor;
+    let mut shift = 0;
+    for (index, (row_index, left_index, right_index, cell_indent)) in cells.into_iter().enumerate() {
+      let number = if is_vertical { count - index } else { index + 1 };
+      // rule columns keep their own indentation, numbers of new rule columns are centered
+      let indent = match (is_vertical, self.rows[row_index].is_empty_range(left_index, right_index)) {
+        (false, _) => indent,
+        (true, false) => cell_indent,
+        (true, true) => (right_index - left_index + 1).saturating_sub(number.to_string().len()) / 2,
+      };
+      let width = self.rows[row_index].len();
+      for col_index in left_index..=right_index {
+        self.rows[row_index][col_index].set_char(SPACE);
+      }
+      self.cursor.set(left_index, row_index);
+      for ch in format!("{}{}", " ".repeat(indent), number).chars() {
+        self.insert_char(ch);
+      }
+      // columns may be widened, then the cursor placed on the right side must be shifted
+      if cursor.col() > right_index {
+        shift += self.rows[row_index].len() - width;
+      }
+    }
+    self.cursor = cursor;
+    self.cursor.inc_col(shift);
+  }
+
+  /// Transposes the decision table, so rules become columns and clauses become rows, or the other way around.
+  ///
+  /// The decision table is parsed, rendered in the other orientation and the cursor
+  /// is placed in the top-left cell, alignments kept in columns are forgotten.
+  /// Nothing is changed when the decision table can not be parsed.
+  pub fn transpose(&mut self) -> bool {
+    let Ok(decision_table) = self.decision_table() else {
+      return false;
+    };
+    let orientation = decision_table.orientation().transposed();
+    let plane = Plane::new(decision_table.with_orientation(orientation));
+    self.rows = plane.rows;
+    self.cursor = plane.cursor;
+    self.anchor = None;
+    self.alignments.clear();
+    self.invalidate_content_region();
+    true
+  }
+
+  /// Reformats the decision table in place, with the specified padding on both sides of the text in cells.
+  ///
+  /// Empty lines at the end of cells are removed, then every column is widened where the text
+  /// is closer to the frame than the padding, and shrunk by removing surplus spaces. The text
+  /// keeps its offset in cells, so the alignment of the text is preserved, and nothing else
+  /// (rule numbers, hit policy, headers) is rewritten. The joining row below the information
+  /// item name is updated like after any other change of column widths.
+  /// Nothing is changed when the decision table can not be parsed or when it is already formatted.
+  pub fn reformat(&mut self, padding: usize) -> bool {
+    if self.decision_table().is_err() {
+      return false;
+    }
+    let mut changed = self.remove_empty_trailing_lines();
+    while let Some(col_index) = self.padding_position(padding) {
+      self.insert_position(col_index);
+      changed = true;
+    }
+    let width = self.rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    for col_index in (1..width).rev() {
+      while let Some(positions) = self.removable_positions(col_index, padding) {
+        self.remove_positions(&positions);
+        changed = true;
+      }
+    }
+    if !changed {
+      return false;
+    }
+    self.update_joining_row(self.join_row_info());
+    let (col_index, row_index) = self.cursor.pos();
+    let is_frame = |col_index: usize| self.rows.get(row_index).and_then(|row| row.get(col_index)).is_none_or(|chr| chr.is_frame());
+    if is_frame(col_index) {
+      if col_index > 0 && !is_frame(col_index - 1) {
+        self.cursor.dec_col(1);
+      } else {
+        self.cursor.set(1, 1);
+      }
+    }
+    self.anchor = None;
+    self.invalidate_content_region();
+    true
+  }
+
+  /// Removes empty lines at the end of cells, keeping at least one line in every row of cells.
+  fn remove_empty_trailing_lines(&mut self) -> bool {
+    let mut changed = false;
+    for row_index in (1..self.rows.len()).rev() {
+      let row = &self.rows[row_index];
+      let is_blank = row.iter().all(|chr| chr.is_space() || chr.is_frame());
+      let is_last = self.rows.get(row_index + 1).is_none_or(|lower| !is_same_cells(row, lower));
+      if is_blank && is_last && is_same_cells(&self.rows[row_index - 1], row) {
+        self.rows.remove(row_index);
+        if self.cursor.row() >= row_index {
+          self.cursor.dec_row(1);
+        }
+        changed = true;
+      }
+    }
+    changed
+  }
+
+  /// Returns the column index where a position must be inserted,
+  /// to widen the first line of text that is closer to the frame than the padding.
+  fn padding_position(&self, padding: usize) -> Option<usize> {
+    self.rows.iter().flat_map(cell_lines).find_map(|(left, right, text)| {
+      let (first, last) = text?;
+      if first - left - 1 < padding {
+        Some(left + 1)
+      } else if right - last - 1 < padding {
+        Some(right)
+      } else {
+        None
+      }
+    })
+  }
+
+  /// Inserts a position at the specified column index in all rows.
+  ///
+  /// Horizontal lines are continued, spaces are inserted elsewhere. When the column index
+  /// points inside the text, the space is inserted at the end of the cell, so the text is not split.
+  fn insert_position(&mut self, col_index: usize) {
+    for (row_index, row) in self.rows.iter_mut().enumerate() {
+      if col_index >= row.len() {
+        continue;
+      }
+      let (before, after) = (&row[col_index - 1], &row[col_index]);
+      let (index, chr) = if before.is_frame() && after.is_frame() {
+        let line = before.arms().right;
+        let ch = if line == Line::Empty {
+          SPACE
+        } else {
+          Arms {
+            left: line,
+            right: line,
+            ..Default::default()
+          }
+          .char()
+        };
+        (col_index, Char::new(ch, before.attributes()))
+      } else {
+        let index = match cell_line(row, col_index) {
+          Some((_, right, Some((first, last)))) if first < col_index && col_index <= last => right,
+          _ => col_index,
+        };
+        (index, Char::new(SPACE, row[index - 1].attributes()))
+      };
+      row.insert(index, chr);
+      if row_index == self.cursor.row() && self.cursor.col() >= index {
+        self.cursor.inc_col(1);
+      }
+    }
+  }
+
+  /// Returns the column indexes, one for each row, of surplus positions that can be removed
+  /// to shrink the column at the specified index, or `None` when the column can not be shrunk.
+  ///
+  /// Horizontal lines and spaces exceeding the padding are removable. When the column index
+  /// points inside the information item name, the surplus space at the end or at the beginning
+  /// of the name is removed instead. Text in the decision table's body is never shifted.
+  fn removable_positions(&self, col_index: usize, padding: usize) -> Option<Vec<Option<usize>>> {
+    let body_index = self.body_index();
+    let mut positions = vec![];
+    for (row_index, row) in self.rows.iter().enumerate() {
+      if col_index >= row.len() {
+        positions.push(None);
+        continue;
+      }
+      let chr = &row[col_index];
+      if chr.is_frame() {
+        let arms = chr.arms();
+        if arms.up != Line::Empty || arms.down != Line::Empty {
+          return None;
+        }
+        positions.push(Some(col_index));
+        continue;
+      }
+      let (left, right, text) = cell_line(row, col_index)?;
+      let position = match text {
+        None => (right - left - 1 > (2 * padding).max(1)).then_some(col_index),
+        Some((first, last)) => {
+          let (left_margin, right_margin) = (first - left - 1, right - last - 1);
+          if col_index < first {
+            (left_margin > padding).then_some(col_index)
+          } else if col_index > last {
+            (right_margin > padding).then_some(col_index)
+          } else if row_index >= body_index {
+            None
+          } else if right_margin > padding {
+            Some(right - 1)
+          } else if left_margin > padding {
+            Some(left + 1)
+          } else {
+            None
+          }
+        }
+      };
+      positions.push(Some(position?));
+    }
+    Some(positions)
+  }
+
+  /// Removes positions with the specified column indexes, one for each row.
+  fn remove_positions(&mut self, positions: &[Option<usize>]) {
+    for (row_index, (row, position)) in self.rows.iter_mut().zip(positions).enumerate() {
+      if let Some(col_index) = *position {
+        row.remove(col_index);
+        if row_index == self.cursor.row() && self.cursor.col() > col_index {
+          self.cursor.dec_col(1);
+        }
+      }
+    }
+  }
+
+  /// Replaces all variants of box-drawing characters (heavy lines, rounded corners)
+  /// with their canonical light equivalents, see [canonical_frame].
+  pub fn normalize(&mut self) -> bool {
+    let mut changed = false;
+    for chr in self.rows.iter().flat_map(|row| row.iter()) {
+      let canonical = chr.canonical();
+      if canonical != chr.char() {
+        chr.set_char(canonical);
+        changed = true;
+      }
+    }
+    if changed {
+      self.invalidate_content_region();
+    }
+    changed
+  }
+
+  /// Inserts a new input column on the left side of the double line separating inputs from outputs.
+  ///
+  /// In crosstabs, the new column of values is inserted on the left side of the current column.
+  pub fn insert_input_column(&mut self) -> bool {
+    match self.orientation() {
+      Orientation::Vertical => return false,
+      Orientation::Crosstab => return self.insert_value_column(false),
+      Orientation::Horizontal => {}
+    }
+    let Some(&vert_index) = self.double_lines().first() else {
+      return false;
+    };
+    self.insert_column(vert_index, true)
+  }
+
+  /// Inserts a new output column on the right side of the double line separating inputs from outputs.
+  ///
+  /// In crosstabs, the new column of values is inserted on the right side of the current column.
+  pub fn insert_output_column(&mut self) -> bool {
+    match self.orientation() {
+      Orientation::Vertical => return false,
+      Orientation::Crosstab => return self.insert_value_column(true),
+      Orientation::Horizontal => {}
+    }
+    let Some(&vert_index) = self.double_lines().first() else {
+      return false;
+    };
+    self.insert_column(vert_index, false)
+  }
+
+  /// Inserts a new annotation column on the right side of the decision table.
+  ///
+  /// The first annotation column is separated from output columns with the double line.
+  pub fn insert_annotation_column(&mut self) -> bool {
+    if self.orientation() != Orientation::Horizontal {
+      return false;
+    }
+    let double_lines = self.double_lines();
+    let Some(edge_index) = self.rules().first().map(|(top_index, _)| self.rows[*top_index].len() - 1) else {
+      return false;
+    };
+    if double_lines.is_empty() || !self.insert_column(edge_index, false) {
+      return false;
+    }
+    // the first annotation column is separated from output columns with the double line
+    if double_lines.len() < 2 {
+      let body_index = self.body_index();
+      for (row_index, row) in self.rows.iter().enumerate().skip(body_index) {
+        let mut arms = row[edge_index].arms();
+        if arms.up != Line::Empty && row_index > body_index {
+          arms.up = Line::Double;
+        }
+        if arms.down != Line::Empty {
+          arms.down = Line::Double;
+        }
+        row[edge_index].set_char(arms.char());
+      }
+    }
+    true
+  }
+
+  /// Deletes the column pointed by the cursor, together with its right vertical line.
+  ///
+  /// The column is not deleted when it is the hit policy column, the only input column
+  /// or the only output column, or when it is not bounded by vertical lines in all rows
+  /// of the decision table's body (like a single column of a compound output).
+  /// Columns of vertical decision tables and crosstabs are not deleted.
+  pub fn delete_column(&mut self) -> bool {
+    if self.orientation() != Orientation::Horizontal {
+      return false;
+    }
+    let Some((left_index, right_index, sections)) = self.column_range() else {
+      return false;
+    };
+    // the only input or output column can not be deleted, but all annotations can
+    let is_annotation = sections.len() > 3 && left_index >= sections[2];
+    if !is_annotation && sections.windows(2).any(|pair| pair[0] == left_index && pair[1] == right_index) {
+      return false;
+    }
+    self.drain_column(left_index, right_index, sections[sections.len() - 1])
+  }
+
+  /// Deletes the column bounded by vertical lines with the specified indexes, together with its right vertical line.
+  ///
+  /// The index of the right edge of the decision table is required to narrow the information item name.
+  fn drain_column(&mut self, left_index: usize, right_index: usize, edge_index: usize) -> bool {
+    let row_index = self.cursor.row();
+    let join_row_info = self.join_row_info();
+    let body_index = self.body_index();
+    // the information item name must be narrowed when it is wider than the remaining body
+    let width = edge_index + 1 - (right_index - left_index);
+    let mut upper_rows = self.rows[..body_index].to_vec();
+    while upper_rows.iter().any(|row| row.len() > width) {
+      if !upper_rows.iter().all(|row| row.is_deletable_space(1)) {
+        return false;
+      }
+      upper_rows.iter_mut().for_each(|row| row.delete_space(1));
+    }
+    self.rows.splice(..body_index, upper_rows);
+    if let Some(column_index) = self.column_lines().iter().position(|index| *index == left_index) {
+      self.shift_alignments(column_index, -1);
+    }
+    for row in &mut self.rows[body_index..] {
+      let (left, right) = (row[left_index].arms(), row[right_index].arms());
+      let arms = if right_index == row.len() - 1 {
+        // the deleted column was the last one, so the right border remains
+        Arms { left: left.left, ..right }
+      } else {
+        Arms {
+          up: left.up.max(right.up),
+          right: right.right,
+          down: left.down.max(right.down),
+          left: left.left,
+        }
+      };
+      row[left_index].set_char(arms.char());
+      row.drain(left_index + 1..=right_index);
+    }
+    self.update_joining_row(join_row_info);
+    self.cursor.set(if left_index + 1 < width - 1 { left_index + 1 } else { left_index - 1 }, row_index);
+    self.invalidate_content_region();
+    true
+  }
+
+  /// Moves the column pointed by the cursor one position left, within its section.
+  pub fn move_column_left(&mut self) -> bool {
+    if self.orientation() != Orientation::Horizontal {
+      return false;
+    }
+    let Some((left_index, right_index, sections)) = self.column_range() else {
+      return false;
+    };
+    if sections.contains(&left_index) {
+      return false;
+    }
+    let Some(prev_index) = (0..left_index).rev().find(|col_index| self.is_column_line(*col_index)) else {
+      return false;
+    };
+    self.swap_columns(prev_index, left_index, right_index);
+    self.cursor.dec_col(left_index - prev_index);
+    true
+  }
+
+  /// Moves the column pointed by the cursor one position right, within its section.
+  pub fn move_column_right(&mut self) -> bool {
+    if self.orientation() != Orientation::Horizontal {
+      return false;
+    }
+    let Some((left_index, right_index, sections)) = self.column_range() else {
+      return false;
+    };
+    if sections.contains(&right_index) {
+      return false;
+    }
+    let Some(next_index) = (right_index + 1..=sections[sections.len() - 1]).find(|col_index| self.is_column_line(*col_index)) else {
+      return false;
+    };
+    self.swap_columns(left_index, right_index, next_index);
+    self.cursor.inc_col(next_index - right_index);
+    true
+  }
+
+  /// Swaps two adjacent columns bounded by vertical lines with the specified indexes.
+  ///
+  /// Columns are swapped in all rows of the decision table's body, the crossings
+  /// of the vertical lines are updated to match the horizontal lines of swapped columns.
+  fn swap_columns(&mut self, left_index: usize, middle_index: usize, right_index: usize) {
+    let join_row_info = self.join_row_info();
+    let body_index = self.body_index();
+    if let Some(column_index) = self.column_lines().iter().position(|index| *index == left_index) {
+      // kept alignments are moved together with swapped columns
+      let (left, right) = (self.alignments.remove(&column_index), self.alignments.remove(&(column_index + 1)));
+      if let Some(alignment) = left {
+        self.alignments.insert(column_index + 1, alignment);
+      }
+      if let Some(alignment) = right {
+        self.alignments.insert(column_index, alignment);
+      }
+    }
+    for row in &mut self.rows[body_index..] {
+      let left = Arms {
+        right: row[middle_index + 1].arms().left,
+        ..row[left_index].arms()
+      };
+      let middle = Arms {
+        right: row[left_index + 1].arms().left,
+        left: row[right_index - 1].arms().right,
+        ..row[middle_index].arms()
+      };
+      let right = Arms {
+        left: row[middle_index - 1].arms().right,
+        ..row[right_index].arms()
+      };
+      // left column, line, right column => right column, left column, line => right column, line, left column
+      row[left_index + 1..right_index].rotate_left(middle_index - left_index);
+      row[left_index + right_index - middle_index..right_index].rotate_right(1);
+      row[left_index].set_char(left.char());
+      row[left_index + right_index - middle_index].set_char(middle.char());
+      row[right_index].set_char(right.char());
+    }
+    self.update_joining_row(join_row_info);
+  }
+
+  /// Inserts the new column next to the vertical line with the specified index.
+  ///
+  /// The new column is inserted in all rows of the decision table's body, before or after
+  /// the vertical line. Horizontal lines are drawn in the new column only in rows, where
+  /// the columns on both sides of the vertical line have them. The information item name
+  /// is widened only when it covers the inserted column.
+  fn insert_column(&mut self, vert_index: usize, before: bool) -> bool {
+    let join_row_info = self.join_row_info();
+    let body_index = self.body_index();
+    if vert_index == 0 || self.rows.len() <= body_index || self.rows[body_index..].iter().any(|row| row.len() <= vert_index) {
+      return false;
+    }
+    if let Some(column_index) = self.column_lines().iter().position(|index| *index == vert_index) {
+      self.shift_alignments(column_index, 1);
+    }
+    let col_index = if before { vert_index } else { vert_index + 1 };
+    for row in &mut self.rows[..body_index] {
+      if row.len() > col_index {
+        for _ in 0..=COLUMN_WIDTH {
+          row.insert_fill(col_index);
+        }
+      }
+    }
+    for row in &mut self.rows[body_index..] {
+      let mut vert = row[vert_index].arms();
+      let left = row[vert_index - 1].arms().right;
+      let right = row.get(vert_index + 1).map_or(Line::Empty, |chr| chr.arms().left);
+      let horz = match (left, right) {
+        (_, Line::Empty) if col_index == row.len() => left,
+        (Line::Empty, _) | (_, Line::Empty) => Line::Empty,
+        _ if before => left,
+        _ => right,
+      };
+      let mut border = Arms {
+        up: vert.up.min(Line::Single),
+        down: vert.down.min(Line::Single),
+        ..Default::default()
+      };
+      let fill = Arms {
+        right: horz,
+        left: horz,
+        ..Default::default()
+      }
+      .char();
+      let mut column = vec![Char::from(fill); COLUMN_WIDTH];
+      if before {
+        (border.left, border.right, vert.left) = (left, horz, horz);
+        column.insert(0, Char::from(border.char()));
+      } else {
+        (border.left, border.right, vert.right) = (horz, right, horz);
+        column.push(Char::from(border.char()));
+      }
+      row[vert_index].set_char(vert.char());
+      row.splice(col_index..col_index, column);
+    }
+    self.update_joining_row(join_row_info);
+    // place the cursor in the new column, within the body
+    let row_index = self.cursor.row().max(body_index + 1);
+    self.cursor.set(vert_index + 2, row_index);
+    self.invalidate_content_region();
+    true
+  }
+
+  /// Returns the indexes of vertical lines bounding the column pointed by the cursor,
+  /// together with the indexes of vertical lines bounding the sections of the decision table
+  /// (hit policy, inputs, outputs, annotations), including the right border.
+  ///
+  /// The hit policy column and columns not bounded by vertical lines in all rows
+  /// of the decision table's body (like a single column of a compound output) are not reported.
+  fn column_range(&self) -> Option<(usize, usize, Vec<usize>)> {
+    let (col_index, row_index) = self.cursor.pos();
+    let (left_index, right_index) = self.rows.get(row_index)?.cell_range(col_index)?;
+    let (left_index, right_index) = (left_index - 1, right_index + 1);
+    let (top_index, _) = self.rules().first().copied()?;
+    let hit_policy_index = self.rows[top_index].iter().skip(1).position(|chr| chr.is_vert_line())? + 1;
+    if left_index < hit_policy_index || !self.is_column_line(left_index) || !self.is_column_line(right_index) {
+      return None;
+    }
+    let mut sections = vec![hit_policy_index];
+    sections.extend(self.double_lines());
+    sections.push(self.rows[top_index].len() - 1);
+    Some((left_index, right_index, sections))
+  }
+
+  /// Returns `true` when there is a vertical line with the specified index
+  /// in all rows of the decision table's body.
+  fn is_column_line(&self, col_index: usize) -> bool {
+    self.rows[self.body_index()..].iter().all(|row| row.get(col_index).is_some_and(|chr| chr.arms().is_vert()))
+  }
+
+  /// Returns the index of the first row of the decision table's body,
+  /// i.e. the joining row when the information item name is present.
+  fn body_index(&self) -> usize {
+    self.join_row_info().map_or(0, |(join_row_index, _)| join_row_index)
+  }
+
+  /// Returns the indexes of double vertical lines separating inputs, outputs and annotations.
+  fn double_lines(&self) -> Vec<usize> {
+    self
+      .rules()
+      .first()
+      .map(|(top_index, _)| {
+        self.rows[*top_index]
+          .iter()
+          .enumerate()
+          .filter(|(_, chr)| chr.is_double_vert_line())
+          .map(|(col_index, _)| col_index)
+          .collect()
+      })
+      .unwrap_or_default()
+  }
+
+  /// Removes the row of whitespaces placed before the horizontal line below the cursor,
+  /// when such a row exists in all columns.
   fn remove_horizontal_whitespaces(&mut self) {
     // Retrieve the current cursor position.
     let (col_index, row_index) = self.cursor.pos();
@@ -682,7 +2345,7 @@ impl Plane {
               .rows
               .get(ix_row - 1)
               .and_then(|row| row.get(ix_col))
-              .map_or(true, |chr| chr.is_space() || chr.is_vert_line())
+              .is_none_or(|chr| chr.is_space() || chr.is_vert_line())
         });
         // If only spaces are before th horizontal line, then shrink the decision table's height.
         if all_whitespaces {
@@ -711,7 +2374,7 @@ impl Plane {
               let ix_row_below = nearest_row_index + ix_row;
               if let Some(row_below) = self.rows.get(ix_row_below) {
                 if let Some((chr, chr_below)) = row.get(ix_col).zip(row_below.get(ix_col)) {
-                  chr.set_char(chr_below.char());
+                  chr.set_content(chr_below);
                 }
               }
             }
@@ -795,7 +2458,7 @@ impl Plane {
           }
           if let Some(upper_chr) = upper_row.get(i) {
             if upper_chr.is_single_vert_line() {
-              match chr.char() {
+              match chr.canonical() {
                 LIGHT_HORIZONTAL => chr.set_char(LIGHT_UP_AND_HORIZONTAL),
                 LIGHT_DOWN_AND_HORIZONTAL => chr.set_char(LIGHT_VERTICAL_AND_HORIZONTAL),
                 LIGHT_DOWN_AND_LEFT => chr.set_char(LIGHT_VERTICAL_AND_LEFT),
@@ -805,7 +2468,7 @@ impl Plane {
               continue; // <--- DO NOT OVERSEE THIS WHEN ANALYSING THE CODE
             }
           }
-          match chr.char() {
+          match chr.canonical() {
             LIGHT_UP_AND_HORIZONTAL => chr.set_char(LIGHT_HORIZONTAL),
             LIGHT_VERTICAL_AND_HORIZONTAL => chr.set_char(LIGHT_DOWN_AND_HORIZONTAL),
             LIGHT_VERTICAL_AND_LEFT => chr.set_char(LIGHT_DOWN_AND_LEFT),
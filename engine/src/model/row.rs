@@ -1,6 +1,6 @@
 //! # Single row of text
 
-use crate::{Char, SPACE};
+use crate::{to_chars, Char, SPACE};
 use std::ops::{Deref, DerefMut};
 
 /// A single row of text.
@@ -25,8 +25,10 @@ impl DerefMut for Row {
 
 impl<T: ToString> From<T> for Row {
   /// Implements the [From] trait for [Row] for all types that implement [ToString].
+  ///
+  /// The row contains a single character per terminal column, see [to_chars].
   fn from(value: T) -> Self {
-    Self(value.to_string().chars().map(Into::into).collect::<Vec<Char>>())
+    Self(to_chars(&value.to_string()))
   }
 }
 
@@ -51,7 +53,7 @@ impl Row {
   /// assert!(!row.is_join());
   /// ```
   pub fn is_join(&self) -> bool {
-    self.0.first().map_or(false, |chr| chr.is_join())
+    self.0.first().is_some_and(|chr| chr.is_join())
   }
 
   pub fn set_join(&self) {
@@ -70,7 +72,7 @@ impl Row {
   /// assert!(!row.is_full_join());
   /// ```
   pub fn is_full_join(&self) -> bool {
-    self.0.first().map_or(false, |chr| chr.is_full_join())
+    self.0.first().is_some_and(|chr| chr.is_full_join())
   }
 
   /// Searches for a `left vertical line` starting from the specified
@@ -152,9 +154,9 @@ impl Row {
       }
       if chr.is_vert_line_left() {
         // Check if there is a space directly before the vertical line.
-        let is_space_before = self.0.get(col_index.saturating_sub(1)).map_or(false, |chr| chr.is_space());
+        let is_space_before = self.0.get(col_index.saturating_sub(1)).is_some_and(|chr| chr.is_space());
         // To preserve a minimum single space in a cell, check if there is no frame before the last space.
-        let no_frame_before_space = self.0.get(col_index.saturating_sub(2)).map_or(false, |chr| !chr.is_frame());
+        let no_frame_before_space = self.0.get(col_index.saturating_sub(2)).is_some_and(|chr| !chr.is_frame());
         return is_space_before && no_frame_before_space;
       }
       col_index += 1;
@@ -221,6 +223,6 @@ impl Row {
   /// assert_eq!("║border options   ║", row.text());
   /// ```
   pub fn text(&self) -> String {
-    self.0.iter().map(|chr| chr.char()).collect::<String>()
+    self.0.iter().map(|chr| chr.to_string()).collect::<String>()
   }
 }
@@ -78,6 +78,42 @@ impl Region {
     (self.left, self.top, self.width, self.height)
   }
 
+  /// Returns `true` when the specified point lies inside the region.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::Region;
+  ///
+  /// let region = Region::new(10, 11, 3, 2);
+  /// assert!(region.contains(10, 11));
+  /// assert!(region.contains(12, 12));
+  /// assert!(!region.contains(13, 12));
+  /// assert!(!region.contains(12, 13));
+  /// assert!(!Region::default().contains(0, 0));
+  /// ```
+  pub fn contains(&self, column: usize, row: usize) -> bool {
+    self.width > 0 && self.height > 0 && column >= self.left() && column <= self.right() && row >= self.top() && row <= self.bottom()
+  }
+
+  /// Returns the smallest region containing both regions.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::Region;
+  ///
+  /// let region = Region::new(10, 11, 3, 2).union(&Region::new(5, 12, 2, 4));
+  /// assert_eq!("(5, 11, 8, 5)", region.to_string());
+  /// ```
+  pub fn union(&self, other: &Region) -> Region {
+    let left = self.left().min(other.left());
+    let top = self.top().min(other.top());
+    let right = self.right().max(other.right());
+    let bottom = self.bottom().max(other.bottom());
+    Region::new(left, top, right - left + 1, bottom - top + 1)
+  }
+
   pub fn resize(&mut self, width: usize, height: usize) {
     self.width = width;
     self.height = height;
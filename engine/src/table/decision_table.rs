@@ -0,0 +1,323 @@
+//! # Typed model of the decision table
+
+use crate::{HitPolicy, Region};
+
+/// Text of a single cell of the decision table.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Cell {
+  /// Text of the cell, lines are separated with `\n`.
+  text: String,
+  /// Region occupied by the cell in the source text, if the cell was parsed.
+  region: Option<Region>,
+}
+
+impl Cell {
+  /// Creates a new cell with the specified text.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::Cell;
+  ///
+  /// let cell = Cell::new("\"Business\",\n\"Private\"");
+  /// assert_eq!(vec!["\"Business\",", "\"Private\""], cell.lines());
+  /// assert_eq!(None, cell.region());
+  /// ```
+  pub fn new<T: ToString>(text: T) -> Self {
+    Self {
+      text: text.to_string(),
+      region: None,
+    }
+  }
+
+  /// Sets the region occupied by the cell in the source text.
+  pub fn with_region(mut self, region: Region) -> Self {
+    self.region = Some(region);
+    self
+  }
+
+  /// Returns the text of the cell.
+  pub fn text(&self) -> &str {
+    &self.text
+  }
+
+  /// Returns the lines of the text of the cell.
+  pub fn lines(&self) -> Vec<&str> {
+    self.text.lines().collect()
+  }
+
+  /// Returns the region occupied by the cell in the source text.
+  pub fn region(&self) -> Option<Region> {
+    self.region
+  }
+
+  /// Returns `true` when the cell contains no text.
+  pub fn is_empty(&self) -> bool {
+    self.text.is_empty()
+  }
+}
+
+/// Input clause of the decision table.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct InputClause {
+  /// Input expression.
+  input_expression: Cell,
+  /// Allowed input values.
+  allowed_values: Option<Cell>,
+}
+
+impl InputClause {
+  /// Creates a new input clause with the specified input expression.
+  pub fn new(input_expression: Cell) -> Self {
+    Self {
+      input_expression,
+      allowed_values: None,
+    }
+  }
+
+  /// Sets the allowed input values.
+  pub fn with_allowed_values(mut self, allowed_values: Cell) -> Self {
+    self.allowed_values = Some(allowed_values);
+    self
+  }
+
+  /// Returns the input expression.
+  pub fn input_expression(&self) -> &Cell {
+    &self.input_expression
+  }
+
+  /// Returns the allowed input values.
+  pub fn allowed_values(&self) -> Option<&Cell> {
+    self.allowed_values.as_ref()
+  }
+}
+
+/// Output clause of the decision table.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct OutputClause {
+  /// Name of the output, for compound outputs this is the name of the output component.
+  name: Cell,
+  /// Allowed output values.
+  allowed_values: Option<Cell>,
+}
+
+impl OutputClause {
+  /// Creates a new output clause with the specified name.
+  pub fn new(name: Cell) -> Self {
+    Self { name, allowed_values: None }
+  }
+
+  /// Sets the allowed output values.
+  pub fn with_allowed_values(mut self, allowed_values: Cell) -> Self {
+    self.allowed_values = Some(allowed_values);
+    self
+  }
+
+  /// Returns the name of the output.
+  pub fn name(&self) -> &Cell {
+    &self.name
+  }
+
+  /// Returns the allowed output values.
+  pub fn allowed_values(&self) -> Option<&Cell> {
+    self.allowed_values.as_ref()
+  }
+}
+
+/// Annotation clause of the decision table.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AnnotationClause {
+  /// Name of the annotation.
+  name: Cell,
+}
+
+impl AnnotationClause {
+  /// Creates a new annotation clause with the specified name.
+  pub fn new(name: Cell) -> Self {
+    Self { name }
+  }
+
+  /// Returns the name of the annotation.
+  pub fn name(&self) -> &Cell {
+    &self.name
+  }
+}
+
+/// Single rule of the decision table.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Rule {
+  /// Input entries, one for each input clause.
+  input_entries: Vec<Cell>,
+  /// Output entries, one for each output clause.
+  output_entries: Vec<Cell>,
+  /// Annotation entries, one for each annotation clause.
+  annotation_entries: Vec<Cell>,
+}
+
+impl Rule {
+  /// Creates a new rule with the specified input and output entries.
+  pub fn new(input_entries: Vec<Cell>, output_entries: Vec<Cell>) -> Self {
+    Self {
+      input_entries,
+      output_entries,
+      annotation_entries: vec![],
+    }
+  }
+
+  /// Sets the annotation entries.
+  pub fn with_annotation_entries(mut self, annotation_entries: Vec<Cell>) -> Self {
+    self.annotation_entries = annotation_entries;
+    self
+  }
+
+  /// Returns the input entries.
+  pub fn input_entries(&self) -> &[Cell] {
+    &self.input_entries
+  }
+
+  /// Returns the output entries.
+  pub fn output_entries(&self) -> &[Cell] {
+    &self.output_entries
+  }
+
+  /// Returns the annotation entries.
+  pub fn annotation_entries(&self) -> &[Cell] {
+    &self.annotation_entries
+  }
+}
+
+/// Orientation of the decision table.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum Orientation {
+  /// Rules are rows, input, output and annotation clauses are columns.
+  #[default]
+  Horizontal,
+  /// Rules are columns, input, output and annotation clauses are rows.
+  Vertical,
+  /// Values of the first input are rows, values of the second input are columns
+  /// and output entries fill the grid (crosstab). Only decision tables with unique hit policy,
+  /// two inputs, a single output, no allowed values, no annotations and at most one rule
+  /// for each pair of input values can be rendered as crosstabs, other ones are rendered horizontally.
+  Crosstab,
+}
+
+impl Orientation {
+  /// Returns the opposite orientation, crosstabs are transposed to horizontal decision tables.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::Orientation;
+  ///
+  /// assert_eq!(Orientation::Vertical, Orientation::Horizontal.transposed());
+  /// assert_eq!(Orientation::Horizontal, Orientation::Vertical.transposed());
+  /// assert_eq!(Orientation::Horizontal, Orientation::Crosstab.transposed());
+  /// ```
+  pub fn transposed(self) -> Self {
+    match self {
+      Orientation::Horizontal => Orientation::Vertical,
+      Orientation::Vertical | Orientation::Crosstab => Orientation::Horizontal,
+    }
+  }
+}
+
+/// Typed model of the decision table.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DecisionTable {
+  /// Name of the information item the decision table belongs to.
+  information_item_name: Option<Cell>,
+  /// Orientation of the decision table.
+  orientation: Orientation,
+  /// Hit policy.
+  hit_policy: HitPolicy,
+  /// Name of the compound output, spanning all output clauses.
+  output_label: Option<Cell>,
+  /// Input clauses.
+  input_clauses: Vec<InputClause>,
+  /// Output clauses.
+  output_clauses: Vec<OutputClause>,
+  /// Annotation clauses.
+  annotation_clauses: Vec<AnnotationClause>,
+  /// Rules.
+  rules: Vec<Rule>,
+}
+
+impl DecisionTable {
+  /// Creates a new decision table.
+  pub fn new(hit_policy: HitPolicy, input_clauses: Vec<InputClause>, output_clauses: Vec<OutputClause>, rules: Vec<Rule>) -> Self {
+    Self {
+      information_item_name: None,
+      orientation: Orientation::default(),
+      hit_policy,
+      output_label: None,
+      input_clauses,
+      output_clauses,
+      annotation_clauses: vec![],
+      rules,
+    }
+  }
+
+  /// Sets the name of the information item.
+  pub fn with_information_item_name(mut self, information_item_name: Cell) -> Self {
+    self.information_item_name = Some(information_item_name);
+    self
+  }
+
+  /// Sets the orientation of the decision table.
+  pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+    self.orientation = orientation;
+    self
+  }
+
+  /// Sets the name of the compound output.
+  pub fn with_output_label(mut self, output_label: Cell) -> Self {
+    self.output_label = Some(output_label);
+    self
+  }
+
+  /// Sets the annotation clauses.
+  pub fn with_annotation_clauses(mut self, annotation_clauses: Vec<AnnotationClause>) -> Self {
+    self.annotation_clauses = annotation_clauses;
+    self
+  }
+
+  /// Returns the name of the information item.
+  pub fn information_item_name(&self) -> Option<&Cell> {
+    self.information_item_name.as_ref()
+  }
+
+  /// Returns the orientation of the decision table.
+  pub fn orientation(&self) -> Orientation {
+    self.orientation
+  }
+
+  /// Returns the hit policy.
+  pub fn hit_policy(&self) -> HitPolicy {
+    self.hit_policy
+  }
+
+  /// Returns the name of the compound output.
+  pub fn output_label(&self) -> Option<&Cell> {
+    self.output_label.as_ref()
+  }
+
+  /// Returns the input clauses.
+  pub fn input_clauses(&self) -> &[InputClause] {
+    &self.input_clauses
+  }
+
+  /// Returns the output clauses.
+  pub fn output_clauses(&self) -> &[OutputClause] {
+    &self.output_clauses
+  }
+
+  /// Returns the annotation clauses.
+  pub fn annotation_clauses(&self) -> &[AnnotationClause] {
+    &self.annotation_clauses
+  }
+
+  /// Returns the rules.
+  pub fn rules(&self) -> &[Rule] {
+    &self.rules
+  }
+}
@@ -0,0 +1,62 @@
+//! # Errors
+
+use crate::Region;
+use std::fmt;
+use std::fmt::Display;
+
+/// Errors reported while processing the decision table.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum TableError {
+  /// The line separating the header from the rules (`╞═══╡`) was not found.
+  MissingHeaderSeparator,
+  /// The double line separating input columns from output columns (`║`) was not found.
+  MissingInputOutputSeparator,
+  /// The text in the hit policy cell is not a valid hit policy.
+  InvalidHitPolicy(String),
+  /// The cell does not fit the structure of the decision table.
+  UnexpectedCell(Region),
+  /// The cell is missing at the specified column and row.
+  MissingCell(usize, usize),
+  /// The name of the format is not known.
+  UnknownFormat(String),
+  /// Importing decision tables from the format with the specified name is not supported.
+  UnsupportedImport(String),
+  /// The DMN XML document could not be parsed.
+  InvalidDmn(String),
+  /// The JSON document could not be parsed.
+  InvalidJson(String),
+  /// The Markdown table could not be parsed.
+  InvalidMarkdown(String),
+  /// The CSV content could not be parsed.
+  InvalidCsv(String),
+  /// No decision table was found in the DMN XML document.
+  DecisionTableNotFound,
+  /// The decision with the specified identifier or name was not found.
+  DecisionNotFound(String),
+  /// There are several decisions with decision tables, one of them must be selected.
+  AmbiguousDecision(Vec<String>),
+}
+
+impl Display for TableError {
+  /// Implements [Display] trait for [TableError].
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      TableError::MissingHeaderSeparator => write!(f, "missing line separating the header from the rules"),
+      TableError::MissingInputOutputSeparator => write!(f, "missing double line separating input and output columns"),
+      TableError::InvalidHitPolicy(text) => write!(f, "invalid hit policy: '{text}'"),
+      TableError::UnexpectedCell(region) => write!(f, "unexpected cell at {region}"),
+      TableError::MissingCell(column, row) => write!(f, "missing cell at column {column}, row {row}"),
+      TableError::UnknownFormat(name) => write!(f, "unknown format: '{name}'"),
+      TableError::UnsupportedImport(name) => write!(f, "import from format '{name}' is not supported"),
+      TableError::InvalidDmn(reason) => write!(f, "invalid DMN: {reason}"),
+      TableError::InvalidJson(reason) => write!(f, "invalid JSON: {reason}"),
+      TableError::InvalidMarkdown(reason) => write!(f, "invalid Markdown: {reason}"),
+      TableError::InvalidCsv(reason) => write!(f, "invalid CSV: {reason}"),
+      TableError::DecisionTableNotFound => write!(f, "no decision table found"),
+      TableError::DecisionNotFound(decision) => write!(f, "decision not found: '{decision}'"),
+      TableError::AmbiguousDecision(decisions) => write!(f, "several decisions found, select one of: {}", decisions.join(", ")),
+    }
+  }
+}
+
+impl std::error::Error for TableError {}
@@ -0,0 +1,91 @@
+//! # Hit policy
+
+use crate::TableError;
+use std::fmt;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Aggregation function applied to the results of the `COLLECT` hit policy.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum BuiltinAggregator {
+  /// Number of the results (`C#`).
+  Count,
+  /// Sum of the results (`C+`).
+  Sum,
+  /// Minimum of the results (`C<`).
+  Min,
+  /// Maximum of the results (`C>`).
+  Max,
+}
+
+/// Hit policy of the decision table.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum HitPolicy {
+  /// Only a single rule can be matched (`U`).
+  Unique,
+  /// Multiple rules can match, but they all have the same output (`A`).
+  Any,
+  /// Multiple rules can match, the one with the highest output priority is selected (`P`).
+  Priority,
+  /// Multiple rules can match, the first one in rule order is selected (`F`).
+  First,
+  /// Multiple rules can match, the results are optionally aggregated (`C`, `C+`, `C#`, `C<`, `C>`).
+  Collect(Option<BuiltinAggregator>),
+  /// Results of all matching rules in decreasing output priority order (`O`).
+  OutputOrder,
+  /// Results of all matching rules in rule order (`R`).
+  RuleOrder,
+}
+
+impl FromStr for HitPolicy {
+  type Err = TableError;
+
+  /// Parses the hit policy from its abbreviation or from its full name.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::{BuiltinAggregator, HitPolicy};
+  ///
+  /// assert_eq!(Ok(HitPolicy::Unique), "U".parse());
+  /// assert_eq!(Ok(HitPolicy::Collect(Some(BuiltinAggregator::Sum))), "C+".parse());
+  /// assert_eq!(Ok(HitPolicy::RuleOrder), "RULE ORDER".parse());
+  /// assert!("X".parse::<HitPolicy>().is_err());
+  /// ```
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.trim() {
+      "U" | "UNIQUE" => Ok(HitPolicy::Unique),
+      "A" | "ANY" => Ok(HitPolicy::Any),
+      "P" | "PRIORITY" => Ok(HitPolicy::Priority),
+      "F" | "FIRST" => Ok(HitPolicy::First),
+      "C" | "COLLECT" => Ok(HitPolicy::Collect(None)),
+      "C#" | "COLLECT COUNT" => Ok(HitPolicy::Collect(Some(BuiltinAggregator::Count))),
+      "C+" | "COLLECT SUM" => Ok(HitPolicy::Collect(Some(BuiltinAggregator::Sum))),
+      "C<" | "COLLECT MIN" => Ok(HitPolicy::Collect(Some(BuiltinAggregator::Min))),
+      "C>" | "COLLECT MAX" => Ok(HitPolicy::Collect(Some(BuiltinAggregator::Max))),
+      "O" | "OUTPUT ORDER" => Ok(HitPolicy::OutputOrder),
+      "R" | "RULE ORDER" => Ok(HitPolicy::RuleOrder),
+      other => Err(TableError::InvalidHitPolicy(other.to_string())),
+    }
+  }
+}
+
+impl Display for HitPolicy {
+  /// Implements [Display] trait for [HitPolicy], the abbreviation of the hit policy is displayed.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let text = match self {
+      HitPolicy::Unique => "U",
+      HitPolicy::Any => "A",
+      HitPolicy::Priority => "P",
+      HitPolicy::First => "F",
+      HitPolicy::Collect(None) => "C",
+      HitPolicy::Collect(Some(BuiltinAggregator::Count)) => "C#",
+      HitPolicy::Collect(Some(BuiltinAggregator::Sum)) => "C+",
+      HitPolicy::Collect(Some(BuiltinAggregator::Min)) => "C<",
+      HitPolicy::Collect(Some(BuiltinAggregator::Max)) => "C>",
+      HitPolicy::OutputOrder => "O",
+      HitPolicy::RuleOrder => "R",
+    };
+    write!(f, "{text}")
+  }
+}
@@ -0,0 +1,13 @@
+//! # Decision table
+
+mod decision_table;
+mod errors;
+mod hit_policy;
+mod parser;
+mod renderer;
+
+pub use decision_table::*;
+pub use errors::*;
+pub use hit_policy::*;
+pub use parser::*;
+pub use renderer::*;
@@ -0,0 +1,445 @@
+//! # Parser of the decision table
+
+use crate::{
+  AnnotationClause, Arms, Cell, Char, DecisionTable, HitPolicy, InputClause, Line, Orientation, OutputClause, Plane, Region, Row, Rule, TableError, LIGHT_DOWN_AND_LEFT,
+  LIGHT_VERTICAL, LIGHT_VERTICAL_AND_RIGHT, SPACE, VERTICAL_SINGLE_AND_RIGHT_DOUBLE,
+};
+use std::str::FromStr;
+
+impl FromStr for DecisionTable {
+  type Err = TableError;
+
+  /// Parses the decision table from text.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use dtee::{DecisionTable, HitPolicy};
+  ///
+  /// let text = "┌───┬───╥───┐\n│ F │ a ║ b │\n╞═══╪═══╬═══╡\n│ 1 │ 2 ║ 3 │\n└───┴───╨───┘";
+  /// let decision_table = text.parse::<DecisionTable>().unwrap();
+  /// assert_eq!(HitPolicy::First, decision_table.hit_policy());
+  /// assert_eq!("a", decision_table.input_clauses()[0].input_expression().text());
+  /// assert_eq!("3", decision_table.rules()[0].output_entries()[0].text());
+  /// ```
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    parse_decision_table(Plane::new(s).content())
+  }
+}
+
+/// Parses the decision table from rows of the box-drawing grid.
+///
+/// The body of the decision table starts with the row below the information item name
+/// (or with the first row, when there is no information item name) and consists of the header
+/// and rules, separated with the line `╞═══╡`. Input columns are separated from output columns
+/// with the double line, the second double line separates output columns from annotation columns.
+///
+/// Vertical decision tables (see [table_orientation]) are parsed from the transposed frame
+/// of their body, where rules become rows and clauses become columns. Crosstabs are parsed
+/// into decision tables with two inputs and a single output, having a rule for each cell of the grid.
+pub fn parse_decision_table(rows: &[Row]) -> Result<DecisionTable, TableError> {
+  let grid = Grid::new(rows);
+  let orientation = table_orientation(rows);
+  if orientation == Orientation::Horizontal {
+    return parse_grid(&grid);
+  }
+  let body_index = body_index(rows);
+  let mut name_cells = grid.cells().into_iter().filter(|region| region.bottom() < body_index);
+  let information_item_name = name_cells.next().map(|region| grid.cell(&region));
+  if let Some(region) = name_cells.next() {
+    return Err(TableError::UnexpectedCell(region));
+  }
+  let decision_table = if orientation == Orientation::Crosstab {
+    parse_crosstab(&grid, body_index)?
+  } else {
+    let transposed = grid.transposed(body_index);
+    parse_grid(&transposed).map_err(|reason| transposed.source_error(reason))?
+  };
+  Ok(
+    match information_item_name {
+      Some(information_item_name) => decision_table.with_information_item_name(information_item_name),
+      None => decision_table,
+    }
+    .with_orientation(orientation),
+  )
+}
+
+/// Returns the orientation of the decision table in rows of the box-drawing grid.
+///
+/// In horizontal decision tables, the hit policy cell spans all rows of the header,
+/// so there are no horizontal lines starting at the left edge between the top of the body
+/// and the first double line (`╞═══╡`). In vertical decision tables, such lines separate
+/// the hit policy and the rows of input clauses. In crosstabs, the top-left cell with the name
+/// of the output spans the whole header, up to the first double vertical line.
+///
+/// # Examples
+///
+/// ```
+/// use dtee::{table_orientation, Orientation, Plane};
+///
+/// let text = "┌───┬───╥───┐\n│ F │ a ║ b │\n╞═══╪═══╬═══╡\n│ 1 │ 2 ║ 3 │\n└───┴───╨───┘";
+/// assert_eq!(Orientation::Horizontal, table_orientation(Plane::new(text).content()));
+/// let text = "┌───╥───┐\n│ F ║ 1 │\n├───╫───┤\n│ a ║ 2 │\n╞═══╬═══╡\n│ b ║ 3 │\n└───╨───┘";
+/// assert_eq!(Orientation::Vertical, table_orientation(Plane::new(text).content()));
+/// let text = "┌───╥───┐\n│ O ║ a │\n│   ╟───┤\n│   ║ 1 │\n╞═╤═╬═══╡\n│b│2║ 3 │\n└─┴─╨───┘";
+/// assert_eq!(Orientation::Crosstab, table_orientation(Plane::new(text).content()));
+/// ```
+pub fn table_orientation(rows: &[Row]) -> Orientation {
+  let starts_with = |row: &Row, ch: char| row.first().is_some_and(|chr| chr.canonical() == ch);
+  let Some(separator_index) = rows.iter().position(|row| starts_with(row, VERTICAL_SINGLE_AND_RIGHT_DOUBLE)) else {
+    return Orientation::Horizontal;
+  };
+  let body_index = body_index(rows);
+  if separator_index <= body_index {
+    // the decision table starts with the separator, there are no rows of the header
+    return Orientation::Horizontal;
+  }
+  if rows[body_index + 1..separator_index].iter().any(|row| starts_with(row, LIGHT_VERTICAL_AND_RIGHT)) {
+    return Orientation::Vertical;
+  }
+  let top_row = &rows[body_index];
+  let Some(io_index) = top_row.iter().position(|chr| chr.arms().down == Line::Double) else {
+    return Orientation::Horizontal;
+  };
+  if top_row[1..io_index].iter().any(|chr| chr.arms().down != Line::Empty) {
+    Orientation::Horizontal
+  } else {
+    Orientation::Crosstab
+  }
+}
+
+/// Returns the index of the top edge of the decision table's body.
+///
+/// When the information item name is present, the top edge of the table is not joined
+/// with any vertical line, and the body starts with the row where the right side
+/// of the name box ends, starting with `├`.
+fn body_index(rows: &[Row]) -> usize {
+  let Some(top_row) = rows.first() else {
+    return 0;
+  };
+  if top_row.iter().skip(1).any(|chr| chr.arms().down != Line::Empty && chr.arms().right != Line::Empty) {
+    return 0;
+  }
+  let Some(right_index) = top_row.iter().position(|chr| chr.canonical() == LIGHT_DOWN_AND_LEFT) else {
+    return 0;
+  };
+  (1..rows.len())
+    .find(|row_index| rows[*row_index].get(right_index).is_none_or(|chr| chr.canonical() != LIGHT_VERTICAL))
+    .filter(|row_index| rows[*row_index].first().is_some_and(|chr| chr.canonical() == LIGHT_VERTICAL_AND_RIGHT))
+    .unwrap_or(0)
+}
+
+/// Parses the crosstab from the grid, the body of the crosstab starts with the specified row.
+///
+/// The top-left cell contains the name of the output, the cell on the right side of it contains
+/// the expression of the input placed in columns, followed by the row of its values.
+/// Below the header, the first column contains the expression of the input placed in rows,
+/// followed by the column of its values and the grid of output entries.
+fn parse_crosstab(grid: &Grid, body_index: usize) -> Result<DecisionTable, TableError> {
+  let header_index = (body_index..grid.height())
+    .find(|row_index| grid.get(0, *row_index) == Some(VERTICAL_SINGLE_AND_RIGHT_DOUBLE))
+    .ok_or(TableError::MissingHeaderSeparator)?;
+  let io_index = (0..grid.width(header_index))
+    .find(|col_index| grid.arms(*col_index, header_index).up == Line::Double)
+    .ok_or(TableError::MissingInputOutputSeparator)?;
+  let mut cells = grid.cells().into_iter().filter(|region| region.top() > body_index).collect::<Vec<Region>>();
+  let mut take = |f: &dyn Fn(&Region) -> bool| cells.iter().position(f).map(|index| cells.remove(index));
+
+  // header
+  let output_name = take(&|region| region.left() == 1 && region.top() == body_index + 1).ok_or(TableError::MissingCell(1, body_index + 1))?;
+  let column_expression = take(&|region| region.left() == io_index + 1 && region.top() == body_index + 1).ok_or(TableError::MissingCell(io_index + 1, body_index + 1))?;
+  let mut columns = vec![];
+  while let Some(region) = take(&|region| region.left() > io_index && region.bottom() + 1 == header_index) {
+    columns.push(region);
+  }
+  columns.sort_by_key(|region| region.left());
+
+  // rows
+  let row_expression = take(&|region| region.left() == 1 && region.top() == header_index + 1).ok_or(TableError::MissingCell(1, header_index + 1))?;
+  let values_index = row_expression.right() + 2;
+  let mut rows = vec![];
+  while let Some(region) = take(&|region| region.left() == values_index && region.top() > header_index) {
+    rows.push(region);
+  }
+  rows.sort_by_key(|region| region.top());
+  let mut rules = vec![];
+  for row in &rows {
+    for column in &columns {
+      let entry = take(&|region| region.left() == column.left() && region.top() == row.top()).ok_or(TableError::MissingCell(column.left(), row.top()))?;
+      rules.push(Rule::new(vec![grid.cell(row), grid.cell(column)], vec![grid.cell(&entry)]));
+    }
+  }
+  if let Some(region) = cells.first() {
+    return Err(TableError::UnexpectedCell(*region));
+  }
+  if columns.is_empty() || rows.is_empty() {
+    return Err(TableError::MissingCell(values_index, header_index + 1));
+  }
+  let input_clauses = vec![InputClause::new(grid.cell(&row_expression)), InputClause::new(grid.cell(&column_expression))];
+  let output_clauses = vec![OutputClause::new(grid.cell(&output_name))];
+  Ok(DecisionTable::new(HitPolicy::Unique, input_clauses, output_clauses, rules))
+}
+
+/// Parses the decision table from the grid, where rules are rows.
+fn parse_grid(grid: &Grid) -> Result<DecisionTable, TableError> {
+  let height = grid.height();
+  let header_index = (0..height)
+    .find(|row_index| grid.get(0, *row_index) == Some(VERTICAL_SINGLE_AND_RIGHT_DOUBLE))
+    .ok_or(TableError::MissingHeaderSeparator)?;
+  // all rows of the header start with a vertical line, because the hit policy cell spans the whole header
+  let body_index = (0..header_index)
+    .rev()
+    .find(|row_index| !grid.get(0, *row_index).is_some_and(|ch| Char::from(ch).is_vert_line()))
+    .unwrap_or(0);
+  // vertical lines crossing the line separating the header from the rules
+  let lines = (0..grid.width(header_index))
+    .filter(|col_index| grid.arms(*col_index, header_index).is_vert())
+    .collect::<Vec<usize>>();
+  let double_lines = lines
+    .iter()
+    .copied()
+    .filter(|col_index| grid.arms(*col_index, header_index).up == Line::Double)
+    .collect::<Vec<usize>>();
+  let io_index = *double_lines.first().ok_or(TableError::MissingInputOutputSeparator)?;
+  let annotation_index = double_lines.get(1).copied().unwrap_or(usize::MAX);
+  let hit_policy_index = *lines.get(1).ok_or(TableError::MissingInputOutputSeparator)?;
+  let columns = lines.windows(2).skip(1).map(|pair| (pair[0] + 1, pair[1] - 1)).collect::<Vec<(usize, usize)>>();
+  let cells = grid.cells();
+
+  // hit policy
+  let hit_policy_cell = cells
+    .iter()
+    .find(|region| region.left() == 1 && region.top() == body_index + 1)
+    .ok_or(TableError::MissingCell(1, body_index + 1))?;
+  let hit_policy = HitPolicy::from_str(&grid.text(hit_policy_cell))?;
+
+  // information item name
+  let mut name_cells = cells.iter().filter(|region| region.bottom() < body_index);
+  let information_item_name = name_cells.next().map(|region| grid.cell(region));
+  if let Some(region) = name_cells.next() {
+    return Err(TableError::UnexpectedCell(*region));
+  }
+
+  // header
+  let header_cells = cells
+    .iter()
+    .filter(|region| region.top() > body_index && region.bottom() < header_index && region.left() > hit_policy_index)
+    .collect::<Vec<&Region>>();
+  let mut output_label = None;
+  for region in &header_cells {
+    if !columns.iter().any(|(left, right)| region.left() == *left && region.right() == *right) {
+      // only the name of the compound output may span several columns
+      if output_label.is_none() && region.left() > io_index && region.right() < annotation_index {
+        output_label = Some(grid.cell(region));
+      } else {
+        return Err(TableError::UnexpectedCell(**region));
+      }
+    }
+  }
+  let mut input_clauses = vec![];
+  let mut output_clauses = vec![];
+  let mut annotation_clauses = vec![];
+  for (left, right) in &columns {
+    let mut column_cells = header_cells.iter().filter(|region| region.left() == *left && region.right() == *right);
+    let name = column_cells.next().map(|region| grid.cell(region)).ok_or(TableError::MissingCell(*left, body_index + 1))?;
+    let allowed_values = column_cells.next().map(|region| grid.cell(region));
+    if let Some(region) = column_cells.next() {
+      return Err(TableError::UnexpectedCell(**region));
+    }
+    if *right < io_index {
+      let input_clause = InputClause::new(name);
+      input_clauses.push(match allowed_values {
+        Some(allowed_values) => input_clause.with_allowed_values(allowed_values),
+        None => input_clause,
+      });
+    } else if *right < annotation_index {
+      let output_clause = OutputClause::new(name);
+      output_clauses.push(match allowed_values {
+        Some(allowed_values) => output_clause.with_allowed_values(allowed_values),
+        None => output_clause,
+      });
+    } else {
+      annotation_clauses.push(AnnotationClause::new(name));
+    }
+  }
+
+  // rules
+  let mut rules = vec![];
+  for rule_region in cells.iter().filter(|region| region.top() > header_index && region.left() == 1) {
+    let mut entries = vec![];
+    for (left, _) in &columns {
+      let region = cells
+        .iter()
+        .find(|region| region.left() == *left && region.top() == rule_region.top())
+        .ok_or(TableError::MissingCell(*left, rule_region.top()))?;
+      if region.bottom() != rule_region.bottom() {
+        return Err(TableError::UnexpectedCell(*region));
+      }
+      entries.push(grid.cell(region));
+    }
+    let annotation_entries = entries.split_off(input_clauses.len() + output_clauses.len());
+    let output_entries = entries.split_off(input_clauses.len());
+    rules.push(Rule::new(entries, output_entries).with_annotation_entries(annotation_entries));
+  }
+
+  let mut decision_table = DecisionTable::new(hit_policy, input_clauses, output_clauses, rules).with_annotation_clauses(annotation_clauses);
+  if let Some(information_item_name) = information_item_name {
+    decision_table = decision_table.with_information_item_name(information_item_name);
+  }
+  if let Some(output_label) = output_label {
+    decision_table = decision_table.with_output_label(output_label);
+  }
+  Ok(decision_table)
+}
+
+/// Two-dimensional grid of characters.
+struct Grid {
+  /// Characters of the grid, row by row.
+  chars: Vec<Vec<char>>,
+  /// Texts of the characters including their marks, empty in second columns of wide characters.
+  texts: Vec<Vec<String>>,
+  /// Texts of the source grid and the index of its first transposed row, when the grid is transposed.
+  source: Option<(Vec<Vec<String>>, usize)>,
+}
+
+impl Grid {
+  /// Creates a grid from rows of characters.
+  fn new(rows: &[Row]) -> Self {
+    Self {
+      chars: rows.iter().map(|row| row.iter().map(|chr| chr.canonical()).collect()).collect(),
+      texts: rows.iter().map(|row| row.iter().map(|chr| chr.to_string()).collect()).collect(),
+      source: None,
+    }
+  }
+
+  /// Returns the grid with the frame of rows starting with the specified index transposed,
+  /// so rows become columns and columns become rows.
+  ///
+  /// Lines attached to the top side of the first transposed row are skipped,
+  /// texts of the cells are read from the source grid.
+  fn transposed(&self, top_index: usize) -> Self {
+    let rows = &self.chars[top_index.min(self.chars.len())..];
+    // the top edge of the body may be joined with the wider box of the information item name
+    let width = rows.iter().skip(1).map(Vec::len).max().unwrap_or(0);
+    let chars = (0..width)
+      .map(|col_index| {
+        rows
+          .iter()
+          .enumerate()
+          .map(|(row_index, row)| {
+            let ch = row.get(col_index).copied().unwrap_or(SPACE);
+            if !Char::from(ch).is_frame() {
+              return SPACE;
+            }
+            let arms = Arms::from(ch);
+            Arms {
+              up: arms.left,
+              right: arms.down,
+              down: arms.right,
+              left: if row_index == 0 { Line::Empty } else { arms.up },
+            }
+            .char()
+          })
+          .collect()
+      })
+      .collect();
+    Self {
+      chars,
+      texts: vec![],
+      source: Some((self.texts.clone(), top_index)),
+    }
+  }
+
+  /// Returns the region in the source grid, corresponding to the region in this grid.
+  fn source_region(&self, region: &Region) -> Region {
+    match &self.source {
+      Some((_, top_index)) => Region::new(region.top(), region.left() + top_index, region.height(), region.width()),
+      None => *region,
+    }
+  }
+
+  /// Returns the error with positions referring to the source grid.
+  fn source_error(&self, error: TableError) -> TableError {
+    match (&self.source, error) {
+      (Some(_), TableError::UnexpectedCell(region)) => TableError::UnexpectedCell(self.source_region(&region)),
+      (Some((_, top_index)), TableError::MissingCell(col_index, row_index)) => TableError::MissingCell(row_index, col_index + top_index),
+      (_, error) => error,
+    }
+  }
+
+  /// Returns the number of rows in the grid.
+  fn height(&self) -> usize {
+    self.chars.len()
+  }
+
+  /// Returns the number of characters in the row with the specified index.
+  fn width(&self, row_index: usize) -> usize {
+    self.chars.get(row_index).map_or(0, Vec::len)
+  }
+
+  /// Returns the character at the specified position.
+  fn get(&self, col_index: usize, row_index: usize) -> Option<char> {
+    self.chars.get(row_index).and_then(|row| row.get(col_index)).copied()
+  }
+
+  /// Returns the lines attached to the character at the specified position.
+  fn arms(&self, col_index: usize, row_index: usize) -> Arms {
+    self.get(col_index, row_index).map(Arms::from).unwrap_or_default()
+  }
+
+  /// Returns `true` when there is a horizontal line at the specified position.
+  fn is_horz(&self, col_index: usize, row_index: usize) -> bool {
+    let arms = self.arms(col_index, row_index);
+    arms.left != Line::Empty || arms.right != Line::Empty
+  }
+
+  /// Returns the regions of all cells, ordered by the top-left corner (row by row).
+  ///
+  /// A cell is a rectangle of characters other than frame characters, having
+  /// a vertical line on the left side and a horizontal line on the top side of its top-left corner.
+  fn cells(&self) -> Vec<Region> {
+    let mut cells = vec![];
+    for (row_index, row) in self.chars.iter().enumerate().skip(1) {
+      for (col_index, ch) in row.iter().enumerate().skip(1) {
+        if Char::from(*ch).is_frame() || !self.arms(col_index - 1, row_index).is_vert() || !self.is_horz(col_index, row_index - 1) {
+          continue;
+        }
+        let mut right_index = col_index;
+        while self.get(right_index + 1, row_index).is_some_and(|ch| !Arms::from(ch).is_vert()) {
+          right_index += 1;
+        }
+        let mut bottom_index = row_index;
+        while self.get(col_index, bottom_index + 1).is_some() && !self.is_horz(col_index, bottom_index + 1) {
+          bottom_index += 1;
+        }
+        cells.push(Region::new(col_index, row_index, right_index - col_index + 1, bottom_index - row_index + 1));
+      }
+    }
+    cells
+  }
+
+  /// Returns the text of the cell, lines are trimmed and empty lines
+  /// at the beginning and at the end of the cell are skipped.
+  fn text(&self, region: &Region) -> String {
+    let region = self.source_region(region);
+    let texts = self.source.as_ref().map_or(&self.texts, |(texts, _)| texts);
+    let lines = (region.top()..=region.bottom())
+      .map(|row_index| {
+        (region.left()..=region.right())
+          .filter_map(|col_index| texts.get(row_index).and_then(|row| row.get(col_index)))
+          .map(String::as_str)
+          .collect::<String>()
+          .trim()
+          .to_string()
+      })
+      .collect::<Vec<String>>();
+    let first = lines.iter().position(|line| !line.is_empty()).unwrap_or(lines.len());
+    let last = lines.iter().rposition(|line| !line.is_empty()).map_or(first, |index| index + 1);
+    lines[first..last].join("\n")
+  }
+
+  /// Returns the cell with the text and region in the source grid.
+  fn cell(&self, region: &Region) -> Cell {
+    Cell::new(self.text(region)).with_region(self.source_region(region))
+  }
+}
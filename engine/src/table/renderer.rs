@@ -0,0 +1,669 @@
+//! # Renderer of the decision table
+
+use crate::{display_width, to_chars, Arms, Cell, Char, DecisionTable, HitPolicy, Line, Orientation};
+use std::fmt;
+use std::fmt::Display;
+
+/// Padding on both sides of the text in table cells.
+const PADDING: usize = 1;
+
+/// Padding on both sides of the information item name.
+const NAME_PADDING: usize = 2;
+
+impl Display for DecisionTable {
+  /// Renders the decision table as box-drawing text.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", render_decision_table(self))
+  }
+}
+
+/// Horizontal alignment of the text in a cell.
+#[derive(Copy, Clone)]
+enum Align {
+  Left,
+  Center,
+}
+
+/// Renders the decision table as box-drawing text.
+///
+/// Column widths are calculated to fit the widest line of text in each column.
+/// Texts in the header are centered, input, output and annotation entries are aligned to the left.
+/// Input columns are separated from output columns with a double line, and so are output columns
+/// from annotation columns. The information item name (if present) is rendered in a box
+/// joined with the top edge of the table. Vertical decision tables are rendered with rules
+/// as columns, see [Orientation::Vertical], and crosstabs with output entries in the grid
+/// of input values, see [Orientation::Crosstab].
+///
+/// # Examples
+///
+/// ```
+/// use dtee::*;
+///
+/// let decision_table = DecisionTable::new(
+///   HitPolicy::First,
+///   vec![InputClause::new(Cell::new("Age"))],
+///   vec![OutputClause::new(Cell::new("Adult"))],
+///   vec![Rule::new(vec![Cell::new("<18")], vec![Cell::new("false")]), Rule::new(vec![Cell::new(">=18")], vec![Cell::new("true")])],
+/// )
+/// .with_information_item_name(Cell::new("Adult"));
+///
+/// let expected = r#"
+/// ┌─────────┐
+/// │  Adult  │
+/// ├───┬─────┴╥───────┐
+/// │ F │ Age  ║ Adult │
+/// ╞═══╪══════╬═══════╡
+/// │ 1 │ <18  ║ false │
+/// ├───┼──────╫───────┤
+/// │ 2 │ >=18 ║ true  │
+/// └───┴──────╨───────┘"#;
+/// assert_eq!(expected.trim(), render_decision_table(&decision_table));
+/// ```
+pub fn render_decision_table(decision_table: &DecisionTable) -> String {
+  render_decision_table_with_padding(decision_table, PADDING)
+}
+
+/// Renders the decision table as box-drawing text, like [render_decision_table],
+/// with the specified number of spaces on both sides of the text in table cells.
+///
+/// # Examples
+///
+/// ```
+/// use dtee::*;
+///
+/// let decision_table = "┌───┬───╥───┐\n│ F │ a ║ b │\n╞═══╪═══╬═══╡\n│ 1 │ 2 ║ 3 │\n└───┴───╨───┘".parse::<DecisionTable>().unwrap();
+/// assert_eq!("┌─┬─╥─┐\n│F│a║b│\n╞═╪═╬═╡\n│1│2║3│\n└─┴─╨─┘", render_decision_table_with_padding(&decision_table, 0));
+/// ```
+pub fn render_decision_table_with_padding(decision_table: &DecisionTable, padding: usize) -> String {
+  match decision_table.orientation() {
+    Orientation::Vertical => return render_vertical(decision_table, padding),
+    Orientation::Crosstab => {
+      if let Some(crosstab) = Crosstab::new(decision_table) {
+        return render_crosstab(decision_table, &crosstab, padding);
+      }
+    }
+    Orientation::Horizontal => {}
+  }
+  let input_count = decision_table.input_clauses().len();
+  let output_count = decision_table.output_clauses().len();
+  let annotation_count = decision_table.annotation_clauses().len();
+  let rules = decision_table.rules();
+  let output_label = decision_table.output_label().filter(|_| output_count > 1);
+
+  // texts of the header
+  let names = decision_table
+    .input_clauses()
+    .iter()
+    .map(|clause| clause.input_expression())
+    .chain(decision_table.output_clauses().iter().map(|clause| clause.name()))
+    .chain(decision_table.annotation_clauses().iter().map(|clause| clause.name()))
+    .collect::<Vec<&Cell>>();
+  let allowed_values = decision_table
+    .input_clauses()
+    .iter()
+    .map(|clause| clause.allowed_values())
+    .chain(decision_table.output_clauses().iter().map(|clause| clause.allowed_values()))
+    .collect::<Vec<Option<&Cell>>>();
+  let has_allowed_values = allowed_values.iter().any(Option::is_some);
+  let entries = rules
+    .iter()
+    .map(|rule| {
+      rule
+        .input_entries()
+        .iter()
+        .chain(rule.output_entries())
+        .chain(rule.annotation_entries())
+        .collect::<Vec<&Cell>>()
+    })
+    .collect::<Vec<Vec<&Cell>>>();
+
+  // column widths, including the padding
+  let hit_policy = decision_table.hit_policy().to_string();
+  let hit_policy_width = hit_policy.chars().count().max(rules.len().to_string().len()) + 2 * padding;
+  let mut widths = names
+    .iter()
+    .enumerate()
+    .map(|(index, name)| {
+      let width = allowed_values
+        .get(index)
+        .copied()
+        .flatten()
+        .into_iter()
+        .chain(entries.iter().filter_map(|rule_entries| rule_entries.get(index).copied()))
+        .fold(text_width(name), |width, cell| width.max(text_width(cell)));
+      width.max(1) + 2 * padding
+    })
+    .collect::<Vec<usize>>();
+  if let Some(output_label) = output_label {
+    let outputs = input_count..input_count + output_count;
+    let span = widths[outputs.clone()].iter().sum::<usize>() + output_count - 1;
+    let required = text_width(output_label) + 2 * padding;
+    if required > span {
+      widths[outputs.end - 1] += required - span;
+    }
+  }
+
+  // positions of vertical lines, starting with the left edge and ending with the right edge
+  let mut lines = vec![0, hit_policy_width + 1];
+  for width in &widths {
+    lines.push(lines[lines.len() - 1] + width + 1);
+  }
+  let edge = lines[lines.len() - 1];
+  let io_line = lines[1 + input_count];
+  let annotation_line = (annotation_count > 0).then(|| lines[1 + input_count + output_count]);
+
+  // rows of the header
+  let name_height = decision_table.information_item_name().map_or(0, text_height);
+  let top = if decision_table.information_item_name().is_some() { name_height + 1 } else { 0 };
+  let label_height = output_label.map_or(0, |label| text_height(label) + 1);
+  let header_height = names
+    .iter()
+    .enumerate()
+    .map(|(index, name)| {
+      let is_output = index >= input_count && index < input_count + output_count;
+      text_height(name) + if is_output { label_height } else { 0 }
+    })
+    .max()
+    .unwrap_or(1)
+    .max(1);
+  let allowed_values_height = if has_allowed_values {
+    allowed_values.iter().map(|cell| cell.map_or(1, text_height)).max().unwrap_or(1) + 1
+  } else {
+    0
+  };
+  let separator = top + header_height + allowed_values_height + 1;
+
+  // rows of rules
+  let mut rule_tops = vec![];
+  let mut bottom = separator;
+  for rule_entries in &entries {
+    rule_tops.push(bottom + 1);
+    bottom += rule_entries.iter().map(|cell| text_height(cell)).max().unwrap_or(1).max(1) + 1;
+  }
+  if rules.is_empty() {
+    bottom += 2;
+  }
+
+  let name_width = decision_table.information_item_name().map_or(0, |name| text_width(name) + 2 * NAME_PADDING);
+  let mut canvas = Canvas::new(edge.max(name_width + 1) + 1, bottom + 1);
+
+  // information item name
+  if let Some(name) = decision_table.information_item_name() {
+    canvas.name_box(name, top, name_width);
+  }
+
+  // frame of the table
+  canvas.horz(top, 0, edge, Line::Single);
+  canvas.horz(bottom, 0, edge, Line::Single);
+  canvas.horz(separator, 0, edge, Line::Double);
+  for rule_top in rule_tops.iter().skip(1) {
+    canvas.horz(rule_top - 1, 0, edge, Line::Single);
+  }
+  if has_allowed_values {
+    canvas.horz(top + header_height + 1, lines[1], edge, Line::Single);
+  }
+  for (index, line) in lines.iter().enumerate() {
+    let style = if *line == io_line || Some(*line) == annotation_line {
+      Line::Double
+    } else {
+      Line::Single
+    };
+    let is_inner_output = index > input_count + 1 && index < input_count + output_count + 1;
+    let line_top = if is_inner_output { top + label_height } else { top };
+    canvas.vert(*line, line_top, bottom, style);
+  }
+
+  // header
+  canvas.text(&Cell::new(&hit_policy), 1 + padding, top + 1, hit_policy_width - 2 * padding, Align::Center);
+  if let Some(output_label) = output_label {
+    let right = lines[1 + input_count + output_count];
+    canvas.horz(top + label_height, io_line, right, Line::Single);
+    canvas.text(output_label, io_line + 1 + padding, top + 1, right - io_line - 1 - 2 * padding, Align::Center);
+  }
+  for (index, name) in names.iter().enumerate() {
+    let is_output = index >= input_count && index < input_count + output_count;
+    let name_top = top + 1 + if is_output { label_height } else { 0 };
+    canvas.text(name, lines[index + 1] + 1 + padding, name_top, widths[index] - 2 * padding, Align::Center);
+    if let Some(Some(cell)) = allowed_values.get(index) {
+      canvas.text(cell, lines[index + 1] + 1 + padding, top + header_height + 2, widths[index] - 2 * padding, Align::Center);
+    }
+  }
+
+  // rules
+  for (rule_index, (rule_top, rule_entries)) in rule_tops.iter().zip(&entries).enumerate() {
+    let number = Cell::new(rule_index + 1);
+    canvas.text(&number, 1 + padding, *rule_top, hit_policy_width - 2 * padding, Align::Center);
+    for (index, cell) in rule_entries.iter().enumerate() {
+      if let Some(width) = widths.get(index) {
+        canvas.text(cell, lines[index + 1] + 1 + padding, *rule_top, width - 2 * padding, Align::Left);
+      }
+    }
+  }
+
+  canvas.to_string()
+}
+
+/// Renders the vertical decision table as box-drawing text.
+///
+/// The hit policy is placed in the top-left cell, followed by rule numbers. Below are rows
+/// of input, output and annotation clauses, each row starts with the name of the clause
+/// (and allowed values, when present in any clause) followed by the entries of all rules.
+/// Input rows are separated from output rows with a double line, and so are output rows
+/// from annotation rows. The name of the compound output is placed on the left side
+/// of the names of all output clauses.
+fn render_vertical(decision_table: &DecisionTable, padding: usize) -> String {
+  let input_count = decision_table.input_clauses().len();
+  let output_count = decision_table.output_clauses().len();
+  let rules = decision_table.rules();
+  let output_label = decision_table.output_label().filter(|_| output_count > 1);
+  let outputs = input_count..input_count + output_count;
+
+  // texts of the clause rows
+  let names = decision_table
+    .input_clauses()
+    .iter()
+    .map(|clause| clause.input_expression())
+    .chain(decision_table.output_clauses().iter().map(|clause| clause.name()))
+    .chain(decision_table.annotation_clauses().iter().map(|clause| clause.name()))
+    .collect::<Vec<&Cell>>();
+  let allowed_values = decision_table
+    .input_clauses()
+    .iter()
+    .map(|clause| clause.allowed_values())
+    .chain(decision_table.output_clauses().iter().map(|clause| clause.allowed_values()))
+    .collect::<Vec<Option<&Cell>>>();
+  let has_allowed_values = allowed_values.iter().any(Option::is_some);
+  let entries = rules
+    .iter()
+    .map(|rule| {
+      rule
+        .input_entries()
+        .iter()
+        .chain(rule.output_entries())
+        .chain(rule.annotation_entries())
+        .collect::<Vec<&Cell>>()
+    })
+    .collect::<Vec<Vec<&Cell>>>();
+
+  // widths of the columns of the header, including the padding; names of inputs and annotations
+  // span the compound output name column and the name column, the hit policy spans all columns
+  let hit_policy = decision_table.hit_policy().to_string();
+  let label_width = output_label.map_or(0, |label| text_width(label) + 2 * padding + 1);
+  let allowed_values_width = if has_allowed_values {
+    allowed_values.iter().flatten().map(|cell| text_width(cell)).max().unwrap_or(0).max(1) + 2 * padding + 1
+  } else {
+    0
+  };
+  let mut name_width = names.iter().enumerate().fold(1 + 2 * padding, |width, (index, name)| {
+    let required = text_width(name) + 2 * padding;
+    width.max(if outputs.contains(&index) { required } else { required.saturating_sub(label_width) })
+  });
+  name_width = name_width.max((hit_policy.chars().count() + 2 * padding).saturating_sub(label_width + allowed_values_width));
+  let mut widths = entries
+    .iter()
+    .enumerate()
+    .map(|(index, rule_entries)| {
+      let width = rule_entries.iter().fold((index + 1).to_string().len(), |width, cell| width.max(text_width(cell)));
+      width.max(1) + 2 * padding
+    })
+    .collect::<Vec<usize>>();
+  if rules.is_empty() {
+    widths.push(1 + 2 * padding);
+  }
+
+  // positions of vertical lines, starting with the left edge and ending with the right edge
+  let label_line = label_width;
+  let name_line = label_width + name_width + 1;
+  let header_line = name_line + allowed_values_width;
+  let mut lines = vec![header_line];
+  for width in &widths {
+    lines.push(lines[lines.len() - 1] + width + 1);
+  }
+  let edge = lines[lines.len() - 1];
+
+  // positions of horizontal lines, starting with the top edge and ending with the bottom edge
+  let name_height = decision_table.information_item_name().map_or(0, text_height);
+  let top = if decision_table.information_item_name().is_some() { name_height + 1 } else { 0 };
+  let mut heights = names
+    .iter()
+    .enumerate()
+    .map(|(index, name)| {
+      let height = allowed_values
+        .get(index)
+        .copied()
+        .flatten()
+        .into_iter()
+        .chain(entries.iter().filter_map(|rule_entries| rule_entries.get(index).copied()))
+        .fold(text_height(name), |height, cell| height.max(text_height(cell)));
+      height.max(1)
+    })
+    .collect::<Vec<usize>>();
+  if let Some(output_label) = output_label {
+    let span = heights[outputs.clone()].iter().sum::<usize>() + output_count - 1;
+    let required = text_height(output_label);
+    if required > span {
+      heights[outputs.end - 1] += required - span;
+    }
+  }
+  let mut rows = vec![top, top + text_height(&Cell::new(&hit_policy)) + 1];
+  for height in &heights {
+    rows.push(rows[rows.len() - 1] + height + 1);
+  }
+  let bottom = rows[rows.len() - 1];
+  let io_row = rows[1 + input_count];
+  let annotation_row = (names.len() > input_count + output_count).then(|| rows[1 + input_count + output_count]);
+
+  let name_width = decision_table.information_item_name().map_or(0, |name| text_width(name) + 2 * NAME_PADDING);
+  let mut canvas = Canvas::new(edge.max(name_width + 1) + 1, bottom + 1);
+
+  // information item name
+  if let Some(name) = decision_table.information_item_name() {
+    canvas.name_box(name, top, name_width);
+  }
+
+  // frame of the table
+  for (index, row) in rows.iter().enumerate() {
+    let style = if *row == io_row || Some(*row) == annotation_row { Line::Double } else { Line::Single };
+    let is_inner_output = index > input_count + 1 && index < input_count + output_count + 1;
+    let left = if is_inner_output { label_line } else { 0 };
+    canvas.horz(*row, left, edge, style);
+  }
+  canvas.vert(0, top, bottom, Line::Single);
+  if output_label.is_some() {
+    canvas.vert(label_line, io_row, rows[1 + input_count + output_count], Line::Single);
+  }
+  if has_allowed_values {
+    canvas.vert(name_line, rows[1], bottom, Line::Single);
+  }
+  for (index, line) in lines.iter().enumerate() {
+    canvas.vert(*line, top, bottom, if index == 0 { Line::Double } else { Line::Single });
+  }
+
+  // header
+  canvas.text(&Cell::new(&hit_policy), 1 + padding, top + 1, header_line - 1 - 2 * padding, Align::Center);
+  for index in 0..rules.len() {
+    let number = Cell::new(index + 1);
+    canvas.text(&number, lines[index] + 1 + padding, top + 1, widths[index] - 2 * padding, Align::Center);
+  }
+  if let Some(output_label) = output_label {
+    canvas.text(output_label, 1 + padding, io_row + 1, label_width - 1 - 2 * padding, Align::Center);
+  }
+  for (index, name) in names.iter().enumerate() {
+    let left = if outputs.contains(&index) { label_line } else { 0 };
+    canvas.text(name, left + 1 + padding, rows[index + 1] + 1, 0, Align::Left);
+    if let Some(Some(cell)) = allowed_values.get(index) {
+      canvas.text(cell, name_line + 1 + padding, rows[index + 1] + 1, 0, Align::Left);
+    }
+  }
+
+  // rules
+  for (rule_index, rule_entries) in entries.iter().enumerate() {
+    for (index, cell) in rule_entries.iter().enumerate() {
+      canvas.text(cell, lines[rule_index] + 1 + padding, rows[index + 1] + 1, 0, Align::Left);
+    }
+  }
+
+  canvas.to_string()
+}
+
+/// Values of inputs and output entries of the decision table rendered as a crosstab.
+struct Crosstab<'a> {
+  /// Values of the first input, placed in rows.
+  rows: Vec<&'a Cell>,
+  /// Values of the second input, placed in columns.
+  columns: Vec<&'a Cell>,
+  /// Output entries for each row and column, missing rules leave empty cells.
+  entries: Vec<Vec<Option<&'a Cell>>>,
+}
+
+impl<'a> Crosstab<'a> {
+  /// Groups the rules of the decision table by input values, returns `None`
+  /// when the decision table can not be rendered as a crosstab.
+  fn new(decision_table: &'a DecisionTable) -> Option<Self> {
+    let rules = decision_table.rules();
+    if decision_table.hit_policy() != HitPolicy::Unique
+      || decision_table.input_clauses().len() != 2
+      || decision_table.input_clauses().iter().any(|input_clause| input_clause.allowed_values().is_some())
+      || decision_table.output_clauses().len() != 1
+      || decision_table.output_clauses().iter().any(|output_clause| output_clause.allowed_values().is_some())
+      || !decision_table.annotation_clauses().is_empty()
+      || rules.is_empty()
+    {
+      return None;
+    }
+    let mut rows: Vec<&Cell> = vec![];
+    let mut columns: Vec<&Cell> = vec![];
+    for rule in rules {
+      let [row, column] = rule.input_entries() else {
+        return None;
+      };
+      if !rows.iter().any(|cell| cell.text() == row.text()) {
+        rows.push(row);
+      }
+      if !columns.iter().any(|cell| cell.text() == column.text()) {
+        columns.push(column);
+      }
+    }
+    let mut entries = vec![vec![None; columns.len()]; rows.len()];
+    for rule in rules {
+      let row_index = rows.iter().position(|cell| cell.text() == rule.input_entries()[0].text())?;
+      let col_index = columns.iter().position(|cell| cell.text() == rule.input_entries()[1].text())?;
+      let entry = &mut entries[row_index][col_index];
+      if entry.is_some() {
+        return None;
+      }
+      *entry = rule.output_entries().first();
+    }
+    Some(Self { rows, columns, entries })
+  }
+}
+
+/// Renders the decision table as a crosstab.
+///
+/// The name of the output is placed in the top-left corner, the expression of the second input
+/// spans the columns of its values, the expression of the first input spans the rows of its values.
+/// Input values are separated from output entries with double lines.
+fn render_crosstab(decision_table: &DecisionTable, crosstab: &Crosstab, padding: usize) -> String {
+  let empty = Cell::default();
+  let output_name = decision_table.output_clauses()[0].name();
+  let row_expression = decision_table.input_clauses()[0].input_expression();
+  let column_expression = decision_table.input_clauses()[1].input_expression();
+
+  // column widths, including the padding
+  let row_expression_width = text_width(row_expression) + 2 * padding;
+  let mut row_values_width = crosstab.rows.iter().fold(1, |width, cell| width.max(text_width(cell))) + 2 * padding;
+  let corner_width = row_expression_width + 1 + row_values_width;
+  let required = text_width(output_name) + 2 * padding;
+  if required > corner_width {
+    row_values_width += required - corner_width;
+  }
+  let mut widths = crosstab
+    .columns
+    .iter()
+    .enumerate()
+    .map(|(col_index, column)| {
+      let width = crosstab
+        .entries
+        .iter()
+        .filter_map(|row| row[col_index])
+        .fold(text_width(column), |width, cell| width.max(text_width(cell)));
+      width.max(1) + 2 * padding
+    })
+    .collect::<Vec<usize>>();
+  let span = widths.iter().sum::<usize>() + widths.len() - 1;
+  let required = text_width(column_expression) + 2 * padding;
+  if required > span {
+    if let Some(width) = widths.last_mut() {
+      *width += required - span;
+    }
+  }
+
+  // positions of vertical lines, starting with the left edge and ending with the right edge
+  let row_values_line = row_expression_width + 1;
+  let io_line = row_values_line + row_values_width + 1;
+  let mut lines = vec![io_line];
+  for width in &widths {
+    lines.push(lines[lines.len() - 1] + width + 1);
+  }
+  let edge = lines[lines.len() - 1];
+
+  // positions of horizontal lines, starting with the top edge and ending with the bottom edge
+  let name_height = decision_table.information_item_name().map_or(0, text_height);
+  let top = if decision_table.information_item_name().is_some() { name_height + 1 } else { 0 };
+  let column_expression_line = top + text_height(column_expression) + 1;
+  let mut values_height = crosstab.columns.iter().map(|cell| text_height(cell)).max().unwrap_or(1);
+  let corner_height = column_expression_line - top + values_height;
+  if text_height(output_name) > corner_height {
+    values_height += text_height(output_name) - corner_height;
+  }
+  let header_line = column_expression_line + values_height + 1;
+  let mut heights = crosstab
+    .rows
+    .iter()
+    .zip(&crosstab.entries)
+    .map(|(row, entries)| entries.iter().flatten().fold(text_height(row), |height, cell| height.max(text_height(cell))))
+    .collect::<Vec<usize>>();
+  let span = heights.iter().sum::<usize>() + heights.len() - 1;
+  if text_height(row_expression) > span {
+    if let Some(height) = heights.last_mut() {
+      *height += text_height(row_expression) - span;
+    }
+  }
+  let mut rows = vec![header_line];
+  for height in &heights {
+    rows.push(rows[rows.len() - 1] + height + 1);
+  }
+  let bottom = rows[rows.len() - 1];
+
+  let name_width = decision_table.information_item_name().map_or(0, |name| text_width(name) + 2 * NAME_PADDING);
+  let mut canvas = Canvas::new(edge.max(name_width + 1) + 1, bottom + 1);
+
+  // information item name
+  if let Some(name) = decision_table.information_item_name() {
+    canvas.name_box(name, top, name_width);
+  }
+
+  // frame of the table
+  canvas.horz(top, 0, edge, Line::Single);
+  canvas.horz(column_expression_line, io_line, edge, Line::Single);
+  canvas.horz(header_line, 0, edge, Line::Double);
+  for row in &rows[1..rows.len() - 1] {
+    canvas.horz(*row, row_values_line, edge, Line::Single);
+  }
+  canvas.horz(bottom, 0, edge, Line::Single);
+  canvas.vert(0, top, bottom, Line::Single);
+  canvas.vert(row_values_line, header_line, bottom, Line::Single);
+  canvas.vert(io_line, top, bottom, Line::Double);
+  for line in &lines[1..lines.len() - 1] {
+    canvas.vert(*line, column_expression_line, bottom, Line::Single);
+  }
+  canvas.vert(edge, top, bottom, Line::Single);
+
+  // header
+  canvas.text(output_name, 1 + padding, top + 1, io_line - 1 - 2 * padding, Align::Center);
+  canvas.text(column_expression, io_line + 1 + padding, top + 1, edge - io_line - 1 - 2 * padding, Align::Center);
+  for (col_index, column) in crosstab.columns.iter().enumerate() {
+    canvas.text(column, lines[col_index] + 1 + padding, column_expression_line + 1, 0, Align::Left);
+  }
+
+  // rows
+  canvas.text(row_expression, 1 + padding, header_line + 1, 0, Align::Left);
+  for (row_index, row) in crosstab.rows.iter().enumerate() {
+    canvas.text(row, row_values_line + 1 + padding, rows[row_index] + 1, 0, Align::Left);
+    for (col_index, entry) in crosstab.entries[row_index].iter().enumerate() {
+      canvas.text(entry.unwrap_or(&empty), lines[col_index] + 1 + padding, rows[row_index] + 1, 0, Align::Left);
+    }
+  }
+
+  canvas.to_string()
+}
+
+/// Returns the width of the widest line of text in the cell.
+fn text_width(cell: &Cell) -> usize {
+  cell.lines().iter().map(|line| display_width(line)).max().unwrap_or(0)
+}
+
+/// Returns the number of lines of text in the cell, at least one.
+fn text_height(cell: &Cell) -> usize {
+  cell.lines().len().max(1)
+}
+
+/// Grid of characters, where the lines are drawn first and the texts are placed between them.
+struct Canvas {
+  /// Lines attached to each position.
+  arms: Vec<Vec<Arms>>,
+  /// Characters of the texts placed at each position, one character per terminal column.
+  chars: Vec<Vec<Option<Char>>>,
+}
+
+impl Canvas {
+  /// Creates an empty canvas with the specified size.
+  fn new(width: usize, height: usize) -> Self {
+    Self {
+      arms: vec![vec![Arms::default(); width]; height],
+      chars: vec![vec![None; width]; height],
+    }
+  }
+
+  /// Draws the box with the information item name, joined with the top edge of the table.
+  fn name_box(&mut self, name: &Cell, top: usize, name_width: usize) {
+    let right = name_width + 1;
+    self.horz(0, 0, right, Line::Single);
+    self.horz(top, 0, right, Line::Single);
+    self.vert(0, 0, top, Line::Single);
+    self.vert(right, 0, top, Line::Single);
+    self.text(name, 1 + NAME_PADDING, 1, name_width - 2 * NAME_PADDING, Align::Center);
+  }
+
+  /// Draws a horizontal line between the specified columns (inclusive).
+  fn horz(&mut self, row_index: usize, left: usize, right: usize, style: Line) {
+    for col_index in left..right {
+      let arms = &mut self.arms[row_index];
+      arms[col_index].right = arms[col_index].right.max(style);
+      arms[col_index + 1].left = arms[col_index + 1].left.max(style);
+    }
+  }
+
+  /// Draws a vertical line between the specified rows (inclusive).
+  fn vert(&mut self, col_index: usize, top: usize, bottom: usize, style: Line) {
+    for row_index in top..bottom {
+      let arms = &mut self.arms[row_index][col_index];
+      arms.down = arms.down.max(style);
+      let arms = &mut self.arms[row_index + 1][col_index];
+      arms.up = arms.up.max(style);
+    }
+  }
+
+  /// Places the text of the cell at the specified position, aligned within the specified width.
+  fn text(&mut self, cell: &Cell, left: usize, top: usize, width: usize, align: Align) {
+    for (line_index, line) in cell.lines().iter().enumerate() {
+      let offset = match align {
+        Align::Left => 0,
+        Align::Center => width.saturating_sub(display_width(line)) / 2,
+      };
+      for (col_index, chr) in to_chars(line).into_iter().enumerate() {
+        self.chars[top + line_index][left + offset + col_index] = Some(chr);
+      }
+    }
+  }
+}
+
+impl Display for Canvas {
+  /// Converts the canvas into text, trailing spaces in each row are trimmed.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let rows = self
+      .arms
+      .iter()
+      .zip(&self.chars)
+      .map(|(arms, chars)| {
+        let row = arms
+          .iter()
+          .zip(chars)
+          .map(|(arms, chr)| chr.as_ref().map_or_else(|| arms.char().to_string(), Char::to_string))
+          .collect::<String>();
+        row.trim_end().to_string()
+      })
+      .collect::<Vec<String>>();
+    write!(f, "{}", rows.join("\n"))
+  }
+}
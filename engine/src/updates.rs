@@ -8,6 +8,7 @@ pub struct Updates {
   viewport_pos_changed: bool,
   viewport_size_changed: bool,
   content_changed: bool,
+  selection_changed: bool,
 }
 
 impl From<Updates> for (bool, bool, bool, bool, bool) {
@@ -52,6 +53,11 @@ impl Updates {
     self
   }
 
+  pub fn with_selection_changed(mut self, changed: bool) -> Self {
+    self.selection_changed = changed;
+    self
+  }
+
   pub fn cursor_pos_changed(&self) -> bool {
     self.cursor_pos_changed
   }
@@ -72,6 +78,10 @@ impl Updates {
     self.content_changed
   }
 
+  pub fn selection_changed(&self) -> bool {
+    self.selection_changed
+  }
+
   pub fn get(&self) -> (bool, bool, bool, bool) {
     (self.cursor_pos_changed, self.cursor_shape_changed, self.viewport_pos_changed, self.viewport_size_changed)
   }
@@ -1,28 +1,57 @@
 mod test_controller;
 mod test_files;
+mod test_formats;
+mod test_table;
 
-use dtee::{Char, Controller, Row};
+use dtee::{display_width, Alignment, Char, Controller, Row};
 use std::fmt::Write;
 
 enum Action {
+  Align(Alignment),
+  AlignColumn(Alignment, bool),
   AssertPos(usize, usize),
   CellEnd(usize),
   CellNext(usize),
   CellPrev(usize),
   CellStart(usize),
+  Copy,
+  Cut,
   DeleteBefore(usize),
+  DeleteColumn(usize),
+  DeleteRule(usize),
   DeleteUnder(usize),
   Insert(char, usize),
+  InsertAnnotationColumn(usize),
+  InsertInputColumn(usize),
+  InsertOutputColumn(usize),
+  InsertRuleAbove(usize),
+  InsertRuleBelow(usize),
   InsertStr(String, usize),
+  MoveColumnLeft(usize),
+  MoveColumnRight(usize),
   MoveDown(usize),
   MoveLeft(usize),
   MoveRight(usize),
+  MoveRuleDown(usize),
+  MoveRuleUp(usize),
   MoveUp(usize),
+  Normalize,
+  Paste(usize),
+  Redo(usize),
   RowEnd(usize),
   RowStart(usize),
+  RuleNext(usize),
+  RulePrev(usize),
+  SelectDown(usize),
+  SelectLeft(usize),
+  SelectRight(usize),
+  SelectUp(usize),
   SplitLine(usize),
   ToggleCaretBlock,
   ToggleCaretUnderScore,
+  Transpose,
+  Undo(usize),
+  UnsplitLine(usize),
 }
 
 /// A utility function for printing the decision table like on paper.
@@ -63,11 +92,11 @@ fn view(controller: &Controller) -> String {
   };
   controller.visit_visible_content(f, Some('░'.into()), None, None);
   // wrap the decision table view in frame simulating the terminal
-  let width = output.lines().map(|line| line.chars().count()).max().unwrap();
+  let width = output.lines().map(display_width).max().unwrap();
   let mut framed = String::new();
   let _ = write!(framed, "\n    ╭{}╮", "─".repeat(width));
   output.lines().for_each(|line| {
-    let char_count = line.chars().count();
+    let char_count = display_width(line);
     let _ = write!(
       framed,
       "\n    │{}{}│",
@@ -109,6 +138,12 @@ fn attr(input: &[Row]) -> String {
 /// A utility function for executing editor actions.
 fn actions(controller: &mut Controller, actions: &[Action]) {
   actions.iter().for_each(|action| match action {
+    Action::Align(alignment) => {
+      controller.align(*alignment);
+    }
+    Action::AlignColumn(alignment, keep) => {
+      controller.align_column(*alignment, *keep);
+    }
     Action::AssertPos(col_index, row_index) => {
       assert_eq!((*col_index, *row_index), controller.cursor().pos());
     }
@@ -152,11 +187,42 @@ fn actions(controller: &mut Controller, actions: &[Action]) {
         controller.cursor_move_cell_start();
       });
     }
+    Action::Copy => {
+      controller.copy();
+    }
+    Action::Cut => {
+      controller.cut();
+    }
     Action::Insert(ch, n) => {
       (0..*n).for_each(|_| {
         controller.insert_char(*ch);
       });
     }
+    Action::InsertAnnotationColumn(n) => {
+      (0..*n).for_each(|_| {
+        controller.insert_annotation_column();
+      });
+    }
+    Action::InsertInputColumn(n) => {
+      (0..*n).for_each(|_| {
+        controller.insert_input_column();
+      });
+    }
+    Action::InsertOutputColumn(n) => {
+      (0..*n).for_each(|_| {
+        controller.insert_output_column();
+      });
+    }
+    Action::InsertRuleAbove(n) => {
+      (0..*n).for_each(|_| {
+        controller.insert_rule_above();
+      });
+    }
+    Action::InsertRuleBelow(n) => {
+      (0..*n).for_each(|_| {
+        controller.insert_rule_below();
+      });
+    }
     Action::InsertStr(s, n) => {
       (0..*n).for_each(|_| {
         for ch in s.chars() {
@@ -169,11 +235,54 @@ fn actions(controller: &mut Controller, actions: &[Action]) {
         controller.delete_char_before_cursor();
       });
     }
+    Action::DeleteColumn(n) => {
+      (0..*n).for_each(|_| {
+        controller.delete_column();
+      });
+    }
+    Action::DeleteRule(n) => {
+      (0..*n).for_each(|_| {
+        controller.delete_rule();
+      });
+    }
     Action::DeleteUnder(n) => {
       (0..*n).for_each(|_| {
         controller.delete_char_under_cursor();
       });
     }
+    Action::MoveColumnLeft(n) => {
+      (0..*n).for_each(|_| {
+        controller.move_column_left();
+      });
+    }
+    Action::MoveColumnRight(n) => {
+      (0..*n).for_each(|_| {
+        controller.move_column_right();
+      });
+    }
+    Action::MoveRuleDown(n) => {
+      (0..*n).for_each(|_| {
+        controller.move_rule_down();
+      });
+    }
+    Action::MoveRuleUp(n) => {
+      (0..*n).for_each(|_| {
+        controller.move_rule_up();
+      });
+    }
+    Action::Normalize => {
+      controller.normalize();
+    }
+    Action::Paste(n) => {
+      (0..*n).for_each(|_| {
+        controller.paste();
+      });
+    }
+    Action::Redo(n) => {
+      (0..*n).for_each(|_| {
+        controller.redo();
+      });
+    }
     Action::RowEnd(n) => {
       (0..*n).for_each(|_| {
         controller.cursor_move_row_end();
@@ -184,6 +293,36 @@ fn actions(controller: &mut Controller, actions: &[Action]) {
         controller.cursor_move_row_start();
       });
     }
+    Action::RuleNext(n) => {
+      (0..*n).for_each(|_| {
+        controller.cursor_move_rule_next();
+      });
+    }
+    Action::RulePrev(n) => {
+      (0..*n).for_each(|_| {
+        controller.cursor_move_rule_prev();
+      });
+    }
+    Action::SelectDown(n) => {
+      (0..*n).for_each(|_| {
+        controller.select_down();
+      });
+    }
+    Action::SelectLeft(n) => {
+      (0..*n).for_each(|_| {
+        controller.select_left();
+      });
+    }
+    Action::SelectRight(n) => {
+      (0..*n).for_each(|_| {
+        controller.select_right();
+      });
+    }
+    Action::SelectUp(n) => {
+      (0..*n).for_each(|_| {
+        controller.select_up();
+      });
+    }
     Action::SplitLine(n) => {
       (0..*n).for_each(|_| {
         controller.split_line();
@@ -195,5 +334,18 @@ fn actions(controller: &mut Controller, actions: &[Action]) {
     Action::ToggleCaretUnderScore => {
       controller.cursor_toggle_caret_under_score();
     }
+    Action::Transpose => {
+      controller.transpose();
+    }
+    Action::Undo(n) => {
+      (0..*n).for_each(|_| {
+        controller.undo();
+      });
+    }
+    Action::UnsplitLine(n) => {
+      (0..*n).for_each(|_| {
+        controller.unsplit_line();
+      });
+    }
   })
 }
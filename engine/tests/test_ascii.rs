@@ -0,0 +1,113 @@
+use dtee::{frame_from_ascii, frame_to_ascii, Controller, DecisionTable, HitPolicy};
+
+const ASCII: &str = r#"
++-------+
+|  SLA  |
++---+---+-------------+---------------+-----+
+| U | YearsAsCustomer | NumberOfUnits # SLA |
+|   +-----------------+---------------+-----+
+|   |    [0..100]     | [0..1000000]  # 1,2 |
++===+=================+===============+=====+
+| 1 | <2              | <1000         # 1   |
++---+-----------------+---------------+-----+
+| 2 | <2              | >=1000        # 2   |
++---+-----------------+---------------+-----+
+| 3 | >=2             | <500          # 1   |
++---+-----------------+---------------+-----+
+| 4 | >=2             | >=500         # 2   |
++---+-----------------+---------------+-----+
+"#;
+
+const UNICODE: &str = r#"
+┌───────┐
+│  SLA  │
+├───┬───┴─────────────┬───────────────╥─────┐
+│ U │ YearsAsCustomer │ NumberOfUnits ║ SLA │
+│   ├─────────────────┼───────────────╫─────┤
+│   │    [0..100]     │ [0..1000000]  ║ 1,2 │
+╞═══╪═════════════════╪═══════════════╬═════╡
+│ 1 │ <2              │ <1000         ║ 1   │
+├───┼─────────────────┼───────────────╫─────┤
+│ 2 │ <2              │ >=1000        ║ 2   │
+├───┼─────────────────┼───────────────╫─────┤
+│ 3 │ >=2             │ <500          ║ 1   │
+├───┼─────────────────┼───────────────╫─────┤
+│ 4 │ >=2             │ >=500         ║ 2   │
+└───┴─────────────────┴───────────────╨─────┘
+"#;
+
+#[test]
+fn _0001() {
+  // ASCII frame is converted to box-drawing characters
+  let lines = ASCII.trim().lines().collect::<Vec<&str>>();
+  assert_eq!(UNICODE.trim(), frame_from_ascii(&lines).join("\n"));
+}
+
+#[test]
+fn _0002() {
+  // box-drawing characters are converted to ASCII frame
+  assert_eq!(ASCII, frame_to_ascii(UNICODE));
+}
+
+#[test]
+fn _0003() {
+  // ASCII frame is converted when loaded and converted back when saved, surrounding text is preserved
+  let text = format!("Service level agreement{ASCII}\n- end -\n");
+  let controller = Controller::new(&text);
+  assert_eq!(UNICODE.trim(), controller.content().iter().map(|row| row.text()).collect::<Vec<String>>().join("\n"));
+  assert_eq!(text, controller.text());
+}
+
+#[test]
+fn _0004() {
+  // decision table with ASCII frame is parsed
+  let decision_table = ASCII.parse::<DecisionTable>().unwrap();
+  assert_eq!(HitPolicy::Unique, decision_table.hit_policy());
+  assert_eq!(4, decision_table.rules().len());
+  assert_eq!(UNICODE.trim(), decision_table.to_string());
+}
+
+#[test]
+fn _0005() {
+  // characters inside cells are not converted
+  let lines = ["+----+-----+", "| C+ | a-b |", "+====+=====+", "| 1  | |-| |", "+----+-----+"];
+  let expected = ["┌────┬─────┐", "│ C+ │ a-b │", "╞════╪═════╡", "│ 1  │ |-| │", "└────┴─────┘"];
+  assert_eq!(expected.to_vec(), frame_from_ascii(&lines));
+}
+
+#[test]
+fn _0006() {
+  // only decision tables are saved with ASCII frames, box-drawing characters in the surrounding text are preserved
+  let text = format!("Frames like ┌─┐ are converted{UNICODE}\n");
+  let controller = Controller::new(&text).with_ascii(true);
+  assert_eq!(format!("Frames like ┌─┐ are converted{ASCII}\n"), controller.text());
+}
+
+#[test]
+fn _0007() {
+  // edited decision table framed with ASCII characters is saved with ASCII frames
+  let mut controller = Controller::new(ASCII);
+  controller.insert_char('F');
+  assert_eq!(ASCII.replace("|  SLA  |", "|F  SLA |"), controller.text());
+}
+
+#[test]
+fn _0008() {
+  // decision tables framed with box-drawing and ASCII characters in the same file are both detected
+  let text = format!("First{UNICODE}\nSecond{ASCII}\n");
+  let mut controller = Controller::new(&text);
+  assert_eq!(2, controller.table_count());
+  controller.cursor_move_table_next();
+  controller.insert_char('F');
+  assert_eq!(format!("First{UNICODE}\nSecond{}\n", ASCII.replace("|  SLA  |", "|F  SLA |")), controller.text());
+}
+
+#[test]
+fn _0009() {
+  // lines starting with a crossing that are not ASCII borders do not start a decision table
+  let text = format!("+ item\n+ other item\n+--- end\n{ASCII}");
+  let controller = Controller::new(&text);
+  assert_eq!(1, controller.table_count());
+  assert_eq!(UNICODE.trim(), controller.content().iter().map(|row| row.text()).collect::<Vec<String>>().join("\n"));
+  assert_eq!(text, controller.text());
+}
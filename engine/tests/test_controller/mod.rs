@@ -1,15 +1,29 @@
+mod test_align;
 mod test_attributes;
+mod test_clipboard;
+mod test_columns;
+mod test_crosstab;
 mod test_cursor_move;
 mod test_cursor_shape;
 mod test_delete_before_cursor;
 mod test_delete_under_cursor;
 mod test_insert_char;
 mod test_new;
+mod test_normalize;
 mod test_override_char;
+mod test_reformat;
+mod test_reorder;
 mod test_resize;
+mod test_rules;
+mod test_selection;
 mod test_split_line;
+mod test_tables;
+mod test_text;
+mod test_undo_redo;
 mod test_unsplit_line;
+mod test_vertical;
 mod test_viewport_move;
+mod test_wide_chars;
 
 use super::test_files::*;
 use super::Action::*;
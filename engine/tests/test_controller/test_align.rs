@@ -0,0 +1,248 @@
+use super::*;
+use dtee::Alignment::*;
+
+#[test]
+fn _0001() {
+  // align the cell pointed by the cursor
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴─────────────┬───────────────╥─────┐
+    │ U │ YearsAsCustomer │ NumberOfUnits ║ SLA │
+    │   ├─────────────────┼───────────────╫─────┤
+    │   │    [0..100]     │ [0..1000000]  ║ 1,2 │
+    ╞═══╪═════════════════╪═══════════════╬═════╡
+    │ 1 │ <2              │    <1000      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 2 │       <2        │   >=1000      ║  2  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 3 │      >=2        │     <500      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 4 │      >=2        │    >=500      ║  2  │
+    └───┴─────────────────┴───────────────╨─────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(4), CellNext(1), MoveRight(7), AssertPos(12, 7), Align(Left), AssertPos(6, 7)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0002() {
+  // align all selected cells
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴─────────────┬───────────────╥─────┐
+    │ U │ YearsAsCustomer │ NumberOfUnits ║ SLA │
+    │   ├─────────────────┼───────────────╫─────┤
+    │   │    [0..100]     │ [0..1000000]  ║ 1,2 │
+    ╞═══╪═════════════════╪═══════════════╬═════╡
+    │ 1 │              <2 │         <1000 ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 2 │              <2 │        >=1000 ║  2  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 3 │             >=2 │          <500 ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 4 │      >=2        │    >=500      ║  2  │
+    └───┴─────────────────┴───────────────╨─────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(4), CellNext(1), SelectDown(2), SelectRight(20), Align(Right)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0003() {
+  // align the whole column, the alignment is not kept while typing
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴─────────────┬───────────────╥─────┐
+    │ U │ YearsAsCustomer │ NumberOfUnits ║ SLA │
+    │   ├─────────────────┼───────────────╫─────┤
+    │   │    [0..100]     │ [0..1000000]  ║ 1,2 │
+    ╞═══╪═════════════════╪═══════════════╬═════╡
+    │ 1 │       <2        │ <1000         ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 2 │       <2        │ >=1000        ║  2  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 3 │      >=2        │ <5000         ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 4 │      >=2        │ >=500         ║  2  │
+    └───┴─────────────────┴───────────────╨─────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(
+    &mut controller,
+    &[MoveDown(6), CellNext(2), AlignColumn(Left, false), AssertPos(23, 11), MoveRight(5), Insert('0', 1)],
+  );
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0004() {
+  // numbers stay right-aligned while typing and deleting characters
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴─────────────┬──────────────╥─────┐
+    │ U │ YearsAsCustomer │NumberOfUnits ║ SLA │
+    │   ├─────────────────┼──────────────╫─────┤
+    │   │    [0..100]     │ [0..1000000] ║ 1,2 │
+    ╞═══╪═════════════════╪══════════════╬═════╡
+    │ 1 │       <2        │        <1000 ║  1  │
+    ├───┼─────────────────┼──────────────╫─────┤
+    │ 2 │       <2        │       >=1000 ║  2  │
+    ├───┼─────────────────┼──────────────╫─────┤
+    │ 3 │      >=2        │       <50000 ║  1  │
+    ├───┼─────────────────┼──────────────╫─────┤
+    │ 4 │      >=2        │         >=50 ║  2  │
+    └───┴─────────────────┴──────────────╨─────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(
+    &mut controller,
+    &[
+      MoveDown(6),
+      CellNext(2),
+      AlignColumn(Right, true),
+      AssertPos(28, 11),
+      MoveRight(9),
+      Insert('0', 2),
+      AssertPos(37, 11),
+      MoveDown(1),
+      DeleteBefore(1),
+      AssertPos(36, 13),
+    ],
+  );
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0005() {
+  // the kept alignment is forgotten after aligning the column without keeping
+  let mut controller = Controller::new(INPUT_0001);
+  actions(
+    &mut controller,
+    &[MoveDown(4), CellNext(1), AlignColumn(Center, true), AlignColumn(Left, false), MoveRight(3), Insert('0', 1)],
+  );
+  assert!(text(&controller).contains("│ 1 │ <20             │"));
+}
+
+#[test]
+fn _0006() {
+  // kept alignment moves together with the column
+  let mut controller = Controller::new(INPUT_0001);
+  actions(
+    &mut controller,
+    &[
+      MoveDown(4),
+      CellNext(2),
+      AlignColumn(Right, true),
+      MoveColumnLeft(1),
+      CellEnd(1),
+      MoveLeft(1),
+      Insert('0', 1),
+    ],
+  );
+  assert!(text(&controller).contains("│ 1 │        <10000 │       <2        ║"));
+}
+
+#[test]
+fn _0007() {
+  // cells spanning several columns do not belong to any column
+  let mut controller = Controller::new(INPUT_0001);
+  assert!(!controller.align_column(Left, true).content_changed());
+  assert!(controller.align(Left).content_changed());
+  assert!(text(&controller).contains("│ SLA   │"));
+}
+
+#[test]
+fn _0008() {
+  // aligning is undone in a single step
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(4), CellNext(1), AlignColumn(Right, false), Undo(1)]);
+  assert_eq!(text(&Controller::new(INPUT_0001)), text(&controller));
+}
+
+#[test]
+fn _0009() {
+  // kept alignment is restored when inserting a column is undone
+  let mut controller = Controller::new(INPUT_0001);
+  actions(
+    &mut controller,
+    &[
+      MoveDown(4),
+      CellNext(3),
+      AlignColumn(Right, true),
+      InsertInputColumn(1),
+      Undo(1),
+      CellEnd(1),
+      MoveLeft(1),
+      Insert('0', 1),
+    ],
+  );
+  assert!(text(&controller).contains("│ 1 │       <2        │    <1000      ║  10 │"));
+}
+
+#[test]
+fn _0010() {
+  // kept alignment is restored when deleting a column is undone
+  let mut controller = Controller::new(INPUT_0001);
+  actions(
+    &mut controller,
+    &[
+      MoveDown(4),
+      CellNext(3),
+      AlignColumn(Right, true),
+      CellPrev(2),
+      DeleteColumn(1),
+      Undo(1),
+      CellNext(2),
+      CellEnd(1),
+      MoveLeft(1),
+      Insert('0', 1),
+    ],
+  );
+  assert!(text(&controller).contains("│ 1 │       <2        │    <1000      ║  10 │"));
+}
+
+#[test]
+fn _0011() {
+  // kept alignment is restored when moving a column is undone
+  let mut controller = Controller::new(INPUT_0001);
+  actions(
+    &mut controller,
+    &[
+      MoveDown(4),
+      CellNext(2),
+      AlignColumn(Right, true),
+      MoveColumnLeft(1),
+      Undo(1),
+      CellEnd(1),
+      MoveLeft(1),
+      Insert('0', 1),
+    ],
+  );
+  assert!(text(&controller).contains("│ 1 │       <2        │        <10000 ║"));
+}
+
+#[test]
+fn _0012() {
+  // kept alignment is restored when transposing is undone
+  let mut controller = Controller::new(INPUT_0001);
+  actions(
+    &mut controller,
+    &[
+      MoveDown(4),
+      CellNext(2),
+      AlignColumn(Right, true),
+      Transpose,
+      Undo(1),
+      CellEnd(1),
+      MoveLeft(1),
+      Insert('0', 1),
+    ],
+  );
+  assert!(text(&controller).contains("│ 1 │       <2        │        <10000 ║"));
+}
@@ -0,0 +1,135 @@
+use super::*;
+use dtee::{Clipboard, Controller};
+
+#[test]
+fn _0001() {
+  // nothing is copied without selection
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[Copy, Cut, Paste(1)]);
+  assert_eq!(None, controller.clipboard());
+  assert_eq!(INPUT_0001, controller.text());
+}
+
+#[test]
+fn _0002() {
+  // copying and pasting text inside a cell
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴─────────────┬───────────────╥─────┐
+    │ U │ YearsAsCustomer │ NumberOfUnits ║ SLA │
+    │   ├─────────────────┼───────────────╫─────┤
+    │   │    [0..100]     │ [0..1000000]  ║ 1,2 │
+    ╞═══╪═════════════════╪═══════════════╬═════╡
+    │ 1 │       <2        │    <1000      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 2 │       <2        │   >=1000      ║  2  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 3 │      >=2        │     <500      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 4 │      >=2        │    >=500>=1000║  2  │
+    └───┴─────────────────┴───────────────╨─────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(5), CellNext(2), AssertPos(23, 9), MoveRight(3), SelectRight(6), Copy]);
+  assert_eq!(Some(&Clipboard::Text(vec![">=1000".to_string()])), controller.clipboard());
+  actions(&mut controller, &[MoveDown(2), Paste(1)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0003() {
+  // cutting text inside a cell
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴─────────────┬──────────────╥─────┐
+    │ U │ YearsAsCustomer │ NumberOfUnits║ SLA │
+    │   ├─────────────────┼──────────────╫─────┤
+    │   │    [0..100]     │ [0..1000000] ║ 1,2 │
+    ╞═══╪═════════════════╪══════════════╬═════╡
+    │ 1 │       <2        │    <1000     ║  1  │
+    ├───┼─────────────────┼──────────────╫─────┤
+    │ 2 │       <2        │              ║  2  │
+    ├───┼─────────────────┼──────────────╫─────┤
+    │ 3 │      >=2        │     <500     ║  1  │
+    ├───┼─────────────────┼──────────────╫─────┤
+    │ 4 │      >=2        │    >=500     ║  2  │
+    └───┴─────────────────┴──────────────╨─────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(5), CellNext(2), MoveRight(3), SelectRight(6), Cut, AssertPos(26, 9)]);
+  assert_eq!(Some(&Clipboard::Text(vec![">=1000".to_string()])), controller.clipboard());
+  assert_eq!(None, controller.selection());
+  assert_eq!(expected, text(&controller));
+  actions(&mut controller, &[Undo(1)]);
+  assert_eq!(INPUT_0001, controller.text());
+}
+
+#[test]
+fn _0004() {
+  // copying and pasting a block of cells
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴─────────────┬───────────────╥─────┐
+    │ U │ YearsAsCustomer │ NumberOfUnits ║ SLA │
+    │   ├─────────────────┼───────────────╫─────┤
+    │   │    [0..100]     │ [0..1000000]  ║ 1,2 │
+    ╞═══╪═════════════════╪═══════════════╬═════╡
+    │ 1 │       <2        │    <1000      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 2 │       <2        │   >=1000      ║  2  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 3 │       <2        │    <1000      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 4 │       <2        │   >=1000      ║  2  │
+    └───┴─────────────────┴───────────────╨─────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(4), CellNext(1), AssertPos(5, 7), SelectDown(1), SelectRight(20), Copy]);
+  assert_eq!(
+    Some(&Clipboard::Block(vec![
+      vec![vec!["       <2".to_string()], vec!["    <1000".to_string()]],
+      vec![vec!["       <2".to_string()], vec!["   >=1000".to_string()]]
+    ])),
+    controller.clipboard()
+  );
+  actions(&mut controller, &[MoveDown(1), CellPrev(1), AssertPos(22, 11), Paste(1)]);
+  assert_eq!(expected, text(&controller));
+  actions(&mut controller, &[Undo(1)]);
+  assert_eq!(INPUT_0001, controller.text());
+}
+
+#[test]
+fn _0005() {
+  // pasting a block of cells splits lines when needed
+  let expected = r#"
+    ┌─────────────────┐
+    │  Order options  │
+    ├───┬───────────┬─┴─────╥─────────────────────╥─────────────┬───────────┐
+    │ U │           │       ║    Order options    ║             │           │
+    │   │ Customer  │ Order ╟──────────┬──────────╢ Description │ Reference │
+    │   │   type    │ size  ║ Discount │ Priority ║             │           │
+    │   ├───────────┼───────╫──────────┼──────────╫─────────────┼───────────┤
+    │   │"Business",│       ║          │"Normal", ║             │           │
+    │   │"Private"  │       ║          │ "High",  ║             │           │
+    │   │           │       ║          │ "Low"    ║             │           │
+    ╞═══╪═══════════╪═══════╬══════════╪══════════╬═════════════╪═══════════╡
+    │ 1 │"Business",│       ║   0.10   │ "Normal" ║ Small order │   Ref 1   │
+    │   │"Private"  │       ║          │          ║             │           │
+    ├───┼───────────┼───────╫──────────┼──────────╫─────────────┼───────────┤
+    │ 2 │"Business" │ >=10  ║   0.15   │  "High"  ║ Large order │   Ref 2   │
+    ├───┼───────────┼───────╫──────────┼──────────╫─────────────┼───────────┤
+    │ 3 │"Private"  │   -   ║   0.05   │  "Low"   ║ All orders  │   Ref 3   │
+    └───┴───────────┴───────╨──────────┴──────────╨─────────────┴───────────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0002);
+  actions(&mut controller, &[MoveDown(5), CellNext(1), AssertPos(5, 7), SelectRight(13), Copy]);
+  assert_eq!(
+    Some(&Clipboard::Block(vec![vec![vec!["\"Business\",".to_string(), "\"Private\"".to_string()], vec![]]])),
+    controller.clipboard()
+  );
+  actions(&mut controller, &[MoveDown(3), CellPrev(1), AssertPos(16, 11), Paste(1)]);
+  assert_eq!(expected, text(&controller));
+}
@@ -0,0 +1,231 @@
+use super::*;
+use dtee::Controller;
+
+#[test]
+fn _0001() {
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴─────────────┬───────────────┬─────╥─────┐
+    │ U │ YearsAsCustomer │ NumberOfUnits │     ║ SLA │
+    │   ├─────────────────┼───────────────┼─────╫─────┤
+    │   │    [0..100]     │ [0..1000000]  │     ║ 1,2 │
+    ╞═══╪═════════════════╪═══════════════╪═════╬═════╡
+    │ 1 │       <2        │    <1000      │     ║  1  │
+    ├───┼─────────────────┼───────────────┼─────╫─────┤
+    │ 2 │       <2        │   >=1000      │     ║  2  │
+    ├───┼─────────────────┼───────────────┼─────╫─────┤
+    │ 3 │      >=2        │     <500      │     ║  1  │
+    ├───┼─────────────────┼───────────────┼─────╫─────┤
+    │ 4 │      >=2        │    >=500      │     ║  2  │
+    └───┴─────────────────┴───────────────┴─────╨─────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(4), AssertPos(1, 7), InsertInputColumn(1), AssertPos(40, 7)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0002() {
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴─────────────┬───────────────╥─────┬─────┐
+    │ U │ YearsAsCustomer │ NumberOfUnits ║     │ SLA │
+    │   ├─────────────────┼───────────────╫─────┼─────┤
+    │   │    [0..100]     │ [0..1000000]  ║     │ 1,2 │
+    ╞═══╪═════════════════╪═══════════════╬═════╪═════╡
+    │ 1 │       <2        │    <1000      ║     │  1  │
+    ├───┼─────────────────┼───────────────╫─────┼─────┤
+    │ 2 │       <2        │   >=1000      ║     │  2  │
+    ├───┼─────────────────┼───────────────╫─────┼─────┤
+    │ 3 │      >=2        │     <500      ║     │  1  │
+    ├───┼─────────────────┼───────────────╫─────┼─────┤
+    │ 4 │      >=2        │    >=500      ║     │  2  │
+    └───┴─────────────────┴───────────────╨─────┴─────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(4), InsertOutputColumn(1), AssertPos(40, 7)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0003() {
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴─────────────┬───────────────╥─────╥─────┬─────┐
+    │ U │ YearsAsCustomer │ NumberOfUnits ║ SLA ║     │     │
+    │   ├─────────────────┼───────────────╫─────╫─────┼─────┤
+    │   │    [0..100]     │ [0..1000000]  ║ 1,2 ║     │     │
+    ╞═══╪═════════════════╪═══════════════╬═════╬═════╪═════╡
+    │ 1 │       <2        │    <1000      ║  1  ║     │     │
+    ├───┼─────────────────┼───────────────╫─────╫─────┼─────┤
+    │ 2 │       <2        │   >=1000      ║  2  ║     │     │
+    ├───┼─────────────────┼───────────────╫─────╫─────┼─────┤
+    │ 3 │      >=2        │     <500      ║  1  ║     │     │
+    ├───┼─────────────────┼───────────────╫─────╫─────┼─────┤
+    │ 4 │      >=2        │    >=500      ║  2  ║     │     │
+    └───┴─────────────────┴───────────────╨─────╨─────┴─────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(4), InsertAnnotationColumn(2), AssertPos(52, 7)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0004() {
+  let expected = r#"
+    ┌─────────────────┐
+    │  Order options  │
+    ├───┬───────────┬─┴─────╥─────┬─────────────────────╥─────────────┬───────────┐
+    │ U │           │       ║     │    Order options    ║             │           │
+    │   │ Customer  │ Order ║     ├──────────┬──────────╢ Description │ Reference │
+    │   │   type    │ size  ║     │ Discount │ Priority ║             │           │
+    │   ├───────────┼───────╫─────┼──────────┼──────────╫─────────────┼───────────┤
+    │   │"Business",│       ║     │          │"Normal", ║             │           │
+    │   │"Private"  │       ║     │          │ "High",  ║             │           │
+    │   │           │       ║     │          │ "Low"    ║             │           │
+    ╞═══╪═══════════╪═══════╬═════╪══════════╪══════════╬═════════════╪═══════════╡
+    │ 1 │"Business" │  <10  ║     │   0.10   │ "Normal" ║ Small order │   Ref 1   │
+    ├───┼───────────┼───────╫─────┼──────────┼──────────╫─────────────┼───────────┤
+    │ 2 │"Business" │ >=10  ║     │   0.15   │  "High"  ║ Large order │   Ref 2   │
+    ├───┼───────────┼───────╫─────┼──────────┼──────────╫─────────────┼───────────┤
+    │ 3 │"Private"  │   -   ║     │   0.05   │  "Low"   ║ All orders  │   Ref 3   │
+    └───┴───────────┴───────╨─────┴──────────┴──────────╨─────────────┴───────────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0002);
+  actions(&mut controller, &[MoveDown(4), InsertOutputColumn(1), AssertPos(26, 6)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0005() {
+  let expected = r#"
+    ┌─────────────────┐
+    │  Order options  │
+    ├───┬───────╥─────┴───────────────╥─────────────┬───────────┐
+    │ U │       ║    Order options    ║             │           │
+    │   │ Order ╟──────────┬──────────╢ Description │ Reference │
+    │   │ size  ║ Discount │ Priority ║             │           │
+    │   ├───────╫──────────┼──────────╫─────────────┼───────────┤
+    │   │       ║          │"Normal", ║             │           │
+    │   │       ║          │ "High",  ║             │           │
+    │   │       ║          │ "Low"    ║             │           │
+    ╞═══╪═══════╬══════════╪══════════╬═════════════╪═══════════╡
+    │ 1 │  <10  ║   0.10   │ "Normal" ║ Small order │   Ref 1   │
+    ├───┼───────╫──────────┼──────────╫─────────────┼───────────┤
+    │ 2 │ >=10  ║   0.15   │  "High"  ║ Large order │   Ref 2   │
+    ├───┼───────╫──────────┼──────────╫─────────────┼───────────┤
+    │ 3 │   -   ║   0.05   │  "Low"   ║ All orders  │   Ref 3   │
+    └───┴───────╨──────────┴──────────╨─────────────┴───────────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0002);
+  actions(&mut controller, &[MoveDown(8), CellNext(1), AssertPos(5, 11), DeleteColumn(1), AssertPos(5, 11)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0006() {
+  let expected = r#"
+    ┌─────────────────┐
+    │  Order options  │
+    ├───┬───────────┬─┴─────╥─────────────────────╥───────────┐
+    │ U │           │       ║    Order options    ║           │
+    │   │ Customer  │ Order ╟──────────┬──────────╢ Reference │
+    │   │   type    │ size  ║ Discount │ Priority ║           │
+    │   ├───────────┼───────╫──────────┼──────────╫───────────┤
+    │   │"Business",│       ║          │"Normal", ║           │
+    │   │"Private"  │       ║          │ "High",  ║           │
+    │   │           │       ║          │ "Low"    ║           │
+    ╞═══╪═══════════╪═══════╬══════════╪══════════╬═══════════╡
+    │ 1 │"Business" │  <10  ║   0.10   │ "Normal" ║   Ref 1   │
+    ├───┼───────────┼───────╫──────────┼──────────╫───────────┤
+    │ 2 │"Business" │ >=10  ║   0.15   │  "High"  ║   Ref 2   │
+    ├───┼───────────┼───────╫──────────┼──────────╫───────────┤
+    │ 3 │"Private"  │   -   ║   0.05   │  "Low"   ║   Ref 3   │
+    └───┴───────────┴───────╨──────────┴──────────╨───────────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0002);
+  actions(&mut controller, &[MoveDown(8), CellNext(5), DeleteColumn(1), AssertPos(47, 11)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0007() {
+  let expected = r#"
+    ┌─────────────────┐
+    │  Order options  │
+    ├───┬───────────┬─┴─────╥─────────────────────╥─────────────┐
+    │ U │           │       ║    Order options    ║             │
+    │   │ Customer  │ Order ╟──────────┬──────────╢ Description │
+    │   │   type    │ size  ║ Discount │ Priority ║             │
+    │   ├───────────┼───────╫──────────┼──────────╫─────────────┤
+    │   │"Business",│       ║          │"Normal", ║             │
+    │   │"Private"  │       ║          │ "High",  ║             │
+    │   │           │       ║          │ "Low"    ║             │
+    ╞═══╪═══════════╪═══════╬══════════╪══════════╬═════════════╡
+    │ 1 │"Business" │  <10  ║   0.10   │ "Normal" ║ Small order │
+    ├───┼───────────┼───────╫──────────┼──────────╫─────────────┤
+    │ 2 │"Business" │ >=10  ║   0.15   │  "High"  ║ Large order │
+    ├───┼───────────┼───────╫──────────┼──────────╫─────────────┤
+    │ 3 │"Private"  │   -   ║   0.05   │  "Low"   ║ All orders  │
+    └───┴───────────┴───────╨──────────┴──────────╨─────────────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0002);
+  actions(&mut controller, &[MoveDown(8), CellNext(6), DeleteColumn(1), AssertPos(59, 11)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0008() {
+  let expected = r#"
+    ┌───────────────┐
+    │ Wide name     │
+    ├───┬───────╥───┤
+    │ U │ In 2  ║ O │
+    ╞═══╪═══════╬═══╡
+    │ 1 │ 2     ║ 3 │
+    └───┴───────╨───┘
+  "#;
+  let mut controller = Controller::new(INPUT_0006);
+  actions(&mut controller, &[MoveDown(2), CellNext(1), DeleteColumn(1), AssertPos(5, 5)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0009() {
+  // single column of the compound output can not be deleted
+  let mut controller = Controller::new(INPUT_0002);
+  actions(&mut controller, &[MoveDown(8), CellNext(3), DeleteColumn(1), AssertPos(25, 11)]);
+  assert_eq!(INPUT_0002, controller.text());
+}
+
+#[test]
+fn _0010() {
+  // the hit policy column and the only output column can not be deleted
+  let mut controller = Controller::new(INPUT_0001);
+  actions(
+    &mut controller,
+    &[MoveDown(4), DeleteColumn(1), AssertPos(1, 7), CellNext(3), DeleteColumn(1), AssertPos(39, 7)],
+  );
+  assert_eq!(INPUT_0001, controller.text());
+}
+
+#[test]
+fn _0011() {
+  // the only input column can not be deleted
+  let mut controller = Controller::new(INPUT_0006);
+  actions(&mut controller, &[MoveDown(2), CellNext(1), DeleteColumn(2), AssertPos(5, 5)]);
+  assert_eq!("│ U │ In 2  ║ O │", controller.content()[3].text());
+}
+
+#[test]
+fn _0012() {
+  let mut controller = Controller::new(INPUT_0002);
+  actions(
+    &mut controller,
+    &[MoveDown(8), InsertInputColumn(1), InsertAnnotationColumn(1), CellPrev(1), DeleteColumn(1), Undo(3)],
+  );
+  assert_eq!(INPUT_0002, controller.text());
+}
@@ -0,0 +1,117 @@
+use super::*;
+use dtee::Controller;
+
+#[test]
+fn _0001() {
+  // cursor moves between rows of values
+  let mut controller = Controller::new(INPUT_0007);
+  actions(&mut controller, &[RuleNext(1), AssertPos(1, 7), RuleNext(1), AssertPos(1, 9), RuleNext(1), AssertPos(1, 9)]);
+  actions(&mut controller, &[MoveRight(8), RulePrev(1), AssertPos(9, 7), RulePrev(1), AssertPos(9, 7)]);
+}
+
+#[test]
+fn _0002() {
+  // row of values is inserted below the current row
+  let expected = r#"
+    ┌────────────┐
+    │  Discount  │
+    ├────────────┴─╥────────────────────────┐
+    │   Discount   ║        Customer        │
+    │              ╟────────────┬───────────┤
+    │              ║ "Business" │ "Private" │
+    ╞═══════╤══════╬════════════╪═══════════╡
+    │ Order │ <10  ║ 0.10       │ 0.05      │
+    │ size  ├──────╫────────────┼───────────┤
+    │       │ >=10 ║ 0.15       │ 0.05      │
+    │       ├──────╫────────────┼───────────┤
+    │       │      ║            │           │
+    └───────┴──────╨────────────┴───────────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0007);
+  actions(&mut controller, &[RuleNext(2), MoveRight(8), InsertRuleBelow(1), AssertPos(9, 11)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0003() {
+  // row of values is inserted above the current row, the expression is kept on top
+  let expected = r#"
+    ┌────────────┐
+    │  Discount  │
+    ├────────────┴─╥────────────────────────┐
+    │   Discount   ║        Customer        │
+    │              ╟────────────┬───────────┤
+    │              ║ "Business" │ "Private" │
+    ╞═══════╤══════╬════════════╪═══════════╡
+    │ Order │      ║            │           │
+    │ size  ├──────╫────────────┼───────────┤
+    │       │ <10  ║ 0.10       │ 0.05      │
+    │       ├──────╫────────────┼───────────┤
+    │       │ >=10 ║ 0.15       │ 0.05      │
+    └───────┴──────╨────────────┴───────────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0007);
+  actions(&mut controller, &[RuleNext(1), MoveRight(8), InsertRuleAbove(1), AssertPos(9, 7)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0004() {
+  // new column of values is inserted on the right side, then on the left side of the current column
+  let expected = r#"
+    ┌────────────┐
+    │  Discount  │
+    ├────────────┴─╥────────────────────────────────────┐
+    │   Discount   ║        Customer                    │
+    │              ╟────────────┬─────┬─────┬───────────┤
+    │              ║ "Business" │     │     │ "Private" │
+    ╞═══════╤══════╬════════════╪═════╪═════╪═══════════╡
+    │ Order │ <10  ║ 0.10       │     │     │ 0.05      │
+    │ size  ├──────╫────────────┼─────┼─────┼───────────┤
+    │       │ >=10 ║ 0.15       │     │     │ 0.05      │
+    └───────┴──────╨────────────┴─────┴─────┴───────────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0007);
+  actions(
+    &mut controller,
+    &[RuleNext(1), MoveRight(16), InsertOutputColumn(1), AssertPos(30, 7), InsertInputColumn(1), AssertPos(30, 7)],
+  );
+  assert_eq!(expected, text(&controller));
+  let decision_table = controller.decision_table().unwrap();
+  assert_eq!(8, decision_table.rules().len());
+}
+
+#[test]
+fn _0005() {
+  // rules and columns are not deleted or moved in crosstabs
+  let mut controller = Controller::new(INPUT_0007);
+  actions(
+    &mut controller,
+    &[RuleNext(1), MoveRight(16), DeleteRule(1), MoveRuleDown(1), DeleteColumn(1), MoveColumnRight(1)],
+  );
+  assert_eq!(text(&Controller::new(INPUT_0007)), text(&controller));
+}
+
+#[test]
+fn _0006() {
+  // crosstab is transposed to horizontal decision table
+  let expected = r#"
+    ┌────────────┐
+    │  Discount  │
+    ├───┬───────┬┴───────────╥──────────┐
+    │ U │ Order │  Customer  ║ Discount │
+    │   │ size  │            ║          │
+    ╞═══╪═══════╪════════════╬══════════╡
+    │ 1 │ <10   │ "Business" ║ 0.10     │
+    ├───┼───────┼────────────╫──────────┤
+    │ 2 │ <10   │ "Private"  ║ 0.05     │
+    ├───┼───────┼────────────╫──────────┤
+    │ 3 │ >=10  │ "Business" ║ 0.15     │
+    ├───┼───────┼────────────╫──────────┤
+    │ 4 │ >=10  │ "Private"  ║ 0.05     │
+    └───┴───────┴────────────╨──────────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0007);
+  actions(&mut controller, &[Transpose, AssertPos(1, 1)]);
+  assert_eq!(expected, text(&controller));
+}
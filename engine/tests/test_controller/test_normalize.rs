@@ -0,0 +1,94 @@
+use super::*;
+use dtee::Controller;
+
+/// Returns the decision table with heavy lines and rounded corners.
+fn heavy(text: &str) -> String {
+  text
+    .chars()
+    .map(|ch| match ch {
+      '─' => '━',
+      '│' => '┃',
+      '┌' => '╭',
+      '┐' => '╮',
+      '└' => '╰',
+      '┘' => '╯',
+      '├' => '┣',
+      '┤' => '┫',
+      '┬' => '┳',
+      '┴' => '┻',
+      '┼' => '╋',
+      other => other,
+    })
+    .collect()
+}
+
+#[test]
+fn _0001() {
+  // heavy and rounded frame is normalized to light lines
+  let mut controller = Controller::new(heavy(INPUT_0001));
+  actions(&mut controller, &[Normalize]);
+  assert_eq!(text(&Controller::new(INPUT_0001)), text(&controller));
+}
+
+#[test]
+fn _0002() {
+  // cursor moves between cells framed with heavy lines
+  let mut controller = Controller::new(heavy(INPUT_0001));
+  actions(
+    &mut controller,
+    &[MoveDown(5), CellNext(1), AssertPos(5, 9), CellNext(2), AssertPos(39, 9), RuleNext(1), AssertPos(39, 11)],
+  );
+}
+
+#[test]
+fn _0003() {
+  // rule is inserted into the decision table framed with heavy lines
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴─────────────┬───────────────╥─────┐
+    │ U │ YearsAsCustomer │ NumberOfUnits ║ SLA │
+    │   ├─────────────────┼───────────────╫─────┤
+    │   │    [0..100]     │ [0..1000000]  ║ 1,2 │
+    ╞═══╪═════════════════╪═══════════════╬═════╡
+    │ 1 │       <2        │    <1000      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 2 │       <2        │   >=1000      ║  2  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 3 │                 │               ║     │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 4 │      >=2        │     <500      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 5 │      >=2        │    >=500      ║  2  │
+    └───┴─────────────────┴───────────────╨─────┘
+  "#;
+  let mut controller = Controller::new(heavy(INPUT_0001));
+  actions(&mut controller, &[MoveDown(5), CellNext(1), InsertRuleBelow(1), AssertPos(5, 11), Normalize]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0004() {
+  // decision table framed with heavy lines is parsed
+  let controller = Controller::new(heavy(INPUT_0002));
+  let decision_table = controller.decision_table().unwrap();
+  assert_eq!(INPUT_0002.parse::<dtee::DecisionTable>().unwrap().to_string(), decision_table.to_string());
+}
+
+#[test]
+fn _0005() {
+  // characters are typed into cells framed with heavy lines like into cells framed with light lines
+  let typing = [MoveDown(5), CellNext(1), InsertStr("1".into(), 1), AssertPos(6, 9), CellNext(1), InsertStr("22".into(), 1)];
+  let mut controller = Controller::new(heavy(INPUT_0001));
+  actions(&mut controller, &typing);
+  let mut expected = Controller::new(INPUT_0001);
+  actions(&mut expected, &typing);
+  assert_eq!(heavy(&text(&expected)), text(&controller));
+}
+
+#[test]
+fn _0006() {
+  // normalizing the decision table with canonical frame changes nothing
+  let mut controller = Controller::new(INPUT_0001);
+  assert!(!controller.normalize().content_changed());
+}
@@ -0,0 +1,160 @@
+use super::*;
+use dtee::Alignment::*;
+use dtee::Controller;
+
+#[test]
+fn _0001() {
+  // already formatted decision table is not changed, the alignment of the text is kept
+  let mut controller = Controller::new(INPUT_0001);
+  assert!(!controller.reformat(1).content_changed());
+  assert_eq!(text(&Controller::new(INPUT_0001)), text(&controller));
+}
+
+#[test]
+fn _0002() {
+  // empty lines at the end of cells are removed, the cursor moves to the last remaining line
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(5), CellNext(1), CellEnd(1), SplitLine(2), AssertPos(5, 11)]);
+  assert!(controller.reformat(1).content_changed());
+  actions(&mut controller, &[AssertPos(5, 9)]);
+  assert_eq!(text(&Controller::new(INPUT_0001)), text(&controller));
+}
+
+#[test]
+fn _0003() {
+  // padding is configurable, the text keeps its offset in cells
+  let expected = r#"
+    ┌────┐
+    │ SLA│
+    ├─┬──┴────────────┬─────────────╥───┐
+    │U│YearsAsCustomer│NumberOfUnits║SLA│
+    │ ├───────────────┼─────────────╫───┤
+    │ │   [0..100]    │[0..1000000] ║1,2│
+    ╞═╪═══════════════╪═════════════╬═══╡
+    │1│      <2       │   <1000     ║ 1 │
+    ├─┼───────────────┼─────────────╫───┤
+    │2│      <2       │  >=1000     ║ 2 │
+    ├─┼───────────────┼─────────────╫───┤
+    │3│     >=2       │    <500     ║ 1 │
+    ├─┼───────────────┼─────────────╫───┤
+    │4│     >=2       │   >=500     ║ 2 │
+    └─┴───────────────┴─────────────╨───┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  controller.reformat(0);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0004() {
+  // the joining row below the information item name is kept consistent
+  let expected = r#"
+    ┌──────────────┐
+    │ Wide name    │
+    ├───┬────┬─────┴╥───┐
+    │ U │ In │ In 2 ║ O │
+    ╞═══╪════╪══════╬═══╡
+    │ 1 │ 1  │ 2    ║ 3 │
+    └───┴────┴──────╨───┘
+  "#;
+  let mut controller = Controller::new(INPUT_0006);
+  controller.reformat(1);
+  assert_eq!(expected, text(&controller));
+  assert!(controller.content()[2].is_join() || controller.content()[2].is_full_join());
+}
+
+#[test]
+fn _0005() {
+  // reformatting is undone in a single step
+  let mut controller = Controller::new(INPUT_0001);
+  controller.reformat(2);
+  actions(&mut controller, &[Undo(1)]);
+  assert_eq!(text(&Controller::new(INPUT_0001)), text(&controller));
+}
+
+#[test]
+fn _0006() {
+  // nothing is changed when the decision table can not be parsed
+  let mut controller = Controller::new("┌───┐\n│ a │\n└───┘");
+  assert!(!controller.reformat(1).content_changed());
+  assert_eq!("┌───┐\n│ a │\n└───┘", controller.text());
+}
+
+#[test]
+fn _0007() {
+  // columns are widened when the text is closer to the frame than the padding
+  let expected = r#"
+    ┌──────────┐
+    │   SLA    │
+    ├─────┬────┴──────────────┬─────────────────╥───────┐
+    │  U  │  YearsAsCustomer  │  NumberOfUnits  ║  SLA  │
+    │     ├───────────────────┼─────────────────╫───────┤
+    │     │     [0..100]      │  [0..1000000]   ║  1,2  │
+    ╞═════╪═══════════════════╪═════════════════╬═══════╡
+    │  1  │        <2         │     <1000       ║   1   │
+    ├─────┼───────────────────┼─────────────────╫───────┤
+    │  2  │        <2         │    >=1000       ║   2   │
+    ├─────┼───────────────────┼─────────────────╫───────┤
+    │  3  │       >=2         │      <500       ║   1   │
+    ├─────┼───────────────────┼─────────────────╫───────┤
+    │  4  │       >=2         │     >=500       ║   2   │
+    └─────┴───────────────────┴─────────────────╨───────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  assert!(controller.reformat(2).content_changed());
+  assert_eq!(expected, text(&controller));
+  assert!(!controller.reformat(2).content_changed());
+}
+
+#[test]
+fn _0008() {
+  // centered entries, rule numbers and headers are not rewritten
+  let expected = r#"
+    ┌──────────────┐
+    │ Order options│
+    ├─┬───────────┬┴────╥───────────────────╥───────────┬─────────┐
+    │U│           │     ║   Order options   ║           │         │
+    │ │ Customer  │Order╟─────────┬─────────╢Description│Reference│
+    │ │   type    │size ║Discount │ Priority║           │         │
+    │ ├───────────┼─────╫─────────┼─────────╫───────────┼─────────┤
+    │ │"Business",│     ║         │"Normal",║           │         │
+    │ │"Private"  │     ║         │ "High", ║           │         │
+    │ │           │     ║         │ "Low"   ║           │         │
+    ╞═╪═══════════╪═════╬═════════╪═════════╬═══════════╪═════════╡
+    │1│"Business" │ <10 ║  0.10   │ "Normal"║Small order│  Ref 1  │
+    ├─┼───────────┼─────╫─────────┼─────────╫───────────┼─────────┤
+    │2│"Business" │>=10 ║  0.15   │  "High" ║Large order│  Ref 2  │
+    ├─┼───────────┼─────╫─────────┼─────────╫───────────┼─────────┤
+    │3│"Private"  │  -  ║  0.05   │  "Low"  ║All orders │  Ref 3  │
+    └─┴───────────┴─────╨─────────┴─────────╨───────────┴─────────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0002);
+  controller.reformat(0);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0009() {
+  // kept alignment survives reformatting
+  let expected = r#"
+    ┌────┐
+    │ SLA│
+    ├─┬──┴────────────┬─────────────╥───┐
+    │U│YearsAsCustomer│NumberOfUnits║SLA│
+    │ ├───────────────┼─────────────╫───┤
+    │ │   [0..100]    │ [0..1000000]║1,2│
+    ╞═╪═══════════════╪═════════════╬═══╡
+    │1│      <2       │        <1000║ 1 │
+    ├─┼───────────────┼─────────────╫───┤
+    │2│      <2       │       >=1000║ 2 │
+    ├─┼───────────────┼─────────────╫───┤
+    │3│     >=2       │         <500║ 1 │
+    ├─┼───────────────┼─────────────╫───┤
+    │4│     >=2       │        >=500║ 2 │
+    └─┴───────────────┴─────────────╨───┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(6), CellNext(2), AlignColumn(Right, true)]);
+  controller.reformat(0);
+  assert_eq!(expected, text(&controller));
+}
@@ -0,0 +1,205 @@
+use super::*;
+use dtee::Controller;
+
+#[test]
+fn _0001() {
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴─────────────┬───────────────╥─────┐
+    │ U │ YearsAsCustomer │ NumberOfUnits ║ SLA │
+    │   ├─────────────────┼───────────────╫─────┤
+    │   │    [0..100]     │ [0..1000000]  ║ 1,2 │
+    ╞═══╪═════════════════╪═══════════════╬═════╡
+    │ 1 │       <2        │   >=1000      ║  2  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 2 │       <2        │    <1000      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 3 │      >=2        │     <500      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 4 │      >=2        │    >=500      ║  2  │
+    └───┴─────────────────┴───────────────╨─────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(4), CellNext(1), AssertPos(5, 7), MoveRuleDown(1), AssertPos(5, 9)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0002() {
+  // the first rule can not be moved up
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(4), CellNext(1), MoveRuleUp(1), AssertPos(5, 7)]);
+  assert_eq!(INPUT_0001, controller.text());
+}
+
+#[test]
+fn _0003() {
+  let expected = r#"
+    ┌─────────────────┐
+    │  Order options  │
+    ├───┬───────────┬─┴─────╥─────────────────────╥─────────────┬───────────┐
+    │ U │           │       ║    Order options    ║             │           │
+    │   │ Customer  │ Order ╟──────────┬──────────╢ Description │ Reference │
+    │   │   type    │ size  ║ Discount │ Priority ║             │           │
+    │   ├───────────┼───────╫──────────┼──────────╫─────────────┼───────────┤
+    │   │"Business",│       ║          │"Normal", ║             │           │
+    │   │"Private"  │       ║          │ "High",  ║             │           │
+    │   │           │       ║          │ "Low"    ║             │           │
+    ╞═══╪═══════════╪═══════╬══════════╪══════════╬═════════════╪═══════════╡
+    │ 1 │"Private"  │   -   ║   0.05   │  "Low"   ║ All orders  │   Ref 3   │
+    ├───┼───────────┼───────╫──────────┼──────────╫─────────────┼───────────┤
+    │ 2 │"Business" │  <10  ║   0.10   │ "Normal" ║ Small order │   Ref 1   │
+    ├───┼───────────┼───────╫──────────┼──────────╫─────────────┼───────────┤
+    │ 3 │"Business" │ >=10  ║   0.15   │  "High"  ║ Large order │   Ref 2   │
+    └───┴───────────┴───────╨──────────┴──────────╨─────────────┴───────────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0002);
+  actions(&mut controller, &[MoveDown(8), MoveDown(4), CellNext(3), MoveRuleUp(2), AssertPos(25, 11)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0004() {
+  let expected = r#"
+    ┌─────────────────┐
+    │  Order options  │
+    ├───┬───────┬─────┴─────╥─────────────────────╥─────────────┬───────────┐
+    │ U │       │           ║    Order options    ║             │           │
+    │   │ Order │ Customer  ╟──────────┬──────────╢ Description │ Reference │
+    │   │ size  │   type    ║ Discount │ Priority ║             │           │
+    │   ├───────┼───────────╫──────────┼──────────╫─────────────┼───────────┤
+    │   │       │"Business",║          │"Normal", ║             │           │
+    │   │       │"Private"  ║          │ "High",  ║             │           │
+    │   │       │           ║          │ "Low"    ║             │           │
+    ╞═══╪═══════╪═══════════╬══════════╪══════════╬═════════════╪═══════════╡
+    │ 1 │  <10  │"Business" ║   0.10   │ "Normal" ║ Small order │   Ref 1   │
+    ├───┼───────┼───────────╫──────────┼──────────╫─────────────┼───────────┤
+    │ 2 │ >=10  │"Business" ║   0.15   │  "High"  ║ Large order │   Ref 2   │
+    ├───┼───────┼───────────╫──────────┼──────────╫─────────────┼───────────┤
+    │ 3 │   -   │"Private"  ║   0.05   │  "Low"   ║ All orders  │   Ref 3   │
+    └───┴───────┴───────────╨──────────┴──────────╨─────────────┴───────────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0002);
+  actions(&mut controller, &[MoveDown(8), CellNext(1), MoveColumnRight(1), AssertPos(13, 11)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0005() {
+  let expected = r#"
+    ┌─────────────────┐
+    │  Order options  │
+    ├───┬───────┬─────┴─────╥─────────────────────╥─────────────┬───────────┐
+    │ U │       │           ║    Order options    ║             │           │
+    │   │ Order │ Customer  ╟──────────┬──────────╢ Description │ Reference │
+    │   │ size  │   type    ║ Discount │ Priority ║             │           │
+    │   ├───────┼───────────╫──────────┼──────────╫─────────────┼───────────┤
+    │   │       │"Business",║          │"Normal", ║             │           │
+    │   │       │"Private"  ║          │ "High",  ║             │           │
+    │   │       │           ║          │ "Low"    ║             │           │
+    ╞═══╪═══════╪═══════════╬══════════╪══════════╬═════════════╪═══════════╡
+    │ 1 │  <10  │"Business" ║   0.10   │ "Normal" ║ Small order │   Ref 1   │
+    ├───┼───────┼───────────╫──────────┼──────────╫─────────────┼───────────┤
+    │ 2 │ >=10  │"Business" ║   0.15   │  "High"  ║ Large order │   Ref 2   │
+    ├───┼───────┼───────────╫──────────┼──────────╫─────────────┼───────────┤
+    │ 3 │   -   │"Private"  ║   0.05   │  "Low"   ║ All orders  │   Ref 3   │
+    └───┴───────┴───────────╨──────────┴──────────╨─────────────┴───────────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0002);
+  actions(&mut controller, &[MoveDown(8), CellNext(2), MoveColumnLeft(1), AssertPos(5, 11)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0006() {
+  // the column can not be moved out of its section
+  let mut controller = Controller::new(INPUT_0002);
+  actions(&mut controller, &[MoveDown(8), CellNext(2), MoveColumnRight(1), AssertPos(17, 11)]);
+  assert_eq!(INPUT_0002, controller.text());
+}
+
+#[test]
+fn _0007() {
+  let expected = r#"
+    ┌─────────────────┐
+    │  Order options  │
+    ├───┬───────────┬─┴─────╥─────────────────────╥───────────┬─────────────┐
+    │ U │           │       ║    Order options    ║           │             │
+    │   │ Customer  │ Order ╟──────────┬──────────╢ Reference │ Description │
+    │   │   type    │ size  ║ Discount │ Priority ║           │             │
+    │   ├───────────┼───────╫──────────┼──────────╫───────────┼─────────────┤
+    │   │"Business",│       ║          │"Normal", ║           │             │
+    │   │"Private"  │       ║          │ "High",  ║           │             │
+    │   │           │       ║          │ "Low"    ║           │             │
+    ╞═══╪═══════════╪═══════╬══════════╪══════════╬═══════════╪═════════════╡
+    │ 1 │"Business" │  <10  ║   0.10   │ "Normal" ║   Ref 1   │ Small order │
+    ├───┼───────────┼───────╫──────────┼──────────╫───────────┼─────────────┤
+    │ 2 │"Business" │ >=10  ║   0.15   │  "High"  ║   Ref 2   │ Large order │
+    ├───┼───────────┼───────╫──────────┼──────────╫───────────┼─────────────┤
+    │ 3 │"Private"  │   -   ║   0.05   │  "Low"   ║   Ref 3   │ All orders  │
+    └───┴───────────┴───────╨──────────┴──────────╨───────────┴─────────────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0002);
+  actions(&mut controller, &[MoveDown(8), CellNext(5), MoveColumnRight(1), AssertPos(59, 11)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0008() {
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴───────────┬─────────────────╥─────┐
+    │ U │ NumberOfUnits │ YearsAsCustomer ║ SLA │
+    │   ├───────────────┼─────────────────╫─────┤
+    │   │ [0..1000000]  │    [0..100]     ║ 1,2 │
+    ╞═══╪═══════════════╪═════════════════╬═════╡
+    │ 1 │    <1000      │       <2        ║  1  │
+    ├───┼───────────────┼─────────────────╫─────┤
+    │ 2 │   >=1000      │       <2        ║  2  │
+    ├───┼───────────────┼─────────────────╫─────┤
+    │ 3 │     <500      │      >=2        ║  1  │
+    ├───┼───────────────┼─────────────────╫─────┤
+    │ 4 │    >=500      │      >=2        ║  2  │
+    └───┴───────────────┴─────────────────╨─────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(4), CellNext(1), MoveColumnRight(1), AssertPos(21, 7)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0009() {
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴───────────┬─────────────────╥─────┐
+    │ U │ NumberOfUnits │ YearsAsCustomer ║ SLA │
+    │   ├───────────────┼─────────────────╫─────┤
+    │   │ [0..1000000]  │    [0..100]     ║ 1,2 │
+    ╞═══╪═══════════════╪═════════════════╬═════╡
+    │ 1 │   >=1000      │       <2        ║  2  │
+    ├───┼───────────────┼─────────────────╫─────┤
+    │ 2 │     <500      │      >=2        ║  1  │
+    ├───┼───────────────┼─────────────────╫─────┤
+    │ 3 │    >=500      │      >=2        ║  2  │
+    ├───┼───────────────┼─────────────────╫─────┤
+    │ 4 │    <1000      │       <2        ║  1  │
+    └───┴───────────────┴─────────────────╨─────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(
+    &mut controller,
+    &[MoveDown(4), CellNext(2), MoveColumnLeft(1), MoveRuleDown(3), MoveRuleDown(1), AssertPos(5, 13)],
+  );
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0010() {
+  // single column of the compound output can not be moved
+  let mut controller = Controller::new(INPUT_0002);
+  actions(&mut controller, &[MoveDown(8), CellNext(3), MoveColumnRight(1), AssertPos(25, 11)]);
+  assert_eq!(INPUT_0002, controller.text());
+}
@@ -0,0 +1,174 @@
+use super::*;
+use dtee::Controller;
+
+#[test]
+fn _0001() {
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴─────────────┬───────────────╥─────┐
+    │ U │ YearsAsCustomer │ NumberOfUnits ║ SLA │
+    │   ├─────────────────┼───────────────╫─────┤
+    │   │    [0..100]     │ [0..1000000]  ║ 1,2 │
+    ╞═══╪═════════════════╪═══════════════╬═════╡
+    │ 1 │       <2        │    <1000      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 2 │       <2        │   >=1000      ║  2  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 3 │                 │               ║     │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 4 │      >=2        │     <500      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 5 │      >=2        │    >=500      ║  2  │
+    └───┴─────────────────┴───────────────╨─────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(5), CellNext(1), AssertPos(5, 9), InsertRuleBelow(1), AssertPos(5, 11)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0002() {
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴─────────────┬───────────────╥─────┐
+    │ U │ YearsAsCustomer │ NumberOfUnits ║ SLA │
+    │   ├─────────────────┼───────────────╫─────┤
+    │   │    [0..100]     │ [0..1000000]  ║ 1,2 │
+    ╞═══╪═════════════════╪═══════════════╬═════╡
+    │ 1 │                 │               ║     │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 2 │       <2        │    <1000      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 3 │       <2        │   >=1000      ║  2  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 4 │      >=2        │     <500      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 5 │      >=2        │    >=500      ║  2  │
+    └───┴─────────────────┴───────────────╨─────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(4), AssertPos(1, 7), InsertRuleAbove(1), AssertPos(1, 7)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0003() {
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴─────────────┬───────────────╥─────┐
+    │ U │ YearsAsCustomer │ NumberOfUnits ║ SLA │
+    │   ├─────────────────┼───────────────╫─────┤
+    │   │    [0..100]     │ [0..1000000]  ║ 1,2 │
+    ╞═══╪═════════════════╪═══════════════╬═════╡
+    │ 1 │       <2        │    <1000      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 2 │       <2        │   >=1000      ║  2  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 3 │      >=2        │     <500      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 4 │      >=2        │    >=500      ║  2  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 5 │                 │               ║     │
+    └───┴─────────────────┴───────────────╨─────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(7), AssertPos(1, 13), InsertRuleBelow(1), AssertPos(1, 15)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0004() {
+  let expected = r#"
+    ┌─────────────────┐
+    │  Order options  │
+    ├───┬───────────┬─┴─────╥─────────────────────╥─────────────┬───────────┐
+    │ U │           │       ║    Order options    ║             │           │
+    │   │ Customer  │ Order ╟──────────┬──────────╢ Description │ Reference │
+    │   │   type    │ size  ║ Discount │ Priority ║             │           │
+    │   ├───────────┼───────╫──────────┼──────────╫─────────────┼───────────┤
+    │   │"Business",│       ║          │"Normal", ║             │           │
+    │   │"Private"  │       ║          │ "High",  ║             │           │
+    │   │           │       ║          │ "Low"    ║             │           │
+    ╞═══╪═══════════╪═══════╬══════════╪══════════╬═════════════╪═══════════╡
+    │ 1 │"Business" │  <10  ║   0.10   │ "Normal" ║ Small order │   Ref 1   │
+    ├───┼───────────┼───────╫──────────┼──────────╫─────────────┼───────────┤
+    │ 2 │           │       ║          │          ║             │           │
+    ├───┼───────────┼───────╫──────────┼──────────╫─────────────┼───────────┤
+    │ 3 │           │       ║          │          ║             │           │
+    ├───┼───────────┼───────╫──────────┼──────────╫─────────────┼───────────┤
+    │ 4 │"Business" │ >=10  ║   0.15   │  "High"  ║ Large order │   Ref 2   │
+    ├───┼───────────┼───────╫──────────┼──────────╫─────────────┼───────────┤
+    │ 5 │"Private"  │   -   ║   0.05   │  "Low"   ║ All orders  │   Ref 3   │
+    └───┴───────────┴───────╨──────────┴──────────╨─────────────┴───────────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0002);
+  actions(&mut controller, &[MoveDown(9), InsertRuleAbove(1), InsertRuleBelow(1)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0005() {
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴─────────────┬───────────────╥─────┐
+    │ U │ YearsAsCustomer │ NumberOfUnits ║ SLA │
+    │   ├─────────────────┼───────────────╫─────┤
+    │   │    [0..100]     │ [0..1000000]  ║ 1,2 │
+    ╞═══╪═════════════════╪═══════════════╬═════╡
+    │ 1 │       <2        │    <1000      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 2 │      >=2        │     <500      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 3 │      >=2        │    >=500      ║  2  │
+    └───┴─────────────────┴───────────────╨─────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(5), AssertPos(1, 9), DeleteRule(1), AssertPos(1, 9)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0006() {
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴─────────────┬───────────────╥─────┐
+    │ U │ YearsAsCustomer │ NumberOfUnits ║ SLA │
+    │   ├─────────────────┼───────────────╫─────┤
+    │   │    [0..100]     │ [0..1000000]  ║ 1,2 │
+    ╞═══╪═════════════════╪═══════════════╬═════╡
+    │ 1 │       <2        │    <1000      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 2 │       <2        │   >=1000      ║  2  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 3 │      >=2        │     <500      ║  1  │
+    └───┴─────────────────┴───────────────╨─────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(7), AssertPos(1, 13), DeleteRule(1), AssertPos(1, 11)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0007() {
+  // the only rule can not be deleted
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(7), DeleteRule(4)]);
+  let expected = controller.text();
+  actions(&mut controller, &[DeleteRule(1)]);
+  assert_eq!(expected, controller.text());
+  assert_eq!(7, controller.content().iter().position(|row| row.text().starts_with("│ 1 │")).unwrap());
+}
+
+#[test]
+fn _0008() {
+  // rules can not be inserted or deleted outside the rules
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(2), InsertRuleAbove(1), InsertRuleBelow(1), DeleteRule(1)]);
+  assert_eq!(INPUT_0001, controller.text());
+  assert!(!controller.can_undo());
+}
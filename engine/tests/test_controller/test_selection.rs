@@ -0,0 +1,109 @@
+use super::*;
+use dtee::Controller;
+
+/// A utility function that marks the selected characters.
+fn selected(controller: &Controller) -> String {
+  let selection = controller.selection().unwrap_or_default();
+  let mut output = String::new();
+  let _ = writeln!(output);
+  for (row_index, row) in controller.content().iter().enumerate() {
+    let line = row
+      .iter()
+      .enumerate()
+      .map(|(col_index, chr)| if selection.contains(col_index, row_index) { '▒' } else { chr.char() })
+      .collect::<String>();
+    let _ = writeln!(output, "    {}", line);
+  }
+  let _ = write!(output, "  ");
+  output
+}
+
+#[test]
+fn _0001() {
+  let controller = Controller::new(INPUT_0002);
+  assert_eq!(None, controller.selection());
+}
+
+#[test]
+fn _0002() {
+  // selecting text inside a single cell
+  let expected = r#"
+    ┌─────────────────┐
+    │  Order options  │
+    ├───┬───────────┬─┴─────╥─────────────────────╥─────────────┬───────────┐
+    │ U │           │       ║    Order options    ║             │           │
+    │   │ Customer  │ Order ╟──────────┬──────────╢ Description │ Reference │
+    │   │   type    │ size  ║ Discount │ Priority ║             │           │
+    │   ├───────────┼───────╫──────────┼──────────╫─────────────┼───────────┤
+    │   │▒▒▒▒▒▒▒▒▒▒,│       ║          │"Normal", ║             │           │
+    │   │▒▒▒▒▒▒▒▒▒▒ │       ║          │ "High",  ║             │           │
+    │   │           │       ║          │ "Low"    ║             │           │
+    ╞═══╪═══════════╪═══════╬══════════╪══════════╬═════════════╪═══════════╡
+    │ 1 │"Business" │  <10  ║   0.10   │ "Normal" ║ Small order │   Ref 1   │
+    ├───┼───────────┼───────╫──────────┼──────────╫─────────────┼───────────┤
+    │ 2 │"Business" │ >=10  ║   0.15   │  "High"  ║ Large order │   Ref 2   │
+    ├───┼───────────┼───────╫──────────┼──────────╫─────────────┼───────────┤
+    │ 3 │"Private"  │   -   ║   0.05   │  "Low"   ║ All orders  │   Ref 3   │
+    └───┴───────────┴───────╨──────────┴──────────╨─────────────┴───────────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0002);
+  actions(&mut controller, &[MoveDown(5), CellNext(1), AssertPos(5, 7), SelectRight(10), SelectDown(1)]);
+  assert_eq!("(5, 7, 10, 2)", controller.selection().unwrap().to_string());
+  assert_eq!(expected, selected(&controller));
+}
+
+#[test]
+fn _0003() {
+  // selecting across cells selects whole cells
+  let expected = r#"
+    ┌─────────────────┐
+    │  Order options  │
+    ├───┬───────────┬─┴─────╥─────────────────────╥─────────────┬───────────┐
+    │ U │           │       ║    Order options    ║             │           │
+    │   │ Customer  │ Order ╟──────────┬──────────╢ Description │ Reference │
+    │   │   type    │ size  ║ Discount │ Priority ║             │           │
+    │   ├───────────┼───────╫──────────┼──────────╫─────────────┼───────────┤
+    │   │"Business",│       ║          │"Normal", ║             │           │
+    │   │"Private"  │       ║          │ "High",  ║             │           │
+    │   │           │       ║          │ "Low"    ║             │           │
+    ╞═══╪═══════════╪═══════╬══════════╪══════════╬═════════════╪═══════════╡
+    │ 1 │"Business" │  <10  ║   0.10   │ "Normal" ║ Small order │   Ref 1   │
+    ├───┼───────────┼───────╫──────────┼──────────╫─────────────┼───────────┤
+    │ 2 │▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒║   0.15   │  "High"  ║ Large order │   Ref 2   │
+    ├───┼▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒╫──────────┼──────────╫─────────────┼───────────┤
+    │ 3 │▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒║   0.05   │  "Low"   ║ All orders  │   Ref 3   │
+    └───┴───────────┴───────╨──────────┴──────────╨─────────────┴───────────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0002);
+  actions(&mut controller, &[MoveDown(9), CellNext(1), AssertPos(5, 13), MoveRight(3), SelectDown(1), SelectRight(12)]);
+  assert_eq!(expected, selected(&controller));
+}
+
+#[test]
+fn _0004() {
+  // moving the cursor clears the selection
+  let mut controller = Controller::new(INPUT_0002);
+  actions(&mut controller, &[MoveDown(5), CellNext(1), SelectRight(3)]);
+  assert!(controller.selection().is_some());
+  let updates = controller.cursor_move_left();
+  assert!(updates.selection_changed());
+  assert_eq!(None, controller.selection());
+}
+
+#[test]
+fn _0005() {
+  // editing clears the selection
+  let mut controller = Controller::new(INPUT_0002);
+  actions(&mut controller, &[MoveDown(5), CellNext(1), SelectRight(3), Insert('A', 1)]);
+  assert_eq!(None, controller.selection());
+}
+
+#[test]
+fn _0006() {
+  // selecting back to the starting position leaves an empty selection
+  let mut controller = Controller::new(INPUT_0002);
+  actions(&mut controller, &[MoveDown(5), CellNext(1), SelectRight(3), SelectLeft(3), SelectUp(1)]);
+  assert!(controller.selection().is_some());
+  assert!(controller.selection_clear().selection_changed());
+  assert!(!controller.selection_clear().selection_changed());
+}
@@ -0,0 +1,173 @@
+//! Test editing multiple decision tables placed in a single file.
+
+use super::*;
+use dtee::{frame_to_ascii, Controller};
+
+#[test]
+fn _0001() {
+  // the text between decision tables is restored
+  let controller = Controller::new(INPUT_0009);
+  assert_eq!(2, controller.table_count());
+  assert_eq!(INPUT_0009, controller.text());
+}
+
+#[test]
+fn _0002() {
+  // byte order mark and line endings are restored
+  let input = format!("\u{FEFF}{}", INPUT_0009.replace('\n', "\r\n"));
+  let controller = Controller::new(&input);
+  assert_eq!(2, controller.table_count());
+  assert_eq!(input, controller.text());
+}
+
+#[test]
+fn _0003() {
+  // the cursor moves between decision tables
+  let first = r#"
+    ┌──────────┐
+    │ Discount │
+    ├───┬──────┴─╥──────────┐
+    │ U │ Order  ║ Discount │
+    ╞═══╪════════╬══════════╡
+    │ 1 │ <10    ║ 0.10     │
+    ├───┼────────╫──────────┤
+    │ 2 │ >=10   ║ 0.15     │
+    └───┴────────╨──────────┘
+  "#;
+  let second = r#"
+    ┌────────────────────┐
+    │ Priority           │
+    ├───┬──────────╥─────┴──────┐
+    │ F │ Customer ║ Priority   │
+    ╞═══╪══════════╬════════════╡
+    │ 1 │ "Gold"   ║ "High"     │
+    └───┴──────────╨────────────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0009);
+  assert_eq!(0, controller.table_index());
+  assert_eq!(first, text(&controller));
+  assert!(!controller.cursor_move_table_prev().content_changed());
+  assert!(controller.cursor_move_table_next().content_changed());
+  assert_eq!(1, controller.table_index());
+  assert_eq!(second, text(&controller));
+  assert!(!controller.cursor_move_table_next().content_changed());
+  assert_eq!(1, controller.table_index());
+  controller.cursor_move_table_prev();
+  assert_eq!(0, controller.table_index());
+  assert_eq!(first, text(&controller));
+}
+
+#[test]
+fn _0004() {
+  // each decision table keeps its own cursor position
+  let mut controller = Controller::new(INPUT_0009);
+  actions(&mut controller, &[MoveDown(2), CellNext(1), AssertPos(5, 5)]);
+  controller.cursor_move_table_next();
+  actions(&mut controller, &[AssertPos(1, 1), MoveDown(2), AssertPos(1, 5)]);
+  controller.cursor_move_table_prev();
+  actions(&mut controller, &[AssertPos(5, 5)]);
+}
+
+#[test]
+fn _0005() {
+  // editing the second decision table updates its own join row
+  let expected = r#"
+    ┌────────────────────────┐
+    │Customer level  Priority│
+    ├───┬──────────╥─────────┴──┐
+    │ F │ Customer ║ Priority   │
+    ╞═══╪══════════╬════════════╡
+    │ 1 │ "Gold"   ║ "High"     │
+    └───┴──────────╨────────────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0009);
+  controller.cursor_move_table_next();
+  actions(&mut controller, &[InsertStr("Customer level ".to_string(), 1)]);
+  assert_eq!(expected, text(&controller));
+  assert!(controller.decision_table().is_ok());
+  assert!(controller.text().starts_with(&INPUT_0009[..INPUT_0009.find("  ┌").unwrap()]));
+  assert!(controller.text().ends_with("  └───┴──────────╨────────────┘\n\nEnd of rules.\n"));
+}
+
+#[test]
+fn _0006() {
+  // editing the first decision table updates its own join row, the second decision table is not changed
+  let expected = r#"
+    ┌───────────────┐
+    │Order  Discount│
+    ├───┬────────╥──┴───────┐
+    │ U │ Order  ║ Discount │
+    ╞═══╪════════╬══════════╡
+    │ 1 │ <10    ║ 0.10     │
+    ├───┼────────╫──────────┤
+    │ 2 │ >=10   ║ 0.15     │
+    └───┴────────╨──────────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0009);
+  actions(&mut controller, &[InsertStr("Order ".to_string(), 1)]);
+  assert_eq!(expected, text(&controller));
+  let second = &INPUT_0009[INPUT_0009.find("Priority is").unwrap()..];
+  assert!(controller.text().ends_with(second));
+}
+
+#[test]
+fn _0007() {
+  // each decision table has its own undo history
+  let mut controller = Controller::new(INPUT_0009);
+  actions(&mut controller, &[Insert('A', 1)]);
+  controller.cursor_move_table_next();
+  actions(&mut controller, &[Insert('B', 1), Undo(2)]);
+  assert!(controller.text().contains("│A Discount"));
+  assert!(controller.text().contains("\n  │ Priority           │\n"));
+  controller.cursor_move_table_prev();
+  actions(&mut controller, &[Undo(1)]);
+  assert_eq!(INPUT_0009, controller.text());
+}
+
+#[test]
+fn _0008() {
+  // every decision table is parsed separately
+  let mut controller = Controller::new(INPUT_0009);
+  let first = controller.decision_table().unwrap();
+  controller.cursor_move_table_next();
+  let second = controller.decision_table().unwrap();
+  assert_eq!("Discount", first.information_item_name().unwrap().text());
+  assert_eq!("Priority", second.information_item_name().unwrap().text());
+  assert_eq!(2, first.rules().len());
+  assert_eq!(1, second.rules().len());
+}
+
+#[test]
+fn _0009() {
+  // decision tables framed with ASCII characters are detected too
+  let input = frame_to_ascii(INPUT_0009);
+  let mut controller = Controller::new(&input);
+  assert_eq!(2, controller.table_count());
+  controller.cursor_move_table_next();
+  assert_eq!("Priority", controller.decision_table().unwrap().information_item_name().unwrap().text());
+}
+
+#[test]
+fn _0010() {
+  // the text surrounding each line of every decision table is restored
+  let input = "┌───┐\n│ A │\n└───┘\n\n  ┌───┐  \n  │ B │\n   └───┘\n";
+  let mut controller = Controller::new(input);
+  assert_eq!(input, controller.text());
+  controller.cursor_move_table_next();
+  actions(&mut controller, &[Insert('C', 1)]);
+  assert_eq!("┌───┐\n│ A │\n└───┘\n\n  ┌───┐  \n  │C B│\n   └───┘\n", controller.text());
+}
+
+#[test]
+fn _0011() {
+  // the viewport is scrolled to the cursor of the selected decision table
+  let first = "┌─────────────────────┐\n│ A                   │\n├─────────────────────┤\n│ B                   │\n├─────────────────────┤\n│ C                   │\n└─────────────────────┘\n";
+  let second = "┌───┐\n│ E │\n└───┘\n";
+  let mut controller = Controller::new(format!("{first}\n{second}")).with_viewport(10, 4);
+  actions(&mut controller, &[MoveDown(4), CellEnd(1), AssertPos(22, 5)]);
+  assert_eq!("(15, 4, 10, 4)", controller.viewport().to_string());
+  assert!(controller.cursor_move_table_next().viewport_pos_changed());
+  assert_eq!("(0, 0, 10, 4)", controller.viewport().to_string());
+  assert!(controller.cursor_move_table_prev().viewport_pos_changed());
+  assert_eq!("(15, 4, 10, 4)", controller.viewport().to_string());
+}
@@ -0,0 +1,90 @@
+//! Test restoring the original text layout.
+
+use super::*;
+use dtee::Controller;
+
+#[test]
+fn _0001() {
+  let controller = Controller::new(INPUT_0001);
+  assert_eq!(INPUT_0001, controller.text());
+}
+
+#[test]
+fn _0002() {
+  let controller = Controller::new(INPUT_0003);
+  assert_eq!(INPUT_0003, controller.text());
+}
+
+#[test]
+fn _0003() {
+  let controller = Controller::new(INPUT_0005);
+  assert_eq!(INPUT_0005, controller.text());
+}
+
+#[test]
+fn _0004() {
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴─────────────┬───────────────╥─────┐
+    │ U │ YearsAsCustomer │ NumberOfUnits ║ SLA │
+    │   ├─────────────────┼───────────────╫─────┤
+    │   │    [0..100]     │ [0..1000000]  ║ 1,2 │
+    ╞═══╪═════════════════╪═══════════════╬═════╡
+    │ 1 │       <2        │    <1000      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 2 │       <2        │   >=1000      ║  2  │
+    └───┴─────────────────┴───────────────╨─────┘
+  "#;
+  let controller = Controller::new(INPUT_0005);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0005() {
+  let input = format!("\u{FEFF}{}", INPUT_0005.replace('\n', "\r\n"));
+  let controller = Controller::new(&input);
+  assert_eq!(input, controller.text());
+}
+
+#[test]
+fn _0006() {
+  // editing a single cell changes only a single line
+  let mut controller = Controller::new(INPUT_0005);
+  actions(&mut controller, &[MoveDown(4), CellNext(2), CellStart(1), MoveRight(8), Insert('0', 1)]);
+  let changed = INPUT_0005
+    .lines()
+    .zip(controller.text().lines())
+    .filter(|(original, edited)| original != edited)
+    .map(|(_, edited)| edited.to_string())
+    .collect::<Vec<String>>();
+  assert_eq!(INPUT_0005.lines().count(), controller.text().lines().count());
+  assert_eq!(vec!["    │ 1 │       <2        │    <10000     ║  1  │"], changed);
+}
+
+#[test]
+fn _0007() {
+  // blank lines and indentation are restored when there is no decision table
+  let controller = Controller::new("No table\n\n  here\n");
+  assert_eq!("No table\n\n  here\n", controller.text());
+}
+
+#[test]
+fn _0008() {
+  // trailing whitespaces and indentation of each line are restored
+  let input = "  ┌───┐  \n  │ A │\n   └───┘\t";
+  let mut controller = Controller::new(input);
+  assert_eq!(input, controller.text());
+  actions(&mut controller, &[Insert('B', 1)]);
+  assert_eq!("  ┌───┐  \n  │B A│\n   └───┘\t", controller.text());
+}
+
+#[test]
+fn _0009() {
+  // line ending of each line is restored, added lines are terminated like the first line
+  let input = "Text\r\n┌───┐\n│ T │\r\n╞═══╡\n│ 1 │\r\n└───┘\nEnd\n";
+  let mut controller = Controller::new(input);
+  assert_eq!(input, controller.text());
+  actions(&mut controller, &[MoveDown(1), SplitLine(1)]);
+  assert_eq!("Text\r\n┌───┐\n│ T │\r\n╞═══╡\n│   │\r\n│ 1 │\r\n└───┘\nEnd\n", controller.text());
+}
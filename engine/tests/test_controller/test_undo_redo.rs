@@ -0,0 +1,84 @@
+use super::*;
+use dtee::Controller;
+
+#[test]
+fn _0001() {
+  // consecutive typing is undone in a single step
+  let mut controller = Controller::new(INPUT_0001);
+  actions(
+    &mut controller,
+    &[MoveDown(6), CellNext(1), AssertPos(5, 11), InsertStr("Gold".to_string(), 1), Undo(1), AssertPos(5, 11)],
+  );
+  assert_eq!(INPUT_0001, controller.text());
+  assert!(!controller.can_undo());
+  assert!(controller.can_redo());
+}
+
+#[test]
+fn _0002() {
+  // typing at another position is undone in two steps
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴─────────────┬───────────────╥─────┐
+    │ U │ YearsAsCustomer │ NumberOfUnits ║ SLA │
+    │   ├─────────────────┼───────────────╫─────┤
+    │   │    [0..100]     │ [0..1000000]  ║ 1,2 │
+    ╞═══╪═════════════════╪═══════════════╬═════╡
+    │ 1 │       <2        │    <1000      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 2 │       <2        │   >=1000      ║  2  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 3 │A B     >=2      │     <500      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 4 │      >=2        │    >=500      ║  2  │
+    └───┴─────────────────┴───────────────╨─────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(6), CellNext(1), MoveRight(1), Insert('B', 1), MoveLeft(2), Insert('A', 1)]);
+  actions(&mut controller, &[Undo(1)]);
+  assert_eq!(expected.replace("│A B     >=2      │", "│ B     >=2       │"), text(&controller));
+  actions(&mut controller, &[Undo(1)]);
+  assert_eq!(INPUT_0001, controller.text());
+  actions(&mut controller, &[Redo(2)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0003() {
+  // splitting a line restores the content and the cursor position
+  let mut controller = Controller::new(INPUT_0002);
+  actions(&mut controller, &[MoveDown(1), MoveRight(30), AssertPos(31, 3), SplitLine(1), Undo(1), AssertPos(31, 3)]);
+  assert_eq!(INPUT_0002, controller.text());
+  actions(&mut controller, &[Redo(1), Undo(1), AssertPos(31, 3)]);
+  assert_eq!(INPUT_0002, controller.text());
+}
+
+#[test]
+fn _0004() {
+  // widening the column while typing is undone
+  let mut controller = Controller::new(INPUT_0001);
+  actions(
+    &mut controller,
+    &[MoveDown(4), CellNext(1), InsertStr("very long condition".to_string(), 1), DeleteBefore(3), Undo(4)],
+  );
+  assert_eq!(INPUT_0001, controller.text());
+}
+
+#[test]
+fn _0005() {
+  // new edit operation clears the redo steps
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(6), CellNext(1), Insert('A', 1), Undo(1), DeleteUnder(1)]);
+  assert!(!controller.can_redo());
+  actions(&mut controller, &[Redo(1), Undo(5)]);
+  assert_eq!(INPUT_0001, controller.text());
+}
+
+#[test]
+fn _0006() {
+  // cursor movement is not recorded
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(6), CellNext(2), Undo(1), AssertPos(23, 11)]);
+  assert!(!controller.can_undo());
+}
@@ -81,3 +81,36 @@ fn _0003() {
   actions(&mut controller, &[MoveDown(1), CellNext(3), SplitLine(1), DeleteBefore(1)]);
   assert_eq!(expected, text(&controller));
 }
+
+#[test]
+fn _0004() {
+  let mut controller = Controller::new(INPUT_0002).with_viewport(WIDTH, HEIGHT);
+  actions(&mut controller, &[MoveDown(1), MoveRight(30), SplitLine(1), UnsplitLine(1), AssertPos(31, 3)]);
+  assert_eq!(INPUT_0002, controller.text());
+}
+
+#[test]
+fn _0005() {
+  let mut controller = Controller::new(INPUT_0002).with_viewport(WIDTH, HEIGHT);
+  actions(
+    &mut controller,
+    &[MoveDown(1), MoveRight(30), SplitLine(1), MoveUp(1), CellEnd(1), DeleteUnder(1), AssertPos(31, 3)],
+  );
+  assert_eq!(INPUT_0002, controller.text());
+}
+
+#[test]
+fn _0006() {
+  // joining with the line below fails when the cell is not wide enough
+  let mut controller = Controller::new(INPUT_0002).with_viewport(WIDTH, HEIGHT);
+  actions(&mut controller, &[MoveDown(5), CellNext(1), CellEnd(1), AssertPos(16, 7), DeleteUnder(1), AssertPos(16, 7)]);
+  assert_eq!(INPUT_0002, controller.text());
+}
+
+#[test]
+fn _0007() {
+  // joining with the line below fails in the last line of the cell
+  let mut controller = Controller::new(INPUT_0002).with_viewport(WIDTH, HEIGHT);
+  actions(&mut controller, &[MoveDown(9), CellEnd(1), AssertPos(4, 13), DeleteUnder(1), AssertPos(4, 13)]);
+  assert_eq!(INPUT_0002, controller.text());
+}
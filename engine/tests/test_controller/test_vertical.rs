@@ -0,0 +1,262 @@
+use super::*;
+use dtee::Controller;
+
+#[test]
+fn _0001() {
+  // the decision table is transposed and the cursor is placed in the top-left cell
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───────┴────────────────────────╥───────┬────────┬──────┬───────┐
+    │               U                ║   1   │   2    │  3   │   4   │
+    ├─────────────────┬──────────────╫───────┼────────┼──────┼───────┤
+    │ YearsAsCustomer │ [0..100]     ║ <2    │ <2     │ >=2  │ >=2   │
+    ├─────────────────┼──────────────╫───────┼────────┼──────┼───────┤
+    │ NumberOfUnits   │ [0..1000000] ║ <1000 │ >=1000 │ <500 │ >=500 │
+    ╞═════════════════╪══════════════╬═══════╪════════╪══════╪═══════╡
+    │ SLA             │ 1,2          ║ 1     │ 2      │ 1    │ 2     │
+    └─────────────────┴──────────────╨───────┴────────┴──────┴───────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[MoveDown(6), Transpose, AssertPos(1, 1)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0002() {
+  // transposing twice renders the original decision table
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴─────────────┬───────────────╥─────┐
+    │ U │ YearsAsCustomer │ NumberOfUnits ║ SLA │
+    │   ├─────────────────┼───────────────╫─────┤
+    │   │    [0..100]     │ [0..1000000]  ║ 1,2 │
+    ╞═══╪═════════════════╪═══════════════╬═════╡
+    │ 1 │ <2              │ <1000         ║ 1   │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 2 │ <2              │ >=1000        ║ 2   │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 3 │ >=2             │ <500          ║ 1   │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 4 │ >=2             │ >=500         ║ 2   │
+    └───┴─────────────────┴───────────────╨─────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[Transpose, Transpose]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0003() {
+  // the cursor is moved rule by rule, also in horizontal decision tables
+  let mut controller = Controller::new(INPUT_0001);
+  actions(
+    &mut controller,
+    &[MoveRight(4), RuleNext(1), AssertPos(5, 7), RuleNext(2), AssertPos(5, 11), RulePrev(1), AssertPos(5, 9)],
+  );
+  actions(&mut controller, &[RuleNext(5), AssertPos(5, 13), RulePrev(5), AssertPos(5, 7)]);
+}
+
+#[test]
+fn _0004() {
+  // the cursor is moved rule by rule in vertical decision tables
+  let mut controller = Controller::new(INPUT_0001);
+  actions(
+    &mut controller,
+    &[
+      Transpose,
+      RuleNext(1),
+      AssertPos(34, 3),
+      RuleNext(1),
+      AssertPos(42, 3),
+      MoveDown(2),
+      RuleNext(1),
+      AssertPos(51, 7),
+    ],
+  );
+  actions(
+    &mut controller,
+    &[RuleNext(5), AssertPos(58, 7), RulePrev(3), AssertPos(34, 7), RulePrev(1), AssertPos(34, 7)],
+  );
+}
+
+#[test]
+fn _0005() {
+  // rules are inserted as columns on the left side of the current rule
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───────┴────────────────────────╥─────┬───────┬────────┬──────┬───────┐
+    │               U                ║  1  │   2   │   3    │  4   │   5   │
+    ├─────────────────┬──────────────╫─────┼───────┼────────┼──────┼───────┤
+    │ YearsAsCustomer │ [0..100]     ║     │ <2    │ <2     │ >=2  │ >=2   │
+    ├─────────────────┼──────────────╫─────┼───────┼────────┼──────┼───────┤
+    │ NumberOfUnits   │ [0..1000000] ║     │ <1000 │ >=1000 │ <500 │ >=500 │
+    ╞═════════════════╪══════════════╬═════╪═══════╪════════╪══════╪═══════╡
+    │ SLA             │ 1,2          ║     │ 1     │ 2      │ 1    │ 2     │
+    └─────────────────┴──────────────╨─────┴───────┴────────┴──────┴───────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[Transpose, RuleNext(1), AssertPos(34, 3), InsertRuleAbove(1), AssertPos(35, 3)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0006() {
+  // rules are inserted as columns on the right side of the current rule
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───────┴────────────────────────╥───────┬────────┬──────┬───────┬─────┐
+    │               U                ║   1   │   2    │  3   │   4   │  5  │
+    ├─────────────────┬──────────────╫───────┼────────┼──────┼───────┼─────┤
+    │ YearsAsCustomer │ [0..100]     ║ <2    │ <2     │ >=2  │ >=2   │     │
+    ├─────────────────┼──────────────╫───────┼────────┼──────┼───────┼─────┤
+    │ NumberOfUnits   │ [0..1000000] ║ <1000 │ >=1000 │ <500 │ >=500 │     │
+    ╞═════════════════╪══════════════╬═══════╪════════╪══════╪═══════╪═════╡
+    │ SLA             │ 1,2          ║ 1     │ 2      │ 1    │ 2     │     │
+    └─────────────────┴──────────────╨───────┴────────┴──────┴───────┴─────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[Transpose, RuleNext(4), AssertPos(58, 3), InsertRuleBelow(1), AssertPos(67, 3)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0007() {
+  // rule columns are deleted and renumbered
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───────┴────────────────────────╥───────┬──────┬───────┐
+    │               U                ║   1   │  2   │   3   │
+    ├─────────────────┬──────────────╫───────┼──────┼───────┤
+    │ YearsAsCustomer │ [0..100]     ║ <2    │ >=2  │ >=2   │
+    ├─────────────────┼──────────────╫───────┼──────┼───────┤
+    │ NumberOfUnits   │ [0..1000000] ║ <1000 │ <500 │ >=500 │
+    ╞═════════════════╪══════════════╬═══════╪══════╪═══════╡
+    │ SLA             │ 1,2          ║ 1     │ 1    │ 2     │
+    └─────────────────┴──────────────╨───────┴──────┴───────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[Transpose, RuleNext(2), AssertPos(42, 3), DeleteRule(1), AssertPos(42, 3)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0008() {
+  // the last rule column is deleted together with its left vertical line
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───────┴────────────────────────╥───────┬──────┐
+    │               U                ║   1   │  2   │
+    ├─────────────────┬──────────────╫───────┼──────┤
+    │ YearsAsCustomer │ [0..100]     ║ <2    │ >=2  │
+    ├─────────────────┼──────────────╫───────┼──────┤
+    │ NumberOfUnits   │ [0..1000000] ║ <1000 │ <500 │
+    ╞═════════════════╪══════════════╬═══════╪══════╡
+    │ SLA             │ 1,2          ║ 1     │ 1    │
+    └─────────────────┴──────────────╨───────┴──────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[Transpose, RuleNext(2), DeleteRule(1), RuleNext(2), DeleteRule(1), AssertPos(47, 3)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0009() {
+  // rule columns are moved right
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───────┴────────────────────────╥────────┬───────┬──────┬───────┐
+    │               U                ║   1    │   2   │  3   │   4   │
+    ├─────────────────┬──────────────╫────────┼───────┼──────┼───────┤
+    │ YearsAsCustomer │ [0..100]     ║ <2     │ <2    │ >=2  │ >=2   │
+    ├─────────────────┼──────────────╫────────┼───────┼──────┼───────┤
+    │ NumberOfUnits   │ [0..1000000] ║ >=1000 │ <1000 │ <500 │ >=500 │
+    ╞═════════════════╪══════════════╬════════╪═══════╪══════╪═══════╡
+    │ SLA             │ 1,2          ║ 2      │ 1     │ 1    │ 2     │
+    └─────────────────┴──────────────╨────────┴───────┴──────┴───────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[Transpose, RuleNext(1), MoveRuleDown(1), AssertPos(43, 3)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0010() {
+  // rule columns are moved right up to the right edge
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───────┴────────────────────────╥────────┬──────┬───────┬───────┐
+    │               U                ║   1    │  2   │   3   │   4   │
+    ├─────────────────┬──────────────╫────────┼──────┼───────┼───────┤
+    │ YearsAsCustomer │ [0..100]     ║ <2     │ >=2  │ >=2   │ <2    │
+    ├─────────────────┼──────────────╫────────┼──────┼───────┼───────┤
+    │ NumberOfUnits   │ [0..1000000] ║ >=1000 │ <500 │ >=500 │ <1000 │
+    ╞═════════════════╪══════════════╬════════╪══════╪═══════╪═══════╡
+    │ SLA             │ 1,2          ║ 2      │ 1    │ 2     │ 1     │
+    └─────────────────┴──────────────╨────────┴──────┴───────┴───────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[Transpose, RuleNext(1), MoveRuleDown(5), AssertPos(58, 3)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0011() {
+  // clause columns are not changed in vertical decision tables
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───────┴────────────────────────╥───────┬────────┬──────┬───────┐
+    │               U                ║   1   │   2    │  3   │   4   │
+    ├─────────────────┬──────────────╫───────┼────────┼──────┼───────┤
+    │ YearsAsCustomer │ [0..100]     ║ <2    │ <2     │ >=2  │ >=2   │
+    ├─────────────────┼──────────────╫───────┼────────┼──────┼───────┤
+    │ NumberOfUnits   │ [0..1000000] ║ <1000 │ >=1000 │ <500 │ >=500 │
+    ╞═════════════════╪══════════════╬═══════╪════════╪══════╪═══════╡
+    │ SLA             │ 1,2          ║ 1     │ 2      │ 1    │ 2     │
+    └─────────────────┴──────────────╨───────┴────────┴──────┴───────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[Transpose, RuleNext(1), InsertInputColumn(1), DeleteColumn(1), AssertPos(34, 3)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0012() {
+  // transposing is undone in a single step
+  let expected = r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴─────────────┬───────────────╥─────┐
+    │ U │ YearsAsCustomer │ NumberOfUnits ║ SLA │
+    │   ├─────────────────┼───────────────╫─────┤
+    │   │    [0..100]     │ [0..1000000]  ║ 1,2 │
+    ╞═══╪═════════════════╪═══════════════╬═════╡
+    │ 1 │       <2        │    <1000      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 2 │       <2        │   >=1000      ║  2  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 3 │      >=2        │     <500      ║  1  │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 4 │      >=2        │    >=500      ║  2  │
+    └───┴─────────────────┴───────────────╨─────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0001);
+  actions(&mut controller, &[Transpose, Undo(1), AssertPos(1, 1)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0013() {
+  // commands do not panic when the decision table starts with the separator
+  let mut controller = Controller::new("╞═══╡\n│ A │\n└───┘");
+  actions(&mut controller, &[InsertRuleBelow(1)]);
+  assert_eq!("╞═══╡\n│ A │\n├───┤\n│   │\n└───┘", controller.text());
+}
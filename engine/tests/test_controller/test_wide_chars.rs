@@ -0,0 +1,163 @@
+use super::*;
+
+#[test]
+fn _0001() {
+  // the cursor jumps over wide characters
+  let mut controller = Controller::new(INPUT_0008);
+  actions(
+    &mut controller,
+    &[
+      MoveDown(3),
+      CellNext(1),
+      MoveRight(1),
+      AssertPos(6, 7),
+      MoveRight(1),
+      AssertPos(7, 7),
+      MoveRight(1),
+      AssertPos(9, 7),
+      MoveRight(1),
+      AssertPos(11, 7),
+      MoveLeft(2),
+      AssertPos(7, 7),
+    ],
+  );
+}
+
+#[test]
+fn _0002() {
+  // the cursor moved vertically does not stop in the second column of a wide character
+  let mut controller = Controller::new(INPUT_0008);
+  actions(&mut controller, &[MoveDown(2), CellNext(1), MoveRight(3), AssertPos(8, 5), MoveDown(1), AssertPos(7, 7)]);
+}
+
+#[test]
+fn _0003() {
+  // wide characters occupy two columns
+  let expected = r#"
+    ┌───────────────┐
+    │  Rabatt 割引  │
+    ├───┬───────────┴╥─────────┐
+    │ U │   Kunde    ║ Rabatt  │
+    ╞═══╪════════════╬═════════╡
+    │ 1 │ "Geschäft" ║ 0.10    │
+    ├───┼────────────╫─────────┤
+    │ 2 │ "顧客店"   ║ 0.05 🎉 │
+    └───┴────────────╨─────────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0008);
+  actions(&mut controller, &[MoveDown(3), CellNext(1), MoveRight(4), Insert('店', 1), AssertPos(13, 7)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0004() {
+  // inserting a wide character at the end of the cell widens the column by two columns
+  let expected = r#"
+    ┌───────────────┐
+    │  Rabatt 割引  │
+    ├───┬───────────┴╥───────────┐
+    │ U │   Kunde    ║ Rabatt    │
+    ╞═══╪════════════╬═══════════╡
+    │ 1 │ "Geschäft" ║ 0.10      │
+    ├───┼────────────╫───────────┤
+    │ 2 │ "顧客"     ║ 0.05 🎉 店│
+    └───┴────────────╨───────────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0008);
+  actions(&mut controller, &[MoveDown(3), CellNext(2), CellEnd(1), Insert('店', 1), AssertPos(29, 7)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0005() {
+  // wide characters are deleted together with their second column
+  let expected = r#"
+    ┌───────────────┐
+    │  Rabatt 割引  │
+    ├───┬───────────┴╥───────┐
+    │ U │   Kunde    ║ Rabatt│
+    ╞═══╪════════════╬═══════╡
+    │ 1 │ "Geschäft" ║ 0.10  │
+    ├───┼────────────╫───────┤
+    │ 2 │ "顧客"     ║ 0.05  │
+    └───┴────────────╨───────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0008);
+  actions(&mut controller, &[MoveDown(3), CellNext(2), MoveRight(7), DeleteBefore(1), AssertPos(24, 7)]);
+  assert_eq!(expected, text(&controller));
+  let mut controller = Controller::new(INPUT_0008);
+  actions(&mut controller, &[MoveDown(3), CellNext(2), MoveRight(6), DeleteUnder(1), AssertPos(24, 7)]);
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0006() {
+  // combining marks are attached to the character before the cursor
+  let mut controller = Controller::new(INPUT_0008);
+  actions(&mut controller, &[MoveDown(3), CellNext(2), MoveRight(2), Insert('\u{301}', 1), AssertPos(20, 7)]);
+  assert!(controller.text().contains("║ 0\u{301}.05 🎉 │"));
+  let decision_table = controller.decision_table().unwrap();
+  assert_eq!("0\u{301}.05 🎉", decision_table.rules()[1].output_entries()[0].text());
+}
+
+#[test]
+fn _0007() {
+  // overriding with a wide character replaces two columns
+  let expected = r#"
+    ┌───────────────┐
+    │  Rabatt 割引  │
+    ├───┬───────────┴╥─────────┐
+    │ U │   Kunde    ║ Rabatt  │
+    ╞═══╪════════════╬═════════╡
+    │ 1 │ 店eschäft" ║ 0.10    │
+    ├───┼────────────╫─────────┤
+    │ 2 │ "顧客"     ║ 0.05 🎉 │
+    └───┴────────────╨─────────┘
+  "#;
+  let mut controller = Controller::new(INPUT_0008);
+  actions(
+    &mut controller,
+    &[MoveDown(2), CellNext(1), MoveRight(1), ToggleCaretBlock, Insert('店', 1), AssertPos(8, 5)],
+  );
+  assert_eq!(expected, text(&controller));
+}
+
+#[test]
+fn _0008() {
+  // wide characters cut by the viewport are displayed as spaces
+  let expected = r#"
+    ╭─────────────────────────╮
+    │┌───────────────┐░░░░░░░░│
+    ││  Rabatt 割引  │░░░░░░░░│
+    │├───┬───────────┴╥───────│
+    ││ U │   Kunde    ║ Rabatt│
+    │╞═══╪════════════╬═══════│
+    ││ 1 │ "Geschäft" ║ 0.10  │
+    │├───┼────────────╫───────│
+    ││ 2 │ "顧客"     ║ 0.05  │
+    │└───┴────────────╨───────│
+    ╰─────────────────────────╯
+  "#;
+  let controller = Controller::new(INPUT_0008).with_viewport(25, 9);
+  assert_eq!(expected, view(&controller));
+}
+
+#[test]
+fn _0009() {
+  // undo restores wide characters and combining marks
+  let mut controller = Controller::new(INPUT_0008);
+  actions(
+    &mut controller,
+    &[
+      MoveDown(3),
+      CellNext(1),
+      MoveRight(3),
+      DeleteBefore(1),
+      Insert('\u{301}', 1),
+      Insert('店', 1),
+      Undo(3),
+      AssertPos(9, 7),
+    ],
+  );
+  assert_eq!(INPUT_0008.trim_end(), controller.text().trim_end());
+}
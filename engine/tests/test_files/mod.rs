@@ -2,3 +2,8 @@ pub const INPUT_0001: &str = include_str!("0001.dtb");
 pub const INPUT_0002: &str = include_str!("0002.dtb");
 pub const INPUT_0003: &str = include_str!("0003.dtb");
 pub const INPUT_0004: &str = include_str!("0004.dtb");
+pub const INPUT_0005: &str = include_str!("0005.dtb");
+pub const INPUT_0006: &str = include_str!("0006.dtb");
+pub const INPUT_0007: &str = include_str!("0007.dtb");
+pub const INPUT_0008: &str = include_str!("0008.dtb");
+pub const INPUT_0009: &str = include_str!("0009.dtb");
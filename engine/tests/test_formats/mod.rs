@@ -0,0 +1,8 @@
+mod test_csv;
+mod test_dmn;
+mod test_format;
+mod test_html;
+mod test_json;
+mod test_markdown;
+
+use super::test_files::*;
@@ -0,0 +1,88 @@
+//! Test exporting decision tables to CSV and importing them back.
+
+use super::*;
+use dtee::{export_csv, import_csv, CsvOptions, CsvQuoting, DecisionTable, Format, HitPolicy, TableError};
+
+#[test]
+fn _0001() {
+  let expected = r#"U,input,input,output
+SLA,YearsAsCustomer,NumberOfUnits,SLA
+,[0..100],[0..1000000],"1,2"
+1,<2,<1000,1
+2,<2,>=1000,2
+3,>=2,<500,1
+4,>=2,>=500,2
+"#;
+  let decision_table = INPUT_0001.parse::<DecisionTable>().unwrap();
+  assert_eq!(expected, export_csv(&decision_table, CsvOptions::default()));
+}
+
+#[test]
+fn _0002() {
+  let expected = r#"U;input;input;output;output;annotation;annotation
+Order options;Customer type;Order size;Order options / Discount;Order options / Priority;Description;Reference
+;"Business", "Private";;;"Normal", "High", "Low";;
+1;"Business";<10;0.10;"Normal";Small order;'Ref '' 1'
+2;"Business";>=10;0.15;"High";Large order;Ref 2
+3;"Private";-;0.05;"Low";All orders;Ref 3
+"#;
+  // double quotes are not special when another quote character is used
+  let options = CsvOptions::default().with_delimiter(';').with_quote('\'');
+  let input = INPUT_0002.replace("  Ref 1  ", " Ref ' 1 ");
+  let decision_table = input.parse::<DecisionTable>().unwrap();
+  assert_eq!(expected, export_csv(&decision_table, options));
+}
+
+#[test]
+fn _0003() {
+  // multi-line cells are flattened, so only flat decision tables are imported back unchanged
+  let decision_table = INPUT_0001.parse::<DecisionTable>().unwrap();
+  for options in [
+    CsvOptions::default(),
+    CsvOptions::default().with_delimiter('\t'),
+    CsvOptions::default().with_quoting(CsvQuoting::Always),
+  ] {
+    let imported = import_csv(&export_csv(&decision_table, options), options).unwrap();
+    assert_eq!(decision_table.to_string(), imported.to_string());
+  }
+  let decision_table = INPUT_0002.parse::<DecisionTable>().unwrap();
+  let format = Format::Csv(CsvOptions::default());
+  let csv = format.export(&decision_table);
+  assert_eq!(csv, format.export(&format.import(&csv, None).unwrap()));
+}
+
+#[test]
+fn _0004() {
+  let csv = "C#,Input,OUTPUT,annotation\r\n,\"a\r\nb\",x,Note\r\n\r\n1,\"say \"\"hi\"\"\",1\r\n";
+  let decision_table = import_csv(csv, CsvOptions::default()).unwrap();
+  assert_eq!(None, decision_table.information_item_name());
+  assert_eq!(HitPolicy::Collect(Some(dtee::BuiltinAggregator::Count)), decision_table.hit_policy());
+  assert_eq!("a\nb", decision_table.input_clauses()[0].input_expression().text());
+  assert_eq!("Note", decision_table.annotation_clauses()[0].name().text());
+  assert_eq!("say \"hi\"", decision_table.rules()[0].input_entries()[0].text());
+  assert_eq!("", decision_table.rules()[0].annotation_entries()[0].text());
+}
+
+#[test]
+fn _0005() {
+  let options = CsvOptions::default();
+  assert_eq!(Err(TableError::InvalidCsv("missing row with column roles".to_string())), import_csv("", options));
+  assert_eq!(
+    Err(TableError::InvalidCsv("missing row with column names".to_string())),
+    import_csv("U,input,output", options)
+  );
+  assert_eq!(Err(TableError::InvalidCsv("unterminated quoted value".to_string())), import_csv("U,\"input", options));
+  assert_eq!(
+    Err(TableError::InvalidCsv("invalid column role 'result'".to_string())),
+    import_csv("U,input,result\n,a,b", options)
+  );
+  assert_eq!(
+    Err(TableError::InvalidCsv("input column 'c' out of order".to_string())),
+    import_csv("U,input,output,input\n,a,b,c", options)
+  );
+  assert_eq!(
+    Err(TableError::InvalidCsv("too many cells in row 3".to_string())),
+    import_csv("U,input,output\n,a,b\n1,2,3,4", options)
+  );
+  assert_eq!(Err(TableError::MissingInputOutputSeparator), import_csv("U,input\n,a", options));
+}
@@ -0,0 +1,239 @@
+//! Test exporting decision tables to DMN XML.
+
+use super::*;
+use dtee::{export_dmn, import_dmn, Cell, DecisionTable, Format, HitPolicy, InputClause, Orientation, OutputClause, Rule, TableError};
+
+#[test]
+fn _0001() {
+  let expected = r#"<?xml version="1.0" encoding="UTF-8"?>
+<definitions xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/" namespace="https://decision-toolkit.org/dte" id="_definitions" name="SLA">
+  <decision id="_decision" name="SLA">
+    <decisionTable id="_decision_table" hitPolicy="UNIQUE">
+      <input id="_input_1" label="YearsAsCustomer">
+        <inputExpression id="_input_1_expression">
+          <text>YearsAsCustomer</text>
+        </inputExpression>
+        <inputValues id="_input_1_values">
+          <text>[0..100]</text>
+        </inputValues>
+      </input>
+      <input id="_input_2" label="NumberOfUnits">
+        <inputExpression id="_input_2_expression">
+          <text>NumberOfUnits</text>
+        </inputExpression>
+        <inputValues id="_input_2_values">
+          <text>[0..1000000]</text>
+        </inputValues>
+      </input>
+      <output id="_output_1" name="SLA" label="SLA">
+        <outputValues id="_output_1_values">
+          <text>1,2</text>
+        </outputValues>
+      </output>
+      <rule id="_rule_1">
+        <inputEntry id="_rule_1_input_entry_1">
+          <text>&lt;2</text>
+        </inputEntry>
+        <inputEntry id="_rule_1_input_entry_2">
+          <text>&lt;1000</text>
+        </inputEntry>
+        <outputEntry id="_rule_1_output_entry_1">
+          <text>1</text>
+        </outputEntry>
+      </rule>
+      <rule id="_rule_2">
+        <inputEntry id="_rule_2_input_entry_1">
+          <text>&lt;2</text>
+        </inputEntry>
+        <inputEntry id="_rule_2_input_entry_2">
+          <text>&gt;=1000</text>
+        </inputEntry>
+        <outputEntry id="_rule_2_output_entry_1">
+          <text>2</text>
+        </outputEntry>
+      </rule>
+      <rule id="_rule_3">
+        <inputEntry id="_rule_3_input_entry_1">
+          <text>&gt;=2</text>
+        </inputEntry>
+        <inputEntry id="_rule_3_input_entry_2">
+          <text>&lt;500</text>
+        </inputEntry>
+        <outputEntry id="_rule_3_output_entry_1">
+          <text>1</text>
+        </outputEntry>
+      </rule>
+      <rule id="_rule_4">
+        <inputEntry id="_rule_4_input_entry_1">
+          <text>&gt;=2</text>
+        </inputEntry>
+        <inputEntry id="_rule_4_input_entry_2">
+          <text>&gt;=500</text>
+        </inputEntry>
+        <outputEntry id="_rule_4_output_entry_1">
+          <text>2</text>
+        </outputEntry>
+      </rule>
+    </decisionTable>
+  </decision>
+</definitions>
+"#;
+  let decision_table = INPUT_0001.parse::<DecisionTable>().unwrap();
+  assert_eq!(expected, export_dmn(&decision_table));
+}
+
+#[test]
+fn _0002() {
+  let decision_table = INPUT_0002.parse::<DecisionTable>().unwrap();
+  let dmn = Format::Dmn.export(&decision_table);
+  assert!(dmn.contains(r#"<decisionTable id="_decision_table" hitPolicy="UNIQUE" outputLabel="Order options">"#));
+  assert!(dmn.contains(r#"<input id="_input_1" label="Customer type">"#));
+  assert!(dmn.contains("<inputValues id=\"_input_1_values\">\n          <text>\"Business\", \"Private\"</text>"));
+  assert!(!dmn.contains("_input_2_values"));
+  assert!(dmn.contains(r#"<output id="_output_1" name="Discount" label="Discount"/>"#));
+  assert!(dmn.contains("<text>\"Normal\", \"High\", \"Low\"</text>"));
+  assert!(dmn.contains(r#"<annotation name="Description"/>"#));
+  assert!(dmn.contains(r#"<annotation name="Reference"/>"#));
+  assert!(dmn.contains("<annotationEntry>\n          <text>All orders</text>\n        </annotationEntry>"));
+}
+
+#[test]
+fn _0003() {
+  let decision_table = DecisionTable::new(
+    HitPolicy::Collect(Some(dtee::BuiltinAggregator::Sum)),
+    vec![InputClause::new(Cell::new("a & b"))],
+    vec![OutputClause::new(Cell::new("\"x\""))],
+    vec![Rule::new(vec![Cell::new("-")], vec![Cell::new("1\n+ 2")])],
+  );
+  let dmn = export_dmn(&decision_table);
+  assert!(dmn.contains(r#"<decision id="_decision" name="decision">"#));
+  assert!(dmn.contains(r#"<decisionTable id="_decision_table" hitPolicy="COLLECT" aggregation="SUM">"#));
+  assert!(dmn.contains(r#"<input id="_input_1" label="a &amp; b">"#));
+  assert!(dmn.contains("<text>a &amp; b</text>"));
+  assert!(dmn.contains(r#"<output id="_output_1" name="&quot;x&quot;" label="&quot;x&quot;"/>"#));
+  assert!(dmn.contains("<text>1\n+ 2</text>"));
+}
+
+#[test]
+fn _0004() {
+  for (hit_policy, name) in [
+    (HitPolicy::Any, r#"hitPolicy="ANY">"#),
+    (HitPolicy::Priority, r#"hitPolicy="PRIORITY">"#),
+    (HitPolicy::Collect(None), r#"hitPolicy="COLLECT">"#),
+    (HitPolicy::OutputOrder, r#"hitPolicy="OUTPUT ORDER">"#),
+    (HitPolicy::RuleOrder, r#"hitPolicy="RULE ORDER">"#),
+  ] {
+    let decision_table = DecisionTable::new(hit_policy, vec![], vec![OutputClause::new(Cell::new("o"))], vec![]);
+    assert!(export_dmn(&decision_table).contains(name));
+  }
+}
+
+/// DMN model with two decisions, using the namespace prefix.
+const DMN_TWO_DECISIONS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<dmn:definitions xmlns:dmn="https://www.omg.org/spec/DMN/20191111/MODEL/" namespace="test" id="_definitions" name="test">
+  <dmn:decision id="_d1" name="Discount">
+    <dmn:decisionTable hitPolicy="COLLECT" aggregation="MAX">
+      <dmn:input label="Customer">
+        <dmn:inputExpression><dmn:text>customer.type</dmn:text></dmn:inputExpression>
+        <dmn:inputValues><dmn:text>"Gold","Silver"</dmn:text></dmn:inputValues>
+      </dmn:input>
+      <dmn:output name="discount"/>
+      <dmn:annotation name="Note"/>
+      <dmn:rule>
+        <dmn:inputEntry><dmn:text>"Gold"</dmn:text></dmn:inputEntry>
+        <dmn:outputEntry><dmn:text>
+          0.2
+        </dmn:text></dmn:outputEntry>
+        <dmn:annotationEntry><dmn:text>best</dmn:text></dmn:annotationEntry>
+      </dmn:rule>
+      <dmn:rule>
+        <dmn:inputEntry><dmn:text>-</dmn:text></dmn:inputEntry>
+        <dmn:outputEntry><dmn:text>0</dmn:text></dmn:outputEntry>
+      </dmn:rule>
+    </dmn:decisionTable>
+  </dmn:decision>
+  <dmn:decision id="_d2" name="Greeting">
+    <dmn:decisionTable>
+      <dmn:input label="Hour"><dmn:inputExpression><dmn:text>hour</dmn:text></dmn:inputExpression></dmn:input>
+      <dmn:output label="Text"/>
+    </dmn:decisionTable>
+  </dmn:decision>
+  <dmn:decision id="_d3" name="Literal">
+    <dmn:literalExpression><dmn:text>1</dmn:text></dmn:literalExpression>
+  </dmn:decision>
+</dmn:definitions>
+"#;
+
+#[test]
+fn _0005() {
+  // imported decision tables are exported back unchanged
+  for input in [INPUT_0001, INPUT_0002] {
+    let dmn = export_dmn(&input.parse::<DecisionTable>().unwrap());
+    assert_eq!(dmn, export_dmn(&import_dmn(&dmn, None).unwrap()));
+  }
+  let decision_table = INPUT_0001.parse::<DecisionTable>().unwrap();
+  let imported = import_dmn(&export_dmn(&decision_table), None).unwrap();
+  assert_eq!(decision_table.to_string(), imported.to_string());
+}
+
+#[test]
+fn _0006() {
+  let decision_table = import_dmn(DMN_TWO_DECISIONS, Some("_d1")).unwrap();
+  assert_eq!("Discount", decision_table.information_item_name().unwrap().text());
+  assert_eq!(HitPolicy::Collect(Some(dtee::BuiltinAggregator::Max)), decision_table.hit_policy());
+  assert_eq!("customer.type", decision_table.input_clauses()[0].input_expression().text());
+  assert_eq!("\"Gold\",\"Silver\"", decision_table.input_clauses()[0].allowed_values().unwrap().text());
+  assert_eq!("discount", decision_table.output_clauses()[0].name().text());
+  assert_eq!("0.2", decision_table.rules()[0].output_entries()[0].text());
+  assert_eq!("best", decision_table.rules()[0].annotation_entries()[0].text());
+  assert_eq!("", decision_table.rules()[1].annotation_entries()[0].text());
+}
+
+#[test]
+fn _0007() {
+  let expected = r#"
+┌────────────┐
+│  Greeting  │
+├───┬──────╥─┴────┐
+│ U │ hour ║ Text │
+╞═══╪══════╬══════╡
+│   │      ║      │
+└───┴──────╨──────┘
+"#;
+  let decision_table = import_dmn(DMN_TWO_DECISIONS, Some("Greeting")).unwrap();
+  assert_eq!(expected.trim(), decision_table.to_string());
+}
+
+#[test]
+fn _0008() {
+  assert_eq!(
+    Err(TableError::AmbiguousDecision(vec!["Discount".to_string(), "Greeting".to_string()])),
+    import_dmn(DMN_TWO_DECISIONS, None)
+  );
+  assert_eq!(Err(TableError::DecisionNotFound("Literal".to_string())), import_dmn(DMN_TWO_DECISIONS, Some("Literal")));
+  assert_eq!(Err(TableError::DecisionTableNotFound), import_dmn("<definitions/>", None));
+  assert!(matches!(import_dmn("<definitions>", None), Err(TableError::InvalidDmn(_))));
+}
+
+#[test]
+fn _0009() {
+  // vertical orientation is preserved as the preferred orientation
+  let decision_table = INPUT_0001.parse::<DecisionTable>().unwrap().with_orientation(Orientation::Vertical);
+  let dmn = export_dmn(&decision_table);
+  assert!(dmn.contains(r#"<decisionTable id="_decision_table" hitPolicy="UNIQUE" preferredOrientation="Rule-as-Column">"#));
+  let imported = import_dmn(&dmn, None).unwrap();
+  assert_eq!(Orientation::Vertical, imported.orientation());
+  assert_eq!(decision_table.to_string(), imported.to_string());
+}
+
+#[test]
+fn _0010() {
+  // crosstab orientation is preserved as the preferred orientation
+  let decision_table = INPUT_0007.parse::<DecisionTable>().unwrap();
+  let dmn = export_dmn(&decision_table);
+  assert!(dmn.contains(r#"<decisionTable id="_decision_table" hitPolicy="UNIQUE" preferredOrientation="CrossTable">"#));
+  let imported = import_dmn(&dmn, None).unwrap();
+  assert_eq!(Orientation::Crosstab, imported.orientation());
+  assert_eq!(4, imported.rules().len());
+  assert_eq!("0.15", imported.rules()[2].output_entries()[0].text());
+}
@@ -0,0 +1,20 @@
+//! Test parsing and displaying format names.
+
+use dtee::{Format, TableError};
+
+#[test]
+fn _0001() {
+  for name in Format::NAMES {
+    assert_eq!(name, name.parse::<Format>().unwrap().to_string());
+  }
+}
+
+#[test]
+fn _0002() {
+  assert_eq!(Format::Dmn, " DMN ".parse::<Format>().unwrap());
+}
+
+#[test]
+fn _0003() {
+  assert_eq!(Err(TableError::UnknownFormat("xls".to_string())), "xls".parse::<Format>());
+}
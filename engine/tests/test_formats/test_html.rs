@@ -0,0 +1,58 @@
+//! Test exporting decision tables to HTML.
+
+use super::*;
+use dtee::{export_html, DecisionTable, Format, TableError};
+
+/// Style of all cells.
+const STYLE: &str = "border: 1px solid; padding: 2px 6px; vertical-align: top;";
+
+#[test]
+fn _0001() {
+  let decision_table = INPUT_0001.parse::<DecisionTable>().unwrap();
+  let html = export_html(&decision_table);
+  assert!(html.starts_with("<table class=\"decision-table\" style=\"border-collapse: collapse;\">\n  <thead>\n"));
+  assert!(html.ends_with("  </tbody>\n</table>\n"));
+  assert!(html.contains(&format!(r#"<th class="information-item-name" colspan="4" style="{STYLE}">SLA</th>"#)));
+  assert!(html.contains(&format!(r#"<th class="hit-policy" rowspan="2" style="{STYLE}">U</th>"#)));
+  assert!(html.contains(&format!(r#"<th class="output" style="{STYLE} border-left: 3px double;">SLA</th>"#)));
+  assert!(html.contains(&format!(r#"<td class="input allowed-values" style="{STYLE}">[0..1000000]</td>"#)));
+  assert!(html.contains(&format!(r#"<td class="rule-number" style="{STYLE} border-top: 3px double;">1</td>"#)));
+  assert!(html.contains(&format!(r#"<td class="input" style="{STYLE}">&gt;=500</td>"#)));
+  assert_eq!(4, html.matches("<td class=\"rule-number\"").count());
+}
+
+#[test]
+fn _0002() {
+  let decision_table = INPUT_0002.parse::<DecisionTable>().unwrap();
+  let html = Format::Html.export(&decision_table);
+  assert!(html.contains(&format!(r#"<th class="information-item-name" colspan="7" style="{STYLE}">Order options</th>"#)));
+  assert!(html.contains(&format!(r#"<th class="hit-policy" rowspan="3" style="{STYLE}">U</th>"#)));
+  assert!(html.contains(&format!(r#"<th class="input" rowspan="2" style="{STYLE}">Customer<br>type</th>"#)));
+  assert!(html.contains(&format!(
+    r#"<th class="output output-label" colspan="2" style="{STYLE} border-left: 3px double;">Order options</th>"#
+  )));
+  assert!(html.contains(&format!(r#"<th class="output" style="{STYLE} border-left: 3px double;">Discount</th>"#)));
+  assert!(html.contains(&format!(r#"<th class="annotation" rowspan="3" style="{STYLE} border-left: 3px double;">Description</th>"#)));
+  assert!(html.contains(&format!(
+    r#"<td class="output allowed-values" style="{STYLE}">&quot;Normal&quot;,<br>&quot;High&quot;,<br>&quot;Low&quot;</td>"#
+  )));
+  assert!(html.contains(&format!(
+    r#"<td class="annotation" style="{STYLE} border-left: 3px double; border-top: 3px double;">Small order</td>"#
+  )));
+}
+
+#[test]
+fn _0003() {
+  // decision table without information item name and rules
+  let text = "┌───┬───╥───┐\n│ U │ a ║ b │\n╞═══╪═══╬═══╡\n└───┴───╨───┘";
+  let decision_table = text.parse::<DecisionTable>().unwrap();
+  let html = export_html(&decision_table);
+  assert!(!html.contains("information-item-name"));
+  assert!(html.ends_with("    </tr>\n  </thead>\n  <tbody>\n  </tbody>\n</table>\n"));
+}
+
+#[test]
+fn _0004() {
+  assert_eq!(Err(TableError::UnsupportedImport("html".to_string())), Format::Html.import("<table></table>", None));
+  assert_eq!("import from format 'html' is not supported", TableError::UnsupportedImport("html".to_string()).to_string());
+}
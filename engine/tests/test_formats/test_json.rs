@@ -0,0 +1,124 @@
+//! Test exporting decision tables to JSON and importing them back.
+
+use super::*;
+use dtee::{export_json, import_json, DecisionTable, Format, HitPolicy, JsonOptions, Region, TableError};
+
+#[test]
+fn _0001() {
+  let expected = r#"{
+  "version": 1,
+  "hitPolicy": "F",
+  "inputs": [
+    {
+      "inputExpression": {
+        "text": "a"
+      }
+    }
+  ],
+  "outputs": [
+    {
+      "name": {
+        "text": "b"
+      }
+    }
+  ],
+  "annotations": [],
+  "rules": [
+    {
+      "inputEntries": [
+        {
+          "text": "<2"
+        }
+      ],
+      "outputEntries": [
+        {
+          "text": "3"
+        }
+      ],
+      "annotationEntries": []
+    }
+  ]
+}
+"#;
+  let text = "┌───┬───╥───┐\n│ F │ a ║ b │\n╞═══╪═══╬═══╡\n│ 1 │<2 ║ 3 │\n└───┴───╨───┘";
+  let decision_table = text.parse::<DecisionTable>().unwrap();
+  assert_eq!(expected, export_json(&decision_table, JsonOptions::default()));
+}
+
+#[test]
+fn _0002() {
+  let decision_table = INPUT_0002.parse::<DecisionTable>().unwrap();
+  let json = Format::Json(JsonOptions::default()).export(&decision_table);
+  assert!(json.contains(r#""informationItemName": {"#));
+  assert!(json.contains(r#""outputLabel": {"#));
+  assert!(json.contains(r#""text": "Customer\ntype""#));
+  assert!(json.contains(r#""text": "\"Normal\",\n\"High\",\n\"Low\"""#));
+  assert!(json.contains(r#""annotationEntries": ["#));
+  assert!(!json.contains("region"));
+}
+
+#[test]
+fn _0003() {
+  // regions refer to the box-drawing text
+  let decision_table = INPUT_0001.parse::<DecisionTable>().unwrap();
+  let json = export_json(&decision_table, JsonOptions::default().with_regions(true));
+  let region = decision_table.information_item_name().unwrap().region().unwrap();
+  let expected = format!(
+    "\"text\": \"SLA\",\n    \"region\": {{\n      \"left\": {},\n      \"top\": {},\n      \"width\": {},\n      \"height\": {}\n    }}",
+    region.left(),
+    region.top(),
+    region.width(),
+    region.height()
+  );
+  assert!(json.contains(&expected));
+}
+
+#[test]
+fn _0004() {
+  // exported decision tables are imported back unchanged
+  for input in [INPUT_0001, INPUT_0002, INPUT_0004, INPUT_0006] {
+    let decision_table = input.parse::<DecisionTable>().unwrap();
+    let imported = import_json(&export_json(&decision_table, JsonOptions::default().with_regions(true))).unwrap();
+    assert_eq!(decision_table.to_string(), imported.to_string());
+  }
+}
+
+#[test]
+fn _0005() {
+  let json = r#"{
+    "version": 1,
+    "informationItemName": { "text": "Adult" },
+    "hitPolicy": "C+",
+    "inputs": [{ "inputExpression": { "text": "Age" }, "allowedValues": { "text": "[0..150]" } }],
+    "outputs": [{ "name": { "text": "Adult" } }],
+    "rules": [{ "inputEntries": [{ "text": "<18", "region": { "left": 0, "top": 0, "width": 1, "height": 1 } }], "outputEntries": [{ "text": "false" }] }]
+  }"#;
+  let decision_table = Format::Json(JsonOptions::default()).import(json, None).unwrap();
+  assert_eq!("Adult", decision_table.information_item_name().unwrap().text());
+  assert_eq!(HitPolicy::Collect(Some(dtee::BuiltinAggregator::Sum)), decision_table.hit_policy());
+  assert_eq!("[0..150]", decision_table.input_clauses()[0].allowed_values().unwrap().text());
+  assert_eq!(0, decision_table.rules()[0].annotation_entries().len());
+  // regions refer to the rendered text, not to the imported document
+  assert_ne!(Some(Region::new(0, 0, 1, 1)), decision_table.rules()[0].input_entries()[0].region());
+}
+
+#[test]
+fn _0006() {
+  assert!(matches!(import_json("{"), Err(TableError::InvalidJson(_))));
+  assert_eq!(
+    Err(TableError::InvalidJson("unsupported version 2".to_string())),
+    import_json(r#"{ "version": 2, "hitPolicy": "U", "inputs": [], "outputs": [] }"#)
+  );
+  assert_eq!(
+    Err(TableError::InvalidHitPolicy("X".to_string())),
+    import_json(r#"{ "version": 1, "hitPolicy": "X", "inputs": [], "outputs": [] }"#)
+  );
+  assert_eq!(
+    Err(TableError::MissingInputOutputSeparator),
+    import_json(r#"{ "version": 1, "hitPolicy": "U", "inputs": [], "outputs": [] }"#)
+  );
+  assert_eq!(
+    Err(TableError::InvalidJson("invalid number of entries in rule 1".to_string())),
+    import_json(r#"{ "version": 1, "hitPolicy": "U", "inputs": [], "outputs": [{ "name": { "text": "a" } }], "rules": [{ "inputEntries": [], "outputEntries": [] }] }"#)
+  );
+}
@@ -0,0 +1,94 @@
+//! Test exporting decision tables to Markdown and importing them back.
+
+use super::*;
+use dtee::{export_markdown, import_markdown, DecisionTable, Format, HitPolicy, Region, TableError};
+
+#[test]
+fn _0001() {
+  let expected = r#"**SLA**
+
+| U | YearsAsCustomer | NumberOfUnits | SLA (output) |
+| - | --------------- | ------------- | ------------ |
+|   | [0..100]        | [0..1000000]  | 1,2          |
+| 1 | <2              | <1000         | 1            |
+| 2 | <2              | >=1000        | 2            |
+| 3 | >=2             | <500          | 1            |
+| 4 | >=2             | >=500         | 2            |
+"#;
+  let decision_table = INPUT_0001.parse::<DecisionTable>().unwrap();
+  assert_eq!(expected, export_markdown(&decision_table));
+}
+
+#[test]
+fn _0002() {
+  let decision_table = INPUT_0002.parse::<DecisionTable>().unwrap();
+  let markdown = Format::Markdown.export(&decision_table);
+  assert!(markdown.starts_with("**Order options**\n\n| U | Customer<br>type         | Order<br>size | Order options / Discount (output) |"));
+  assert!(markdown.contains("| Description (annotation) | Reference (annotation) |\n"));
+  assert!(markdown.contains("| \"Normal\",<br>\"High\",<br>\"Low\"     |"));
+}
+
+#[test]
+fn _0003() {
+  // exported decision tables are imported back unchanged
+  for input in [INPUT_0001, INPUT_0002, INPUT_0004, INPUT_0006] {
+    let decision_table = input.parse::<DecisionTable>().unwrap();
+    let imported = import_markdown(&export_markdown(&decision_table)).unwrap();
+    assert_eq!(decision_table.to_string(), imported.to_string());
+  }
+}
+
+#[test]
+fn _0004() {
+  let markdown = r#"
+Some text before the table.
+
+## Discount
+
+|C+|Customer|a \| b|Rate (output)|Note (annotation)|
+|:-:|:---|---|---:|---|
+|1|"Gold"|x|0.2<br/>+ 0.1|best|
+|2|-|y|0|
+"#;
+  let decision_table = import_markdown(markdown).unwrap();
+  assert_eq!("Discount", decision_table.information_item_name().unwrap().text());
+  assert_eq!(HitPolicy::Collect(Some(dtee::BuiltinAggregator::Sum)), decision_table.hit_policy());
+  assert_eq!("a | b", decision_table.input_clauses()[1].input_expression().text());
+  assert_eq!(None, decision_table.input_clauses()[0].allowed_values());
+  let rules = decision_table.rules();
+  assert_eq!("0.2\n+ 0.1", rules[0].output_entries()[0].text());
+  assert_eq!("best", rules[0].annotation_entries()[0].text());
+  assert_eq!("", rules[1].annotation_entries()[0].text());
+  // regions refer to the rendered box-drawing text
+  assert!(rules[0].output_entries()[0].region().is_some_and(|region: Region| region.height() == 2));
+}
+
+#[test]
+fn _0005() {
+  let markdown = "| U | a | b (output) | c (output) |\n|---|---|---|---|\n| 1 | 1 | 2 | 3 |\n";
+  let decision_table = import_markdown(markdown).unwrap();
+  assert_eq!(None, decision_table.information_item_name());
+  assert_eq!(None, decision_table.output_label());
+  let markdown = format!("Text before the table.\n{markdown}");
+  assert_eq!(None, import_markdown(&markdown).unwrap().information_item_name());
+  assert_eq!("c", decision_table.output_clauses()[1].name().text());
+}
+
+#[test]
+fn _0006() {
+  assert_eq!(Err(TableError::InvalidMarkdown("table not found".to_string())), import_markdown("no table"));
+  assert_eq!(
+    Err(TableError::InvalidMarkdown("missing delimiter row".to_string())),
+    import_markdown("| U | a | b (output) |\n| 1 | 1 | 2 |")
+  );
+  assert_eq!(Err(TableError::MissingInputOutputSeparator), import_markdown("| U | a | b |\n|---|---|---|"));
+  assert_eq!(
+    Err(TableError::InvalidMarkdown("input column 'c' after output columns".to_string())),
+    import_markdown("| U | a | b (output) | c |\n|---|---|---|---|")
+  );
+  assert_eq!(
+    Err(TableError::InvalidMarkdown("too many cells in row 1".to_string())),
+    import_markdown("| U | a | b (output) |\n|---|---|---|\n| 1 | 2 | 3 | 4 |")
+  );
+  assert_eq!(Err(TableError::InvalidHitPolicy("X".to_string())), import_markdown("| X | a | b (output) |\n|---|---|---|"));
+}
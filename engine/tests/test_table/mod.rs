@@ -0,0 +1,7 @@
+mod test_crosstab;
+mod test_hit_policy;
+mod test_orientation;
+mod test_parse;
+mod test_render;
+
+use super::test_files::*;
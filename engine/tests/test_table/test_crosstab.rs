@@ -0,0 +1,99 @@
+//! Test parsing and rendering crosstab decision tables.
+
+use super::*;
+use dtee::{table_orientation, DecisionTable, HitPolicy, Orientation, Plane, Region};
+
+/// Returns the expected text with the indentation removed.
+fn expected(text: &str) -> String {
+  text.trim().lines().map(|line| line.trim()).collect::<Vec<&str>>().join("\n")
+}
+
+#[test]
+fn _0001() {
+  // crosstab is parsed and rendered back to the same text
+  let decision_table = INPUT_0007.parse::<DecisionTable>().unwrap();
+  assert_eq!(Orientation::Crosstab, decision_table.orientation());
+  assert_eq!(HitPolicy::Unique, decision_table.hit_policy());
+  assert_eq!(2, decision_table.input_clauses().len());
+  assert_eq!(1, decision_table.output_clauses().len());
+  assert_eq!(4, decision_table.rules().len());
+  assert_eq!(INPUT_0007.trim_end(), decision_table.to_string());
+}
+
+#[test]
+fn _0002() {
+  // crosstab is rendered horizontally as an equivalent decision table
+  let expected = expected(
+    r#"
+    ┌────────────┐
+    │  Discount  │
+    ├───┬───────┬┴───────────╥──────────┐
+    │ U │ Order │  Customer  ║ Discount │
+    │   │ size  │            ║          │
+    ╞═══╪═══════╪════════════╬══════════╡
+    │ 1 │ <10   │ "Business" ║ 0.10     │
+    ├───┼───────┼────────────╫──────────┤
+    │ 2 │ <10   │ "Private"  ║ 0.05     │
+    ├───┼───────┼────────────╫──────────┤
+    │ 3 │ >=10  │ "Business" ║ 0.15     │
+    ├───┼───────┼────────────╫──────────┤
+    │ 4 │ >=10  │ "Private"  ║ 0.05     │
+    └───┴───────┴────────────╨──────────┘
+    "#,
+  );
+  let decision_table = INPUT_0007.parse::<DecisionTable>().unwrap();
+  assert_eq!(expected, decision_table.clone().with_orientation(Orientation::Horizontal).to_string());
+  assert_eq!(
+    INPUT_0007.trim_end(),
+    expected.parse::<DecisionTable>().unwrap().with_orientation(Orientation::Crosstab).to_string()
+  );
+}
+
+#[test]
+fn _0003() {
+  // missing pairs of input values are rendered as empty cells
+  let expected = expected(
+    r#"
+    ┌──────────────╥────────────────────────┐
+    │   Discount   ║        Customer        │
+    │              ╟────────────┬───────────┤
+    │              ║ "Business" │ "Private" │
+    ╞═══════╤══════╬════════════╪═══════════╡
+    │ Order │ <10  ║ 0.10       │           │
+    │ size  ├──────╫────────────┼───────────┤
+    │       │ >=10 ║ 0.15       │ 0.05      │
+    └───────┴──────╨────────────┴───────────┘
+    "#,
+  );
+  let decision_table = INPUT_0007.parse::<DecisionTable>().unwrap();
+  let mut rules = decision_table.rules().to_vec();
+  rules.remove(1);
+  let decision_table =
+    DecisionTable::new(HitPolicy::Unique, decision_table.input_clauses().to_vec(), decision_table.output_clauses().to_vec(), rules).with_orientation(Orientation::Crosstab);
+  assert_eq!(expected, decision_table.to_string());
+  let parsed = expected.parse::<DecisionTable>().unwrap();
+  assert_eq!(4, parsed.rules().len());
+  assert!(parsed.rules()[1].output_entries()[0].is_empty());
+}
+
+#[test]
+fn _0004() {
+  // decision tables that can not be rendered as crosstabs are rendered horizontally
+  for input in [INPUT_0001, INPUT_0002, INPUT_0004] {
+    let decision_table = input.parse::<DecisionTable>().unwrap();
+    let text = decision_table.clone().with_orientation(Orientation::Crosstab).to_string();
+    assert_eq!(decision_table.to_string(), text);
+    assert_eq!(Orientation::Horizontal, table_orientation(Plane::new(text).content()));
+  }
+  assert_eq!(Orientation::Crosstab, table_orientation(Plane::new(INPUT_0007).content()));
+}
+
+#[test]
+fn _0005() {
+  // regions of cells refer to the crosstab text
+  let decision_table = INPUT_0007.parse::<DecisionTable>().unwrap();
+  assert_eq!(Some(Region::new(1, 1, 12, 1)), decision_table.information_item_name().unwrap().region());
+  assert_eq!(Some(Region::new(16, 3, 24, 1)), decision_table.input_clauses()[1].input_expression().region());
+  assert_eq!(Some(Region::new(9, 9, 6, 1)), decision_table.rules()[3].input_entries()[0].region());
+  assert_eq!(Some(Region::new(29, 9, 11, 1)), decision_table.rules()[3].output_entries()[0].region());
+}
@@ -0,0 +1,23 @@
+//! Test parsing and displaying hit policies.
+
+use dtee::{BuiltinAggregator, HitPolicy, TableError};
+
+#[test]
+fn _0001() {
+  let inputs = ["U", "A", "P", "F", "C", "C#", "C+", "C<", "C>", "O", "R"];
+  for input in inputs {
+    assert_eq!(input, input.parse::<HitPolicy>().unwrap().to_string());
+  }
+}
+
+#[test]
+fn _0002() {
+  assert_eq!(HitPolicy::Unique, "UNIQUE".parse::<HitPolicy>().unwrap());
+  assert_eq!(HitPolicy::RuleOrder, "RULE ORDER".parse::<HitPolicy>().unwrap());
+  assert_eq!(HitPolicy::Collect(Some(BuiltinAggregator::Count)), "COLLECT COUNT".parse::<HitPolicy>().unwrap());
+}
+
+#[test]
+fn _0003() {
+  assert_eq!(Err(TableError::InvalidHitPolicy("X".to_string())), "X".parse::<HitPolicy>());
+}
@@ -0,0 +1,103 @@
+//! Test parsing and rendering vertical decision tables.
+
+use super::*;
+use dtee::{table_orientation, DecisionTable, Orientation, Plane, Region};
+
+/// Returns the expected text with the indentation removed.
+fn expected(text: &str) -> String {
+  text.trim().lines().map(|line| line.trim()).collect::<Vec<&str>>().join("\n")
+}
+
+#[test]
+fn _0001() {
+  let expected = expected(
+    r#"
+    ┌───────┐
+    │  SLA  │
+    ├───────┴────────────────────────╥───────┬────────┬──────┬───────┐
+    │               U                ║   1   │   2    │  3   │   4   │
+    ├─────────────────┬──────────────╫───────┼────────┼──────┼───────┤
+    │ YearsAsCustomer │ [0..100]     ║ <2    │ <2     │ >=2  │ >=2   │
+    ├─────────────────┼──────────────╫───────┼────────┼──────┼───────┤
+    │ NumberOfUnits   │ [0..1000000] ║ <1000 │ >=1000 │ <500 │ >=500 │
+    ╞═════════════════╪══════════════╬═══════╪════════╪══════╪═══════╡
+    │ SLA             │ 1,2          ║ 1     │ 2      │ 1    │ 2     │
+    └─────────────────┴──────────────╨───────┴────────┴──────┴───────┘
+    "#,
+  );
+  let decision_table = INPUT_0001.parse::<DecisionTable>().unwrap().with_orientation(Orientation::Vertical);
+  assert_eq!(expected, decision_table.to_string());
+}
+
+#[test]
+fn _0002() {
+  let expected = expected(
+    r#"
+    ┌─────────────────┐
+    │  Order options  │
+    ├─────────────────┴──────────────────────╥─────────────┬─────────────┬────────────┐
+    │                   U                    ║      1      │      2      │     3      │
+    ├──────────────────────────┬─────────────╫─────────────┼─────────────┼────────────┤
+    │ Customer                 │ "Business", ║ "Business"  │ "Business"  │ "Private"  │
+    │ type                     │ "Private"   ║             │             │            │
+    ├──────────────────────────┼─────────────╫─────────────┼─────────────┼────────────┤
+    │ Order                    │             ║ <10         │ >=10        │ -          │
+    │ size                     │             ║             │             │            │
+    ╞═══════════════╤══════════╪═════════════╬═════════════╪═════════════╪════════════╡
+    │ Order options │ Discount │             ║ 0.10        │ 0.15        │ 0.05       │
+    │               ├──────────┼─────────────╫─────────────┼─────────────┼────────────┤
+    │               │ Priority │ "Normal",   ║ "Normal"    │ "High"      │ "Low"      │
+    │               │          │ "High",     ║             │             │            │
+    │               │          │ "Low"       ║             │             │            │
+    ╞═══════════════╧══════════╪═════════════╬═════════════╪═════════════╪════════════╡
+    │ Description              │             ║ Small order │ Large order │ All orders │
+    ├──────────────────────────┼─────────────╫─────────────┼─────────────┼────────────┤
+    │ Reference                │             ║ Ref 1       │ Ref 2       │ Ref 3      │
+    └──────────────────────────┴─────────────╨─────────────┴─────────────┴────────────┘
+    "#,
+  );
+  let decision_table = INPUT_0002.parse::<DecisionTable>().unwrap().with_orientation(Orientation::Vertical);
+  assert_eq!(expected, decision_table.to_string());
+}
+
+#[test]
+fn _0003() {
+  // vertical text is parsed back to the same decision table
+  for input in [INPUT_0001, INPUT_0002, INPUT_0004, INPUT_0006] {
+    let decision_table = input.parse::<DecisionTable>().unwrap();
+    let text = decision_table.clone().with_orientation(Orientation::Vertical).to_string();
+    let parsed = text.parse::<DecisionTable>().unwrap();
+    assert_eq!(Orientation::Vertical, parsed.orientation());
+    assert_eq!(text, parsed.to_string());
+    assert_eq!(decision_table.to_string(), parsed.with_orientation(Orientation::Horizontal).to_string());
+  }
+}
+
+#[test]
+fn _0004() {
+  // orientation is recognized from the frame of the decision table
+  for input in [INPUT_0001, INPUT_0002, INPUT_0004, INPUT_0006] {
+    assert_eq!(Orientation::Horizontal, table_orientation(Plane::new(input).content()));
+    let text = input.parse::<DecisionTable>().unwrap().with_orientation(Orientation::Vertical).to_string();
+    assert_eq!(Orientation::Vertical, table_orientation(Plane::new(text).content()));
+  }
+}
+
+#[test]
+fn _0005() {
+  // regions of cells refer to the vertical text
+  let text = INPUT_0001.parse::<DecisionTable>().unwrap().with_orientation(Orientation::Vertical).to_string();
+  let decision_table = text.parse::<DecisionTable>().unwrap();
+  assert_eq!(Some(Region::new(1, 1, 7, 1)), decision_table.information_item_name().unwrap().region());
+  assert_eq!(Some(Region::new(1, 5, 17, 1)), decision_table.input_clauses()[0].input_expression().region());
+  assert_eq!(Some(Region::new(34, 5, 7, 1)), decision_table.rules()[0].input_entries()[0].region());
+  assert_eq!(Some(Region::new(51, 9, 6, 1)), decision_table.rules()[2].output_entries()[0].region());
+}
+
+#[test]
+fn _0006() {
+  // decision table starting with the separator is horizontal and parsing it does not panic
+  let text = "╞═══╡\n│ A │\n└───┘";
+  assert_eq!(Orientation::Horizontal, table_orientation(Plane::new(text).content()));
+  assert!(text.parse::<DecisionTable>().is_err());
+}
@@ -0,0 +1,193 @@
+//! Test parsing decision tables from the box-drawing grid.
+
+use super::*;
+use dtee::{Controller, DecisionTable, HitPolicy, Region, TableError};
+
+#[test]
+fn _0001() {
+  let decision_table = INPUT_0001.parse::<DecisionTable>().unwrap();
+  assert_eq!("SLA", decision_table.information_item_name().unwrap().text());
+  assert_eq!(HitPolicy::Unique, decision_table.hit_policy());
+  assert_eq!(None, decision_table.output_label());
+  let input_clauses = decision_table.input_clauses();
+  assert_eq!(2, input_clauses.len());
+  assert_eq!("YearsAsCustomer", input_clauses[0].input_expression().text());
+  assert_eq!("[0..100]", input_clauses[0].allowed_values().unwrap().text());
+  assert_eq!("NumberOfUnits", input_clauses[1].input_expression().text());
+  assert_eq!("[0..1000000]", input_clauses[1].allowed_values().unwrap().text());
+  let output_clauses = decision_table.output_clauses();
+  assert_eq!(1, output_clauses.len());
+  assert_eq!("SLA", output_clauses[0].name().text());
+  assert_eq!("1,2", output_clauses[0].allowed_values().unwrap().text());
+  assert!(decision_table.annotation_clauses().is_empty());
+  let rules = decision_table.rules();
+  assert_eq!(4, rules.len());
+  let texts = rules
+    .iter()
+    .map(|rule| rule.input_entries().iter().chain(rule.output_entries()).map(|cell| cell.text()).collect::<Vec<&str>>())
+    .collect::<Vec<Vec<&str>>>();
+  assert_eq!(
+    vec![vec!["<2", "<1000", "1"], vec!["<2", ">=1000", "2"], vec![">=2", "<500", "1"], vec![">=2", ">=500", "2"]],
+    texts
+  );
+}
+
+#[test]
+fn _0002() {
+  let decision_table = INPUT_0001.parse::<DecisionTable>().unwrap();
+  assert_eq!(Some(Region::new(1, 1, 7, 1)), decision_table.information_item_name().unwrap().region());
+  let input_clause = &decision_table.input_clauses()[0];
+  assert_eq!(Some(Region::new(5, 3, 17, 1)), input_clause.input_expression().region());
+  assert_eq!(Some(Region::new(5, 5, 17, 1)), input_clause.allowed_values().unwrap().region());
+  let rule = &decision_table.rules()[3];
+  assert_eq!(Some(Region::new(23, 13, 15, 1)), rule.input_entries()[1].region());
+  assert_eq!(Some(Region::new(39, 13, 5, 1)), rule.output_entries()[0].region());
+}
+
+#[test]
+fn _0003() {
+  let decision_table = INPUT_0002.parse::<DecisionTable>().unwrap();
+  assert_eq!("Order options", decision_table.information_item_name().unwrap().text());
+  assert_eq!("Order options", decision_table.output_label().unwrap().text());
+  assert_eq!(Some(Region::new(25, 3, 21, 1)), decision_table.output_label().unwrap().region());
+  let input_clauses = decision_table.input_clauses();
+  assert_eq!("Customer\ntype", input_clauses[0].input_expression().text());
+  assert_eq!(vec!["Customer", "type"], input_clauses[0].input_expression().lines());
+  assert_eq!("\"Business\",\n\"Private\"", input_clauses[0].allowed_values().unwrap().text());
+  assert_eq!("Order\nsize", input_clauses[1].input_expression().text());
+  assert!(input_clauses[1].allowed_values().unwrap().is_empty());
+  let output_clauses = decision_table.output_clauses();
+  assert_eq!("Discount", output_clauses[0].name().text());
+  assert_eq!("Priority", output_clauses[1].name().text());
+  assert_eq!("\"Normal\",\n\"High\",\n\"Low\"", output_clauses[1].allowed_values().unwrap().text());
+  let annotation_clauses = decision_table.annotation_clauses();
+  assert_eq!(2, annotation_clauses.len());
+  assert_eq!("Description", annotation_clauses[0].name().text());
+  assert_eq!("Reference", annotation_clauses[1].name().text());
+  let rule = &decision_table.rules()[2];
+  assert_eq!("\"Private\"", rule.input_entries()[0].text());
+  assert_eq!("-", rule.input_entries()[1].text());
+  assert_eq!("0.05", rule.output_entries()[0].text());
+  assert_eq!("\"Low\"", rule.output_entries()[1].text());
+  assert_eq!("All orders", rule.annotation_entries()[0].text());
+  assert_eq!("Ref 3", rule.annotation_entries()[1].text());
+}
+
+#[test]
+fn _0004() {
+  let decision_table = INPUT_0004.parse::<DecisionTable>().unwrap();
+  assert_eq!(None, decision_table.information_item_name());
+  assert_eq!(HitPolicy::Unique, decision_table.hit_policy());
+  assert_eq!(2, decision_table.input_clauses().len());
+  assert_eq!(2, decision_table.output_clauses().len());
+  assert_eq!(2, decision_table.annotation_clauses().len());
+  assert_eq!(3, decision_table.rules().len());
+}
+
+#[test]
+fn _0005() {
+  let input = r#"
+    ┌───┬───────╥───┐
+    │ F │ a     ║ b │
+    ╞═══╪═══════╬═══╡
+    │ 1 │ "one" ║ 1 │
+    │   │ "two" ║   │
+    ├───┼───────╫───┤
+    │ 2 │ -     ║ 2 │
+    └───┴───────╨───┘
+  "#;
+  let decision_table = input.parse::<DecisionTable>().unwrap();
+  assert_eq!(HitPolicy::First, decision_table.hit_policy());
+  assert_eq!(None, decision_table.input_clauses()[0].allowed_values());
+  let rules = decision_table.rules();
+  assert_eq!(2, rules.len());
+  assert_eq!("\"one\"\n\"two\"", rules[0].input_entries()[0].text());
+  assert_eq!(Some(Region::new(5, 3, 7, 2)), rules[0].input_entries()[0].region());
+  assert_eq!("1", rules[0].output_entries()[0].text());
+  assert_eq!(Some(Region::new(13, 3, 3, 2)), rules[0].output_entries()[0].region());
+}
+
+#[test]
+fn _0006() {
+  let controller = Controller::new(INPUT_0006);
+  let decision_table = controller.decision_table().unwrap();
+  assert_eq!("Wide name", decision_table.information_item_name().unwrap().text());
+  assert_eq!(2, decision_table.input_clauses().len());
+  assert_eq!("In 2", decision_table.input_clauses()[1].input_expression().text());
+  assert_eq!("O", decision_table.output_clauses()[0].name().text());
+}
+
+#[test]
+fn _0007() {
+  let input = r#"
+    ┌───┬───╥───┐
+    │ U │ a ║ b │
+    ├───┼───╫───┤
+    │ 1 │ 2 ║ 3 │
+    └───┴───╨───┘
+  "#;
+  assert_eq!(Err(TableError::MissingHeaderSeparator), input.parse::<DecisionTable>());
+}
+
+#[test]
+fn _0008() {
+  let input = r#"
+    ┌───┬───┬───┐
+    │ U │ a │ b │
+    ╞═══╪═══╪═══╡
+    │ 1 │ 2 │ 3 │
+    └───┴───┴───┘
+  "#;
+  assert_eq!(Err(TableError::MissingInputOutputSeparator), input.parse::<DecisionTable>());
+}
+
+#[test]
+fn _0009() {
+  let input = r#"
+    ┌───┬───╥───┐
+    │ X │ a ║ b │
+    ╞═══╪═══╬═══╡
+    │ 1 │ 2 ║ 3 │
+    └───┴───╨───┘
+  "#;
+  assert_eq!(Err(TableError::InvalidHitPolicy("X".to_string())), input.parse::<DecisionTable>());
+}
+
+#[test]
+fn _0010() {
+  let input = r#"
+    ┌───┬───────╥───┐
+    │ U │   a   ║ b │
+    │   ├───┬───╫───┤
+    │   │ c │ d ║ e │
+    ╞═══╪═══╪═══╬═══╡
+    │ 1 │ 2 │ 3 ║ 4 │
+    └───┴───┴───╨───┘
+  "#;
+  assert_eq!(Err(TableError::UnexpectedCell(Region::new(5, 1, 7, 1))), input.parse::<DecisionTable>());
+}
+
+#[test]
+fn _0011() {
+  let input = r#"
+    ┌───┬───╥───┐
+    │ U │ a ║ b │
+    ╞═══╪═══╬═══╡
+    │ 1 │ 2 ║ 3 │
+    │   ├───╫───┤
+    │   │ 4 ║ 5 │
+    └───┴───╨───┘
+  "#;
+  assert_eq!(Err(TableError::UnexpectedCell(Region::new(5, 3, 3, 1))), input.parse::<DecisionTable>());
+}
+
+#[test]
+fn _0012() {
+  // combining marks, wide characters and emoji are parsed, cell regions are in terminal columns
+  let decision_table = INPUT_0008.parse::<DecisionTable>().unwrap();
+  assert_eq!("Rabatt 割引", decision_table.information_item_name().unwrap().text());
+  assert_eq!("\"Gescha\u{308}ft\"", decision_table.rules()[0].input_entries()[0].text());
+  assert_eq!("\"顧客\"", decision_table.rules()[1].input_entries()[0].text());
+  assert_eq!("0.05 🎉", decision_table.rules()[1].output_entries()[0].text());
+  assert_eq!(Some(Region::new(18, 7, 9, 1)), decision_table.rules()[1].output_entries()[0].region());
+}
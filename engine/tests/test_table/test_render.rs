@@ -0,0 +1,120 @@
+//! Test rendering decision tables as box-drawing text.
+
+use super::*;
+use dtee::{Cell, DecisionTable, HitPolicy, InputClause, OutputClause, Rule};
+
+/// Returns the expected text with the indentation removed.
+fn expected(text: &str) -> String {
+  text.trim().lines().map(|line| line.trim()).collect::<Vec<&str>>().join("\n")
+}
+
+#[test]
+fn _0001() {
+  let expected = expected(
+    r#"
+    ┌───────┐
+    │  SLA  │
+    ├───┬───┴─────────────┬───────────────╥─────┐
+    │ U │ YearsAsCustomer │ NumberOfUnits ║ SLA │
+    │   ├─────────────────┼───────────────╫─────┤
+    │   │    [0..100]     │ [0..1000000]  ║ 1,2 │
+    ╞═══╪═════════════════╪═══════════════╬═════╡
+    │ 1 │ <2              │ <1000         ║ 1   │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 2 │ <2              │ >=1000        ║ 2   │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 3 │ >=2             │ <500          ║ 1   │
+    ├───┼─────────────────┼───────────────╫─────┤
+    │ 4 │ >=2             │ >=500         ║ 2   │
+    └───┴─────────────────┴───────────────╨─────┘
+    "#,
+  );
+  let decision_table = INPUT_0001.parse::<DecisionTable>().unwrap();
+  assert_eq!(expected, decision_table.to_string());
+}
+
+#[test]
+fn _0002() {
+  let expected = expected(
+    r#"
+    ┌─────────────────┐
+    │  Order options  │
+    ├───┬─────────────┼───────╥──────────────────────╥─────────────┬───────────┐
+    │ U │  Customer   │ Order ║    Order options     ║ Description │ Reference │
+    │   │    type     │ size  ╟──────────┬───────────╢             │           │
+    │   │             │       ║ Discount │ Priority  ║             │           │
+    │   ├─────────────┼───────╫──────────┼───────────╫─────────────┼───────────┤
+    │   │ "Business", │       ║          │ "Normal", ║             │           │
+    │   │  "Private"  │       ║          │  "High",  ║             │           │
+    │   │             │       ║          │   "Low"   ║             │           │
+    ╞═══╪═════════════╪═══════╬══════════╪═══════════╬═════════════╪═══════════╡
+    │ 1 │ "Business"  │ <10   ║ 0.10     │ "Normal"  ║ Small order │ Ref 1     │
+    ├───┼─────────────┼───────╫──────────┼───────────╫─────────────┼───────────┤
+    │ 2 │ "Business"  │ >=10  ║ 0.15     │ "High"    ║ Large order │ Ref 2     │
+    ├───┼─────────────┼───────╫──────────┼───────────╫─────────────┼───────────┤
+    │ 3 │ "Private"   │ -     ║ 0.05     │ "Low"     ║ All orders  │ Ref 3     │
+    └───┴─────────────┴───────╨──────────┴───────────╨─────────────┴───────────┘
+    "#,
+  );
+  let decision_table = INPUT_0002.parse::<DecisionTable>().unwrap();
+  assert_eq!(expected, decision_table.to_string());
+}
+
+#[test]
+fn _0003() {
+  // rendered text is parsed back to the same decision table
+  for input in [INPUT_0001, INPUT_0002, INPUT_0004, INPUT_0006] {
+    let text = input.parse::<DecisionTable>().unwrap().to_string();
+    assert_eq!(text, text.parse::<DecisionTable>().unwrap().to_string());
+  }
+}
+
+#[test]
+fn _0004() {
+  let expected = expected(
+    r#"
+    ┌───┬──────────╥────────────────┐
+    │ C │ Customer ║ Discount label │
+    │   │          ╟──────┬─────────┤
+    │   │          ║ Rate │ Reason  │
+    ╞═══╪══════════╬══════╪═════════╡
+    │ 1 │ "Gold",  ║ 0.2  │ -       │
+    │   │ "Silver" ║      │         │
+    └───┴──────────╨──────┴─────────┘
+    "#,
+  );
+  let decision_table = DecisionTable::new(
+    HitPolicy::Collect(None),
+    vec![InputClause::new(Cell::new("Customer"))],
+    vec![OutputClause::new(Cell::new("Rate")), OutputClause::new(Cell::new("Reason"))],
+    vec![Rule::new(vec![Cell::new("\"Gold\",\n\"Silver\"")], vec![Cell::new("0.2"), Cell::new("-")])],
+  )
+  .with_output_label(Cell::new("Discount label"));
+  assert_eq!(expected, decision_table.to_string());
+}
+
+#[test]
+fn _0005() {
+  let expected = expected(
+    r#"
+    ┌────────────────────┐
+    │  Long information  │
+    │     item name      │
+    ├───┬───╥───┬────────┘
+    │ U │ a ║ b │
+    ╞═══╪═══╬═══╡
+    │   │   ║   │
+    └───┴───╨───┘
+    "#,
+  );
+  let decision_table = DecisionTable::new(HitPolicy::Unique, vec![InputClause::new(Cell::new("a"))], vec![OutputClause::new(Cell::new("b"))], vec![])
+    .with_information_item_name(Cell::new("Long information\nitem name"));
+  assert_eq!(expected, decision_table.to_string());
+}
+
+#[test]
+fn _0006() {
+  // columns are as wide as the text in terminal columns, not in characters
+  let decision_table = INPUT_0008.parse::<DecisionTable>().unwrap();
+  assert_eq!(INPUT_0008.trim_end(), decision_table.to_string());
+}